@@ -0,0 +1,427 @@
+use clap::{Args, Subcommand};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::{
+        discover_files,
+        migrate::{self, MigrationPlan},
+        schema::{self, ConfigDiagnostic, Severity},
+        Config, ConfigLayers, ConfigOverrides, ResolvedConfig,
+    },
+    error::AdrscanError,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct ConfigCommand {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Print the effective configuration
+    Show(ShowArgs),
+    /// Check config files against the schema
+    Validate(ValidateArgs),
+    /// Print the JSON Schema of config files, for editor integration
+    Schema,
+    /// Upgrade config files written for older versions of adrscan
+    Migrate(MigrateArgs),
+}
+
+#[derive(Args)]
+pub struct ShowArgs {
+    /// List every value with the file, environment or default it comes from
+    #[arg(long)]
+    pub resolved: bool,
+
+    /// Output format (yaml, json)
+    #[arg(short, long, default_value = "yaml")]
+    pub format: String,
+}
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// Config files to check (defaults to the files that would be loaded)
+    pub files: Vec<PathBuf>,
+
+    /// Output format (console, json)
+    #[arg(short, long, default_value = "console")]
+    pub format: String,
+
+    /// Exit with an error code on warnings as well as errors
+    #[arg(long)]
+    pub deny_warnings: bool,
+}
+
+#[derive(Args)]
+pub struct MigrateArgs {
+    /// Config files to upgrade (defaults to the files that would be loaded)
+    pub files: Vec<PathBuf>,
+
+    /// Print the changes as a diff instead of writing them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl ConfigCommand {
+    /// Runs before the configuration is loaded, so that problems loading it
+    /// can be reported
+    pub fn execute(&self, config_path: Option<&Path>, overrides: &ConfigOverrides) -> Result<()> {
+        match self.action {
+            ConfigAction::Show(ref args) => {
+                let resolved = Config::resolve(config_path, overrides)?;
+                println!("{}", args.render(&resolved)?);
+                Ok(())
+            }
+            ConfigAction::Validate(ref args) => args.execute(config_path),
+            ConfigAction::Migrate(ref args) => args.execute(config_path),
+            ConfigAction::Schema => {
+                let schema = serde_json::to_string_pretty(&schema::json_schema())
+                    .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+                println!("{schema}");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ValidateArgs {
+    fn execute(&self, config_path: Option<&Path>) -> Result<()> {
+        let files = self.files(config_path)?;
+        let diagnostics = self.run(&files)?;
+
+        match self.format.as_str() {
+            "json" => println!(
+                "{}",
+                serde_json::to_string_pretty(&diagnostics)
+                    .map_err(|e| AdrscanError::SerializationError(e.to_string()))?
+            ),
+            "console" => print_console(&files, &diagnostics),
+            _ => {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Unsupported output format: {}. Use 'console' or 'json'",
+                    self.format
+                )));
+            }
+        }
+
+        let failed = diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error || self.deny_warnings);
+        if failed {
+            log::warn!("Config validation failed");
+            std::process::exit(1);
+        }
+        Ok(())
+    }
+
+    fn files(&self, config_path: Option<&Path>) -> Result<Vec<PathBuf>> {
+        config_files(&self.files, config_path)
+    }
+
+    fn run(&self, files: &[PathBuf]) -> Result<Vec<ConfigDiagnostic>> {
+        let mut diagnostics = Vec::new();
+        for file in files {
+            let found = schema::validate_file(file)?;
+            let valid = !found.iter().any(|d| d.severity == Severity::Error);
+            diagnostics.extend(found);
+
+            // Values the schema cannot judge, e.g. regexes, are checked the
+            // way loading the file would check them
+            if valid {
+                let checked = ConfigLayers::with_checked_file(file)
+                    .and_then(|layers| layers.config())
+                    .and_then(|config| config.validate());
+                if let Err(error) = checked {
+                    let message = match error {
+                        AdrscanError::ConfigError(message) => message,
+                        error => error.to_string(),
+                    };
+                    diagnostics.push(ConfigDiagnostic {
+                        file: file.clone(),
+                        line: None,
+                        column: None,
+                        key: String::new(),
+                        severity: Severity::Error,
+                        message,
+                    });
+                }
+            }
+        }
+        Ok(diagnostics)
+    }
+}
+
+impl MigrateArgs {
+    fn execute(&self, config_path: Option<&Path>) -> Result<()> {
+        let files = config_files(&self.files, config_path)?;
+        if files.is_empty() {
+            println!("ℹ️  No config files found, nothing to migrate");
+            return Ok(());
+        }
+
+        for plan in self.run(&files)? {
+            if plan.is_current() {
+                println!("✅ {} is up to date", plan.file.display());
+                continue;
+            }
+            println!(
+                "🔄 {}: version {} → {}",
+                plan.file.display(),
+                plan.from,
+                migrate::CONFIG_VERSION
+            );
+            for description in &plan.applied {
+                println!("   • {description}");
+            }
+            for note in &plan.notes {
+                println!("   ⚠️  {note}");
+            }
+            if self.dry_run {
+                print!("{}", plan.diff());
+            } else {
+                plan.write()?;
+                println!("📝 Updated {}", plan.file.display());
+            }
+        }
+        Ok(())
+    }
+
+    /// Plans for every file, so that nothing is written unless all of them
+    /// can be migrated
+    fn run(&self, files: &[PathBuf]) -> Result<Vec<MigrationPlan>> {
+        files.iter().map(|file| migrate::plan(file)).collect()
+    }
+}
+
+/// The files named on the command line, else the `--config` file, else the
+/// discovered config files
+fn config_files(files: &[PathBuf], config_path: Option<&Path>) -> Result<Vec<PathBuf>> {
+    if !files.is_empty() {
+        return Ok(files.to_vec());
+    }
+    if let Some(path) = config_path {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let current_dir = std::env::current_dir()
+        .map_err(|e| AdrscanError::ConfigError(format!("Cannot get current directory: {e}")))?;
+    Ok(discover_files(&current_dir))
+}
+
+fn print_console(files: &[PathBuf], diagnostics: &[ConfigDiagnostic]) {
+    if files.is_empty() {
+        println!("ℹ️  No config files found, using defaults");
+        return;
+    }
+    for diagnostic in diagnostics {
+        let icon = match diagnostic.severity {
+            Severity::Error => "🔴",
+            Severity::Warning => "🟡",
+        };
+        println!("{icon} {diagnostic}");
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    if !diagnostics.is_empty() {
+        println!();
+    }
+    println!(
+        "📊 {} config files checked: {} errors, {} warnings",
+        files.len(),
+        errors,
+        diagnostics.len() - errors
+    );
+    if diagnostics.is_empty() {
+        println!("✅ Configuration is valid!");
+    }
+}
+
+impl ShowArgs {
+    fn render(&self, resolved: &ResolvedConfig) -> Result<String> {
+        match (self.format.as_str(), self.resolved) {
+            ("json", true) => serde_json::to_string_pretty(resolved)
+                .map_err(|e| AdrscanError::SerializationError(e.to_string())),
+            ("json", false) => serde_json::to_string_pretty(&effective(resolved))
+                .map_err(|e| AdrscanError::SerializationError(e.to_string())),
+            ("yaml", true) => Ok(annotated(resolved)),
+            ("yaml", false) => serde_yaml::to_string(&effective(resolved))
+                .map_err(|e| AdrscanError::SerializationError(e.to_string())),
+            _ => Err(AdrscanError::InvalidArgument(format!(
+                "Unsupported output format: {}. Use 'yaml' or 'json'",
+                self.format
+            ))),
+        }
+    }
+}
+
+/// The effective configuration as nested tables
+fn effective(resolved: &ResolvedConfig) -> serde_yaml::Value {
+    let mut root = serde_yaml::Mapping::new();
+    for value in &resolved.values {
+        let mut table = &mut root;
+        let mut keys = value.key.split('.').peekable();
+        while let Some(key) = keys.next() {
+            let key = serde_yaml::Value::String(key.to_string());
+            if keys.peek().is_none() {
+                table.insert(key, value.value.clone());
+                break;
+            }
+            let entry = table
+                .entry(key)
+                .or_insert_with(|| serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+            let serde_yaml::Value::Mapping(nested) = entry else {
+                break;
+            };
+            table = nested;
+        }
+    }
+    serde_yaml::Value::Mapping(root)
+}
+
+/// One `key: value  # source` line per value
+fn annotated(resolved: &ResolvedConfig) -> String {
+    let mut lines = vec!["# Config files, lowest precedence first:".to_string()];
+    if resolved.files.is_empty() {
+        lines.push("#   (none, using defaults)".to_string());
+    }
+    for file in &resolved.files {
+        lines.push(format!("#   {}", file.display()));
+    }
+    if let Some(ref profile) = resolved.profile {
+        lines.push(format!("# Profile: {profile}"));
+    }
+
+    for value in &resolved.values {
+        let sources: Vec<String> = value.sources.iter().map(|s| s.to_string()).collect();
+        lines.push(format!(
+            "{}: {}  # {}",
+            value.key,
+            serde_json::to_string(&value.value).unwrap_or_default(),
+            sources.join(" + ")
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_show_resolved() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".adrscan.yml");
+        fs::write(
+            &config_path,
+            "adr_dir: decisions\nexclude_patterns+: [\"**/vendor/**\"]\n",
+        )
+        .unwrap();
+        let mut layers = ConfigLayers::default();
+        layers.add_file(&config_path).unwrap();
+        let resolved = layers.resolved();
+
+        let show = |resolved_flag: bool, format: &str| {
+            ShowArgs {
+                resolved: resolved_flag,
+                format: format.to_string(),
+            }
+            .render(&resolved)
+        };
+
+        let annotated = show(true, "yaml").unwrap();
+        assert!(annotated.contains(&format!("#   {}", config_path.display())));
+        assert!(annotated.contains(&format!(
+            "adr_dir: \"decisions\"  # {}",
+            config_path.display()
+        )));
+        assert!(annotated.contains(&format!("  # default + {}", config_path.display())));
+        assert!(annotated.contains("template.format: \"madr\"  # default"));
+
+        let json: serde_json::Value = serde_json::from_str(&show(false, "json").unwrap()).unwrap();
+        assert_eq!(json["adr_dir"], "decisions");
+        assert_eq!(json["template"]["format"], "madr");
+        let yaml = show(false, "yaml").unwrap();
+        let config: Config = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(config.adr_dir, std::path::PathBuf::from("decisions"));
+
+        assert!(matches!(
+            show(false, "toml"),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let valid = temp_dir.path().join("valid.yml");
+        let invalid = temp_dir.path().join("invalid.toml");
+        fs::write(
+            &valid,
+            "adr_dir: decisions
+cache:
+  enabld: false
+",
+        )
+        .unwrap();
+        fs::write(
+            &invalid,
+            "[drift]
+enabled = \"no\"\n",
+        )
+        .unwrap();
+
+        let validate = ValidateArgs {
+            files: vec![valid.clone(), invalid.clone()],
+            format: "console".to_string(),
+            deny_warnings: false,
+        };
+        let diagnostics = validate.run(&validate.files).unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].key, "cache.enabld");
+        assert_eq!(
+            diagnostics[1].to_string(),
+            format!(
+                "{}:2:1: error: 'drift.enabled': expected boolean, found string",
+                invalid.display()
+            )
+        );
+        assert_eq!(
+            validate.files(Some(Path::new("other.yml"))).unwrap(),
+            [valid.clone(), invalid.clone()]
+        );
+
+        // Values the schema accepts are checked like a loaded config
+        let bad_regex = temp_dir.path().join("regex.yml");
+        fs::write(
+            &bad_regex,
+            "drift:
+  detection_patterns:
+    - name: Broken
+      file_pattern: \"**/*.rs\"
+      content_pattern: \"(unclosed\"
+      category: test
+",
+        )
+        .unwrap();
+        let diagnostics = validate.run(&[bad_regex]).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0]
+            .message
+            .contains("Invalid regex pattern '(unclosed'"));
+
+        // Loading reports the same errors instead of a bare serde message
+        let mut layers = ConfigLayers::default();
+        layers.add_file(&valid).unwrap();
+        let error = layers.add_file(&invalid).unwrap_err().to_string();
+        assert!(error.contains(&format!("{}:2:1", invalid.display())));
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
     config::Config,
     drift::{DriftEngine, DriftReport, DriftSeverity},
     error::AdrscanError,
+    roots,
 };
 type Result<T> = std::result::Result<T, AdrscanError>;
 
@@ -31,6 +32,12 @@ pub struct DiffCommand {
     /// Save current state as snapshot
     #[arg(long)]
     pub save_snapshot: Option<PathBuf>,
+
+    /// Only rescan files changed since the git commit of the baseline
+    /// snapshot, reusing its results for all other files (a snapshot taken
+    /// with uncommitted changes is always rescanned in full)
+    #[arg(long, requires = "baseline")]
+    pub incremental: bool,
 }
 
 impl DiffCommand {
@@ -44,7 +51,11 @@ impl DiffCommand {
         })?;
 
         rt.block_on(async {
-            let drift_engine = DriftEngine::new();
+            let drift_engine = DriftEngine::new()
+                .with_adr_cache(&config.cache)
+                .with_incremental(self.incremental)
+                .with_scan_patterns(&config.include_patterns, &config.exclude_patterns)?
+                .with_layers(&config.drift.layers);
 
             // Determine directories
             let scan_dir = self
@@ -52,27 +63,33 @@ impl DiffCommand {
                 .clone()
                 .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
-            let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
-
-            // Get detection patterns from config
+            // Check the codebase once against the ADRs of every root that
+            // enables drift detection
+            let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+            let adr_dirs = roots::drift_adr_dirs(&roots);
             let detection_patterns = &config.drift.detection_patterns;
 
-            // Perform drift detection
-            let drift_report = drift_engine
-                .detect_drift(
-                    adr_dir,
-                    &scan_dir,
-                    self.baseline.as_deref(),
-                    detection_patterns,
-                )
-                .await?;
-
-            // Save current snapshot if requested
-            if let Some(ref snapshot_path) = self.save_snapshot {
-                log::info!("Saving current snapshot to: {}", snapshot_path.display());
-                // The snapshot is created internally during drift detection
-                // We would need to expose it from the drift engine to save it
-            }
+            let drift_report = if !adr_dirs.is_empty() {
+                let (report, snapshot) = drift_engine
+                    .detect_drift_with_snapshot(
+                        &adr_dirs,
+                        &scan_dir,
+                        self.baseline.as_deref(),
+                        detection_patterns,
+                    )
+                    .await?;
+
+                // Save current snapshot if requested
+                if let Some(ref snapshot_path) = self.save_snapshot {
+                    snapshot.save(snapshot_path)?;
+                }
+                report
+            } else {
+                if self.save_snapshot.is_some() {
+                    log::warn!("Drift detection is disabled, no snapshot to save");
+                }
+                DriftReport::new(scan_dir.clone(), self.baseline.clone())
+            };
 
             // Output report based on format
             match self.format.as_str() {
@@ -99,14 +116,13 @@ impl DiffCommand {
                 }
             }
 
-            // Exit with error code if critical drift is found
-            let critical_count = drift_report
-                .severity_summary
-                .get(&DriftSeverity::Critical)
-                .unwrap_or(&0);
-
-            if *critical_count > 0 {
-                log::warn!("Found {critical_count} critical drift items");
+            // Exit with error code if drift at the configured severity is found
+            let failing = failing_items(&drift_report, &config.drift.fail_on);
+            if failing > 0 {
+                log::warn!(
+                    "Found {failing} drift items of severity {} or higher",
+                    config.drift.fail_on
+                );
                 std::process::exit(1);
             }
 
@@ -206,6 +222,16 @@ impl DiffCommand {
     }
 }
 
+/// Number of items at least as severe as `threshold`
+fn failing_items(report: &DriftReport, threshold: &DriftSeverity) -> usize {
+    report
+        .severity_summary
+        .iter()
+        .filter(|(severity, _)| severity.is_at_least(threshold))
+        .map(|(_, count)| count)
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,7 +244,7 @@ mod tests {
     #[allow(dead_code)]
     fn create_test_config(adr_dir: &PathBuf) -> Config {
         Config {
-            adr_dir: adr_dir.clone(),
+            adr_dir: adr_dir.clone().into(),
             include_patterns: vec!["**/*.md".to_string()],
             exclude_patterns: vec![],
             snapshot_file: adr_dir.join(".adrscan_snapshot.json"),
@@ -229,7 +255,9 @@ mod tests {
             drift: DriftConfig {
                 enabled: true,
                 detection_patterns: vec![],
+                ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -295,6 +323,7 @@ mod tests {
             directory: None,
             adr_dir: None,
             save_snapshot: None,
+            incremental: false,
         };
 
         assert_eq!(cmd.format, "console");
@@ -314,6 +343,7 @@ mod tests {
             directory: Some(temp_dir.path().to_path_buf()),
             adr_dir: Some(temp_dir.path().join("adr")),
             save_snapshot: Some(save_path.clone()),
+            incremental: false,
         };
 
         assert_eq!(cmd.baseline, Some(baseline_path));
@@ -333,6 +363,7 @@ mod tests {
             directory: None,
             adr_dir: None,
             save_snapshot: None,
+            incremental: false,
         };
 
         // This should not panic and should print "No drift detected"
@@ -350,6 +381,7 @@ mod tests {
             directory: None,
             adr_dir: None,
             save_snapshot: None,
+            incremental: false,
         };
 
         // This should not panic and should show drift items
@@ -439,6 +471,23 @@ mod tests {
         assert_eq!(medium_items[0].title, "New framework detected: Axum");
     }
 
+    #[test]
+    fn test_drift_report_dedup_items() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut report = create_test_drift_report(temp_dir.path());
+        let duplicate = report.items[1].clone();
+        report.add_item(duplicate);
+        assert_eq!(report.total_items, 4);
+
+        report.dedup_items();
+        assert_eq!(report.total_items, 3);
+        assert_eq!(report.severity_summary.get(&DriftSeverity::High), Some(&1));
+        assert_eq!(
+            report.category_summary.get(&DriftCategory::NewTechnology),
+            Some(&2)
+        );
+    }
+
     #[test]
     fn test_drift_report_items_by_category() {
         let temp_dir = TempDir::new().unwrap();
@@ -522,6 +571,7 @@ mod tests {
                 directory: None,
                 adr_dir: None,
                 save_snapshot: None,
+                incremental: false,
             };
             assert!(valid_formats.contains(&cmd.format.as_str()));
         }
@@ -646,9 +696,20 @@ mod tests {
             directory: None,
             adr_dir: None,
             save_snapshot: None,
+            incremental: false,
         };
 
         // Should not panic with large report
         cmd.print_console_report(&report);
     }
+
+    #[test]
+    fn test_failing_items_by_threshold() {
+        let temp_dir = TempDir::new().unwrap();
+        let report = create_test_drift_report(temp_dir.path());
+
+        assert_eq!(failing_items(&report, &DriftSeverity::Critical), 1);
+        assert_eq!(failing_items(&report, &DriftSeverity::High), 2);
+        assert_eq!(failing_items(&report, &DriftSeverity::Info), 3);
+    }
 }
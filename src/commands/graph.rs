@@ -0,0 +1,133 @@
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::{
+    config::Config,
+    error::AdrscanError,
+    graph::{AdrGraph, RenderOptions},
+    roots,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct GraphCommand {
+    /// ADR directory to graph (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Output format (dot, mermaid)
+    #[arg(short, long, default_value = "dot")]
+    pub format: String,
+
+    /// Write the graph to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Only render ADRs reachable from this ADR (id, number or file name)
+    #[arg(long)]
+    pub root: Option<String>,
+
+    /// Cluster ADRs by their first tag
+    #[arg(long)]
+    pub group_by_tag: bool,
+}
+
+impl GraphCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let rendered = self.render(config)?;
+
+        match self.output {
+            Some(ref path) => {
+                std::fs::write(path, rendered)?;
+                println!("✅ ADR graph written to {}", path.display());
+            }
+            None => print!("{rendered}"),
+        }
+
+        Ok(())
+    }
+
+    /// Build the graph and render it in the requested format
+    fn render(&self, config: &Config) -> Result<String> {
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+        let documents = roots::load_documents(&roots, config);
+        let graph = AdrGraph::from_documents(&documents);
+
+        let root = match self.root {
+            Some(ref reference) => Some(graph.resolve(reference).ok_or_else(|| {
+                AdrscanError::InvalidArgument(format!("No ADR matches '{reference}'"))
+            })?),
+            None => None,
+        };
+        let options = RenderOptions {
+            group_by_tag: self.group_by_tag,
+            root,
+        };
+
+        match self.format.as_str() {
+            "dot" => Ok(graph.to_dot(&options)),
+            "mermaid" => Ok(graph.to_mermaid(&options)),
+            _ => Err(AdrscanError::InvalidArgument(format!(
+                "Unsupported output format: {}. Use 'dot' or 'mermaid'",
+                self.format
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn graph_command(adr_dir: PathBuf, format: &str, root: Option<&str>) -> GraphCommand {
+        GraphCommand {
+            adr_dir: Some(adr_dir),
+            format: format.to_string(),
+            output: None,
+            root: root.map(|r| r.to_string()),
+            group_by_tag: false,
+        }
+    }
+
+    #[test]
+    fn test_graph_render() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            adr_dir.join("0001-use-mysql.md"),
+            "---\ntitle: Use MySQL\nstatus: superseded\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0002-use-postgres.md"),
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\nsupersedes: [\"0001\"]\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0003-logging.md"),
+            "---\ntitle: Logging\nstatus: proposed\n---\n",
+        )
+        .unwrap();
+        let config = Config::default();
+
+        let dot = graph_command(adr_dir.clone(), "dot", None)
+            .render(&config)
+            .unwrap();
+        assert!(dot.contains("adr1 -> adr0 [label=\"supersedes\"];"));
+        assert!(dot.contains("Logging"));
+
+        let mermaid = graph_command(adr_dir.clone(), "mermaid", Some("ADR-0002"))
+            .render(&config)
+            .unwrap();
+        assert!(mermaid.contains("adr1 -->|supersedes| adr0"));
+        assert!(!mermaid.contains("Logging"));
+
+        let missing = graph_command(adr_dir.clone(), "dot", Some("0009")).render(&config);
+        assert!(matches!(missing, Err(AdrscanError::InvalidArgument(_))));
+
+        let unsupported = graph_command(adr_dir, "svg", None).render(&config);
+        assert!(matches!(unsupported, Err(AdrscanError::InvalidArgument(_))));
+    }
+}
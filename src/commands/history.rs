@@ -0,0 +1,138 @@
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::{
+    config::Config,
+    error::AdrscanError,
+    git::{AdrHistory, GitRepository},
+    graph::AdrGraph,
+    roots,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct HistoryCommand {
+    /// ADR to show (id, number or file name)
+    pub id: String,
+
+    /// ADR directory (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Output format (console, json)
+    #[arg(short, long, default_value = "console")]
+    pub format: String,
+}
+
+impl HistoryCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let history = self.run(config)?;
+
+        match self.format.as_str() {
+            "json" => {
+                let json = serde_json::to_string_pretty(&history)
+                    .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+                println!("{json}");
+            }
+            "console" => print_console(&history),
+            _ => {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Unsupported output format: {}. Use 'console' or 'json'",
+                    self.format
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the ADR and load its history from git
+    fn run(&self, config: &Config) -> Result<AdrHistory> {
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+        let documents = roots::load_documents(&roots, config);
+        let graph = AdrGraph::from_documents(&documents);
+        let node = graph.resolve(&self.id).ok_or_else(|| {
+            AdrscanError::InvalidArgument(format!("No ADR matches '{}'", self.id))
+        })?;
+        let path = &graph.nodes[node].path;
+
+        let repo = GitRepository::discover(path).ok_or_else(|| {
+            AdrscanError::GitError(format!("{} is not inside a git repository", path.display()))
+        })?;
+        AdrHistory::load(&repo, path)
+    }
+}
+
+fn print_console(history: &AdrHistory) {
+    println!("📜 History of {}", history.path.display());
+    println!("{}", "=".repeat(40));
+
+    if history.entries.is_empty() {
+        println!("No committed versions found.");
+        return;
+    }
+
+    for entry in &history.entries {
+        println!(
+            "\n{} {} <{}>  {}",
+            &entry.commit[..entry.commit.len().min(8)],
+            entry.author,
+            entry.email,
+            entry.date.format("%Y-%m-%d %H:%M")
+        );
+        println!("  {}", entry.summary);
+        for change in &entry.changes {
+            println!("  • {change}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::history::HistoryChange;
+    use crate::git::test_support::{commit_all, init_repo};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_history_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_repo(root);
+        let adr_dir = root.join("docs/adr");
+        fs::create_dir_all(&adr_dir).unwrap();
+        let path = adr_dir.join("0003-cache.md");
+
+        fs::write(&path, "---\ntitle: Cache\nstatus: proposed\n---\n").unwrap();
+        commit_all(root, "Alice", "Propose cache");
+        fs::write(&path, "---\ntitle: Cache\nstatus: accepted\n---\n").unwrap();
+        commit_all(root, "Bob", "Accept cache");
+
+        let cmd = HistoryCommand {
+            id: "ADR-3".to_string(),
+            adr_dir: Some(adr_dir.clone()),
+            format: "json".to_string(),
+        };
+        let history = cmd.run(&Config::default()).unwrap();
+
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.entries[1].author, "Bob");
+        assert_eq!(
+            history.entries[1].changes,
+            vec![HistoryChange::StatusChanged {
+                from: "proposed".to_string(),
+                to: "accepted".to_string(),
+            }]
+        );
+
+        let missing = HistoryCommand {
+            id: "0009".to_string(),
+            ..cmd
+        };
+        assert!(matches!(
+            missing.run(&Config::default()),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+    }
+}
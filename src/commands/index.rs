@@ -3,7 +3,14 @@ use clap::Args;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use crate::{config::Config, error::AdrscanError, parser::AdrParser};
+use crate::{
+    config::Config,
+    error::AdrscanError,
+    graph::{AdrGraph, AdrNode, EdgeKind},
+    parser::AdrCache,
+    roots,
+    status::{self, StatusModel},
+};
 type Result<T> = std::result::Result<T, AdrscanError>;
 
 #[derive(Args)]
@@ -45,66 +52,107 @@ pub struct AdrIndexEntry {
     pub status: String,
     pub date: Option<NaiveDate>,
     pub deciders: Vec<String>,
-    #[allow(dead_code)] // Planned for enhanced templates
     pub tags: Vec<String>,
-    #[allow(dead_code)] // Planned for enhanced templates
     pub supersedes: Vec<String>,
-    #[allow(dead_code)] // Planned for enhanced templates
     pub relates_to: Vec<String>,
 }
 
+impl AdrIndexEntry {
+    /// Relationship graph node for this entry of a root with `namespace`
+    fn graph_node(&self, namespace: Option<&str>) -> AdrNode {
+        AdrNode {
+            path: self.file_path.clone(),
+            id: self.id.clone(),
+            title: self.title.clone(),
+            status: self.status.clone(),
+            tags: self.tags.clone(),
+            supersedes: self.supersedes.clone(),
+            relates_to: self.relates_to.clone(),
+            namespace: namespace.map(str::to_string),
+        }
+    }
+}
+
 impl IndexCommand {
     pub fn execute(&self, config: &Config) -> Result<()> {
         log::info!("Generating ADR index...");
 
-        let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
-
-        // Validate ADR directory exists
-        if !adr_dir.exists() {
-            return Err(AdrscanError::DirectoryNotFound(format!(
-                "ADR directory not found: {}",
-                adr_dir.display()
-            )));
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+        if self.output.is_some() && roots.len() > 1 {
+            return Err(AdrscanError::InvalidArgument(
+                "--output needs a single ADR root; select one with --adr-dir".to_string(),
+            ));
         }
 
-        // Scan for ADR files and build index entries
-        let mut adr_entries = self.scan_and_parse_adrs(adr_dir)?;
-        log::info!("Found {} ADR files", adr_entries.len());
-
-        // Apply status filter if specified
-        if let Some(ref status_filter) = self.status_filter {
-            adr_entries.retain(|entry| entry.status.eq_ignore_ascii_case(status_filter));
+        // Scan every root for ADR files and build index entries
+        let mut root_entries = Vec::new();
+        for root in &roots {
+            let mut cache = AdrCache::open(&root.path, &config.cache);
+            let entries = self.scan_and_parse_adrs(&root.path, &mut cache)?;
+            cache.save_or_warn();
             log::info!(
-                "Filtered to {} ADRs with status '{}'",
-                adr_entries.len(),
-                status_filter
+                "Found {} ADR files in {}",
+                entries.len(),
+                root.path.display()
             );
+            root_entries.push(entries);
         }
 
-        // Sort entries according to specified criteria
-        self.sort_entries(&mut adr_entries);
+        // Resolve relationships across all ADRs before filters are applied
+        let graph = AdrGraph::from_nodes(
+            roots
+                .iter()
+                .zip(&root_entries)
+                .flat_map(|(root, entries)| {
+                    entries
+                        .iter()
+                        .map(|e| e.graph_node(root.namespace.as_deref()))
+                })
+                .collect(),
+        );
 
-        // Determine output path
-        let output_path = self.get_output_path(adr_dir);
+        let statuses = StatusModel::new(&config.status);
+        for (root, mut adr_entries) in roots.iter().zip(root_entries) {
+            // Apply status filter if specified
+            if let Some(ref status_filter) = self.status_filter {
+                let status_filter_key = status::normalize(status_filter);
+                adr_entries.retain(|entry| status::normalize(&entry.status) == status_filter_key);
+                log::info!(
+                    "Filtered to {} ADRs with status '{}'",
+                    adr_entries.len(),
+                    status_filter
+                );
+            }
 
-        // Generate index content
-        let index_content = if let Some(ref template_path) = self.template {
-            self.generate_custom_index(&adr_entries, template_path, adr_dir)?
-        } else {
-            self.generate_default_index(&adr_entries, adr_dir)?
-        };
+            // Sort entries according to specified criteria
+            self.sort_entries(&mut adr_entries, &statuses);
+
+            // Determine output path
+            let output_path = self.get_output_path(&root.path);
+
+            // Generate index content
+            let index_content = if let Some(ref template_path) = self.template {
+                self.generate_custom_index(&adr_entries, &graph, template_path)?
+            } else {
+                self.generate_default_index(&adr_entries, &graph, &statuses)?
+            };
 
-        // Write index file
-        std::fs::write(&output_path, index_content).map_err(AdrscanError::Io)?;
+            // Write index file
+            std::fs::write(&output_path, index_content).map_err(AdrscanError::Io)?;
 
-        println!("✅ ADR index generated: {}", output_path.display());
-        println!("📋 Indexed {} ADRs", adr_entries.len());
+            println!("✅ ADR index generated: {}", output_path.display());
+            println!("📋 Indexed {} ADRs", adr_entries.len());
+        }
 
         Ok(())
     }
 
     /// Scan ADR directory and parse all ADR files
-    fn scan_and_parse_adrs(&self, adr_dir: &PathBuf) -> Result<Vec<AdrIndexEntry>> {
+    fn scan_and_parse_adrs(
+        &self,
+        adr_dir: &PathBuf,
+        cache: &mut AdrCache,
+    ) -> Result<Vec<AdrIndexEntry>> {
         let mut entries = Vec::new();
 
         for entry in walkdir::WalkDir::new(adr_dir)
@@ -116,7 +164,7 @@ impl IndexCommand {
             if path.is_file() {
                 if let Some(extension) = path.extension() {
                     if extension == "md" || extension == "markdown" {
-                        match self.parse_adr_entry(path, adr_dir) {
+                        match self.parse_adr_entry(cache, path, adr_dir) {
                             Ok(adr_entry) => entries.push(adr_entry),
                             Err(e) => {
                                 log::warn!("Failed to parse ADR file {}: {}", path.display(), e);
@@ -134,10 +182,11 @@ impl IndexCommand {
     /// Parse a single ADR file into an index entry
     fn parse_adr_entry(
         &self,
+        cache: &mut AdrCache,
         file_path: &std::path::Path,
         adr_dir: &PathBuf,
     ) -> Result<AdrIndexEntry> {
-        let adr_doc = AdrParser::parse_file(file_path)?;
+        let adr_doc = cache.parse_file(file_path)?;
 
         // Calculate relative path from ADR directory
         let relative_path = file_path
@@ -197,7 +246,7 @@ impl IndexCommand {
     }
 
     /// Sort entries according to specified criteria
-    fn sort_entries(&self, entries: &mut Vec<AdrIndexEntry>) {
+    fn sort_entries(&self, entries: &mut Vec<AdrIndexEntry>, statuses: &StatusModel) {
         match self.sort.as_str() {
             "number" => {
                 entries.sort_by(|a, b| match (&a.number, &b.number) {
@@ -218,8 +267,8 @@ impl IndexCommand {
             "status" => {
                 entries.sort_by(|a, b| {
                     let status_order = self
-                        .get_status_order(&a.status)
-                        .cmp(&self.get_status_order(&b.status));
+                        .get_status_order(&a.status, statuses)
+                        .cmp(&self.get_status_order(&b.status, statuses));
                     if status_order == std::cmp::Ordering::Equal {
                         a.title.cmp(&b.title)
                     } else {
@@ -232,23 +281,47 @@ impl IndexCommand {
             }
             _ => {
                 log::warn!("Unknown sort criteria: {}, using 'number'", self.sort);
-                self.sort_entries(entries); // Recursively call with number sorting
+                self.sort_entries(entries, statuses); // Recursively call with number sorting
             }
         }
     }
 
-    /// Get status ordering for sorting (accepted first, then others)
-    fn get_status_order(&self, status: &str) -> u8 {
-        match status.to_lowercase().as_str() {
+    /// Get status ordering for sorting (accepted first, then the other
+    /// built-in states, then custom states in their configured order)
+    fn get_status_order(&self, status: &str, statuses: &StatusModel) -> usize {
+        match status::normalize(status).as_str() {
             "accepted" => 1,
             "proposed" => 2,
             "rejected" => 3,
             "deprecated" => 4,
             "superseded" => 5,
-            _ => 6,
+            _ => 6 + statuses.position(status).unwrap_or(statuses.states().len()),
         }
     }
 
+    /// Regenerate `index.md` in `adr_dir` if one has been generated before
+    ///
+    /// Used by commands that edit ADRs so an existing index stays in sync.
+    /// Badges are kept when the existing index has them.
+    pub fn refresh(adr_dir: &Path, config: &Config) -> Result<bool> {
+        let index_path = adr_dir.join("index.md");
+        if !index_path.exists() {
+            return Ok(false);
+        }
+
+        let existing = std::fs::read_to_string(&index_path)?;
+        let index = IndexCommand {
+            adr_dir: Some(adr_dir.to_path_buf()),
+            output: None,
+            sort: "number".to_string(),
+            badges: existing.contains("img.shields.io/badge/Status-"),
+            template: None,
+            status_filter: None,
+        };
+        index.execute(config)?;
+        Ok(true)
+    }
+
     /// Determine output path for index file
     fn get_output_path(&self, adr_dir: &Path) -> PathBuf {
         if let Some(ref output) = self.output {
@@ -259,7 +332,12 @@ impl IndexCommand {
     }
 
     /// Generate default index content
-    fn generate_default_index(&self, entries: &[AdrIndexEntry], _adr_dir: &Path) -> Result<String> {
+    fn generate_default_index(
+        &self,
+        entries: &[AdrIndexEntry],
+        graph: &AdrGraph,
+        statuses: &StatusModel,
+    ) -> Result<String> {
         let mut content = String::new();
 
         // Header
@@ -278,7 +356,7 @@ impl IndexCommand {
 
         // Status summary
         if !entries.is_empty() {
-            let status_counts = self.get_status_counts(entries);
+            let status_counts = self.get_status_counts(entries, statuses);
             content.push_str("## Status Summary\n\n");
             for (status, count) in status_counts {
                 let badge = if self.badges {
@@ -338,6 +416,25 @@ impl IndexCommand {
 
         content.push('\n');
 
+        // Relationships between listed ADRs
+        let relationships = self.relationship_lines(entries, graph);
+        if !relationships.is_empty() {
+            content.push_str("## Relationships\n\n");
+            for line in relationships {
+                content.push_str(&format!("- {line}\n"));
+            }
+            content.push('\n');
+        }
+
+        let issues = graph.issues();
+        if !issues.is_empty() {
+            content.push_str("## Relationship Warnings\n\n");
+            for issue in issues {
+                content.push_str(&format!("- ⚠️ {issue}\n"));
+            }
+            content.push('\n');
+        }
+
         // Footer
         content.push_str("---\n\n");
         content.push_str("*This index was automatically generated by [ADRScan](https://github.com/tbowman01/PhotonDrift).*\n");
@@ -349,8 +446,8 @@ impl IndexCommand {
     fn generate_custom_index(
         &self,
         entries: &[AdrIndexEntry],
+        graph: &AdrGraph,
         template_path: &PathBuf,
-        _adr_dir: &Path,
     ) -> Result<String> {
         let template_content = std::fs::read_to_string(template_path).map_err(AdrscanError::Io)?;
 
@@ -384,11 +481,49 @@ impl IndexCommand {
         }
         content = content.replace("{{ADR_LIST}}", &adr_list);
 
+        let relationships: String = self
+            .relationship_lines(entries, graph)
+            .into_iter()
+            .map(|line| format!("- {line}\n"))
+            .collect();
+        content = content.replace("{{RELATIONSHIPS}}", &relationships);
+
         Ok(content)
     }
 
+    /// "A supersedes B" / "A relates to B" lines for edges starting at listed ADRs
+    fn relationship_lines(&self, entries: &[AdrIndexEntry], graph: &AdrGraph) -> Vec<String> {
+        let link = |node: usize| {
+            let path = &graph.nodes[node].path;
+            match entries.iter().find(|e| &e.file_path == path) {
+                Some(entry) => format!("[{}]({})", entry.title, entry.relative_path),
+                None => graph.nodes[node].label(),
+            }
+        };
+
+        let mut lines = Vec::new();
+        for entry in entries {
+            let Some(node) = graph.node_by_path(&entry.file_path) else {
+                continue;
+            };
+            for (kind, verb) in [
+                (EdgeKind::Supersedes, "supersedes"),
+                (EdgeKind::RelatesTo, "relates to"),
+            ] {
+                for target in graph.outgoing(node, kind) {
+                    lines.push(format!("{} {verb} {}", link(node), link(target)));
+                }
+            }
+        }
+        lines
+    }
+
     /// Get status counts for summary
-    fn get_status_counts(&self, entries: &[AdrIndexEntry]) -> Vec<(String, usize)> {
+    fn get_status_counts(
+        &self,
+        entries: &[AdrIndexEntry],
+        statuses: &StatusModel,
+    ) -> Vec<(String, usize)> {
         let mut counts: HashMap<String, usize> = HashMap::new();
 
         for entry in entries {
@@ -397,8 +532,8 @@ impl IndexCommand {
 
         let mut sorted_counts: Vec<_> = counts.into_iter().collect();
         sorted_counts.sort_by(|a, b| {
-            self.get_status_order(&a.0)
-                .cmp(&self.get_status_order(&b.0))
+            self.get_status_order(&a.0, statuses)
+                .cmp(&self.get_status_order(&b.0, statuses))
         });
 
         sorted_counts
@@ -436,7 +571,7 @@ mod tests {
 
     fn create_test_config(adr_dir: &PathBuf) -> Config {
         Config {
-            adr_dir: adr_dir.clone(),
+            adr_dir: adr_dir.clone().into(),
             include_patterns: vec!["**/*.md".to_string()],
             exclude_patterns: vec![],
             snapshot_file: adr_dir.join(".adrscan_snapshot.json"),
@@ -447,7 +582,9 @@ mod tests {
             drift: crate::config::DriftConfig {
                 enabled: true,
                 detection_patterns: vec![],
+                ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -585,6 +722,94 @@ status: accepted
         assert!(content.contains("![Accepted](https://img.shields.io/badge/Status-Accepted-green)"));
     }
 
+    #[test]
+    fn test_index_with_relationships() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        let config = create_test_config(&adr_dir);
+
+        create_test_adr(
+            &adr_dir,
+            "0001-use-mysql.md",
+            "---\ntitle: \"Use MySQL\"\nstatus: accepted\n---\n",
+        );
+        create_test_adr(
+            &adr_dir,
+            "0002-use-postgres.md",
+            "---\ntitle: \"Use PostgreSQL\"\nstatus: accepted\nsupersedes: [\"0001\"]\nrelates_to: [\"0007\"]\n---\n",
+        );
+
+        let cmd = IndexCommand {
+            adr_dir: Some(adr_dir.clone()),
+            output: None,
+            sort: "number".to_string(),
+            badges: false,
+            template: None,
+            status_filter: None,
+        };
+        cmd.execute(&config).unwrap();
+
+        let content = fs::read_to_string(adr_dir.join("index.md")).unwrap();
+        assert!(content.contains(
+            "- [Use PostgreSQL](0002-use-postgres.md) supersedes [Use MySQL](0001-use-mysql.md)"
+        ));
+        assert!(content
+            .contains("0001-use-mysql is still accepted but superseded by 0002-use-postgres"));
+        assert!(content.contains("relates_to reference '0007' matches no ADR"));
+    }
+
+    #[test]
+    fn test_index_every_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        let global = base.join("docs/adr");
+        let payments = base.join("services/payments/docs/adr");
+        fs::create_dir_all(&global).unwrap();
+        fs::create_dir_all(&payments).unwrap();
+        create_test_adr(
+            &global,
+            "0002-use-postgres.md",
+            "---\nid: \"0002\"\ntitle: \"Use PostgreSQL\"\nstatus: accepted\n---\n",
+        );
+        create_test_adr(
+            &payments,
+            "0001-ledger.md",
+            "---\nid: \"0001\"\ntitle: \"Ledger\"\nstatus: accepted\nrelates_to: [\"0002\"]\n---\n",
+        );
+
+        let config = Config {
+            adr_dir: crate::config::AdrDirs::from(vec![
+                base.join("services/*/docs/adr").into(),
+                global.clone().into(),
+            ]),
+            ..create_test_config(&global)
+        };
+        let mut cmd = IndexCommand {
+            adr_dir: None,
+            output: None,
+            sort: "number".to_string(),
+            badges: false,
+            template: None,
+            status_filter: None,
+        };
+        cmd.execute(&config).unwrap();
+
+        let content = fs::read_to_string(global.join("index.md")).unwrap();
+        assert!(content.contains("[Use PostgreSQL](0002-use-postgres.md)"));
+        assert!(!content.contains("Ledger"));
+
+        // References resolve across roots
+        let content = fs::read_to_string(payments.join("index.md")).unwrap();
+        assert!(content.contains("- [Ledger](0001-ledger.md) relates to"));
+        assert!(!content.contains("matches no ADR"));
+
+        cmd.output = Some(base.join("index.md"));
+        assert!(matches!(
+            cmd.execute(&config),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+    }
+
     #[test]
     fn test_index_with_status_filter() {
         let temp_dir = TempDir::new().unwrap();
@@ -679,7 +904,7 @@ status: proposed
             template: None,
             status_filter: None,
         };
-        cmd.sort_entries(&mut entries);
+        cmd.sort_entries(&mut entries, &StatusModel::default());
         assert_eq!(entries[0].number, Some(1));
         assert_eq!(entries[1].number, Some(2));
 
@@ -692,7 +917,7 @@ status: proposed
             template: None,
             status_filter: None,
         };
-        cmd.sort_entries(&mut entries);
+        cmd.sort_entries(&mut entries, &StatusModel::default());
         assert_eq!(entries[0].title, "A Title");
         assert_eq!(entries[1].title, "Z Title");
     }
@@ -858,11 +1083,20 @@ status: accepted
             status_filter: None,
         };
 
-        assert_eq!(cmd.get_status_order("accepted"), 1);
-        assert_eq!(cmd.get_status_order("proposed"), 2);
-        assert_eq!(cmd.get_status_order("rejected"), 3);
-        assert_eq!(cmd.get_status_order("deprecated"), 4);
-        assert_eq!(cmd.get_status_order("superseded"), 5);
-        assert_eq!(cmd.get_status_order("unknown"), 6);
+        let statuses = StatusModel::default();
+        assert_eq!(cmd.get_status_order("accepted", &statuses), 1);
+        assert_eq!(cmd.get_status_order("proposed", &statuses), 2);
+        assert_eq!(cmd.get_status_order("rejected", &statuses), 3);
+        assert_eq!(cmd.get_status_order("deprecated", &statuses), 4);
+        assert_eq!(cmd.get_status_order("superseded", &statuses), 5);
+        assert_eq!(cmd.get_status_order("unknown", &statuses), 11);
+
+        // Custom states sort after the built-in ones, in configured order
+        let custom = StatusModel::new(&crate::config::StatusConfig {
+            states: vec!["draft".to_string(), "In Review".to_string()],
+            transitions: HashMap::new(),
+        });
+        assert_eq!(cmd.get_status_order("in-review", &custom), 7);
+        assert_eq!(cmd.get_status_order("unknown", &custom), 8);
     }
 }
@@ -1,7 +1,7 @@
 use clap::Args;
 use std::path::PathBuf;
 
-use crate::{config::Config, error::AdrscanError};
+use crate::{config::Config, error::AdrscanError, roots};
 type Result<T> = std::result::Result<T, AdrscanError>;
 
 #[derive(Args)]
@@ -20,7 +20,16 @@ impl InitCommand {
         log::info!("Initializing ADR directory and configuration...");
 
         // Determine ADR directory path
-        let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
+        let adr_dir = match self.adr_dir {
+            Some(ref adr_dir) => adr_dir,
+            None => Self::configured_dir(config)?,
+        };
+        if roots::is_glob(&adr_dir.to_string_lossy()) {
+            return Err(AdrscanError::InvalidArgument(format!(
+                "Cannot initialize ADR root pattern '{}'; pass the directory with --adr-dir",
+                adr_dir.display()
+            )));
+        }
 
         // Check if directory already exists
         if adr_dir.exists() && !self.force && adr_dir.read_dir()?.next().is_some() {
@@ -65,6 +74,18 @@ impl InitCommand {
         Ok(())
     }
 
+    /// The configured root new ADRs go to: the first one without a
+    /// namespace, else the first one
+    fn configured_dir(config: &Config) -> Result<&PathBuf> {
+        let roots = config.adr_dir.roots();
+        roots
+            .iter()
+            .find(|root| root.namespace.is_none())
+            .or_else(|| roots.first())
+            .map(|root| &root.path)
+            .ok_or_else(|| AdrscanError::ConfigError("adr_dir lists no directory".to_string()))
+    }
+
     fn create_config_file(&self, adr_dir: &Path) -> Result<()> {
         let config_path = std::path::Path::new(".adrscan.yml");
 
@@ -74,7 +95,7 @@ impl InitCommand {
         }
 
         let config = Config {
-            adr_dir: adr_dir.to_path_buf(),
+            adr_dir: adr_dir.to_path_buf().into(),
             ..Config::default()
         };
 
@@ -316,4 +337,27 @@ mod tests {
         let content = std::fs::read_to_string(adr_dir.join("template.md")).unwrap();
         assert!(content.contains("Title of the decision"));
     }
+
+    #[test]
+    fn test_reject_glob_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config {
+            adr_dir: crate::config::AdrDirs::from(vec![
+                temp_dir.path().join("services/*/docs/adr").into(),
+                temp_dir.path().join("docs/adr").into(),
+            ]),
+            ..Config::default()
+        };
+
+        let cmd = InitCommand {
+            adr_dir: None,
+            force: false,
+        };
+
+        assert!(matches!(
+            cmd.execute(&config),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+        assert!(!temp_dir.path().join("services").exists());
+    }
 }
@@ -1,9 +1,16 @@
 use clap::Args;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
-
-use crate::{config::Config, error::AdrscanError, parser::AdrParser};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config::Config,
+    error::AdrscanError,
+    git::{AdrHistory, GitRepository, HistoryEntry},
+    graph::{AdrGraph, AdrNode, GraphIssue},
+    parser::AdrCache,
+    roots::{self, AdrRoot},
+};
 type Result<T> = std::result::Result<T, AdrscanError>;
 
 #[derive(Args)]
@@ -31,6 +38,10 @@ pub struct InventoryCommand {
     /// Include file statistics
     #[arg(long)]
     pub stats: bool,
+
+    /// Include the git history of each ADR (JSON output only)
+    #[arg(long)]
+    pub history: bool,
 }
 
 /// ADR inventory summary
@@ -41,12 +52,18 @@ pub struct AdrInventory {
     pub tag_breakdown: HashMap<String, usize>,
     pub adrs: Vec<AdrSummary>,
     pub statistics: Option<InventoryStats>,
+    /// Cycles, dangling references and stale statuses across all ADRs
+    #[serde(default)]
+    pub relationship_issues: Vec<GraphIssue>,
 }
 
 /// Summary of a single ADR
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AdrSummary {
     pub path: String,
+    /// Namespace of the ADR root the file belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
     pub id: Option<String>,
     pub title: String,
     pub status: String,
@@ -55,6 +72,18 @@ pub struct AdrSummary {
     pub tags: Vec<String>,
     pub file_size: u64,
     pub line_count: usize,
+    /// References as written in `supersedes`
+    #[serde(default)]
+    pub supersedes: Vec<String>,
+    /// ADRs whose `supersedes` resolves to this one
+    #[serde(default)]
+    pub superseded_by: Vec<String>,
+    /// ADRs related through `relates_to` in either direction
+    #[serde(default)]
+    pub related: Vec<String>,
+    /// Commits that changed the status, title or decision (JSON output only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<HistoryEntry>>,
 }
 
 /// Inventory statistics
@@ -71,42 +100,59 @@ impl InventoryCommand {
     pub fn execute(&self, config: &Config) -> Result<()> {
         log::info!("Scanning ADR inventory...");
 
-        let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
-
-        // Validate ADR directory exists
-        if !adr_dir.exists() {
-            return Err(AdrscanError::DirectoryNotFound(format!(
-                "ADR directory not found: {}",
-                adr_dir.display()
-            )));
-        }
-
-        // Scan for ADR files
-        let adr_files = self.scan_adr_files(adr_dir)?;
-        log::info!("Found {} ADR files", adr_files.len());
+        // Validates that the ADR directories exist
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
 
-        // Parse ADR files and build inventory
         let mut adrs = Vec::new();
-        for file_path in adr_files {
-            match self.parse_adr_file(&file_path) {
-                Ok(adr_summary) => {
-                    // Apply filters
-                    if self.should_include_adr(&adr_summary) {
+        let mut nodes = Vec::new();
+        for root in &roots {
+            // Scan for ADR files
+            let adr_files = self.scan_adr_files(&root.path)?;
+            log::info!(
+                "Found {} ADR files in {}",
+                adr_files.len(),
+                root.path.display()
+            );
+
+            // Parse ADR files, reusing unchanged ones from the cache
+            let mut cache = AdrCache::open(&root.path, &config.cache);
+            for file_path in adr_files {
+                match self.parse_adr_file(&mut cache, &file_path) {
+                    Ok((mut adr_summary, mut node)) => {
+                        adr_summary.namespace = root.namespace.clone();
+                        node.namespace = root.namespace.clone();
                         adrs.push(adr_summary);
+                        nodes.push(node);
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to parse ADR file {}: {}", file_path.display(), e);
+                        // Continue processing other files
                     }
-                }
-                Err(e) => {
-                    log::warn!("Failed to parse ADR file {}: {}", file_path.display(), e);
-                    // Continue processing other files
                 }
             }
+            cache.save_or_warn();
+        }
+
+        // Resolve relationships across all ADRs before filters are applied
+        let graph = AdrGraph::from_nodes(nodes);
+        Self::link_relationships(&mut adrs, &graph);
+        adrs.retain(|adr| self.should_include_adr(adr));
+
+        // Git history is only surfaced in machine-readable output
+        if self.history {
+            if self.format == "json" {
+                Self::load_history(&mut adrs, &roots);
+            } else {
+                log::warn!("--history is only included in JSON output");
+            }
         }
 
         // Sort ADRs
         self.sort_adrs(&mut adrs);
 
         // Build inventory summary
-        let inventory = self.build_inventory(adrs)?;
+        let mut inventory = self.build_inventory(adrs)?;
+        inventory.relationship_issues = graph.issues();
 
         // Output results
         self.output_inventory(&inventory)?;
@@ -137,9 +183,14 @@ impl InventoryCommand {
         Ok(adr_files)
     }
 
-    /// Parse a single ADR file into a summary
-    fn parse_adr_file(&self, file_path: &PathBuf) -> Result<AdrSummary> {
-        let adr_doc = AdrParser::parse_file(file_path)?;
+    /// Parse a single ADR file into a summary and its graph node
+    fn parse_adr_file(
+        &self,
+        cache: &mut AdrCache,
+        file_path: &PathBuf,
+    ) -> Result<(AdrSummary, AdrNode)> {
+        let adr_doc = cache.parse_file(file_path)?;
+        let node = AdrNode::from(&adr_doc);
 
         // Get file metadata
         let metadata = std::fs::metadata(file_path).map_err(AdrscanError::Io)?;
@@ -155,8 +206,9 @@ impl InventoryCommand {
             .date
             .map(|d| d.format("%Y-%m-%d").to_string());
 
-        Ok(AdrSummary {
+        let summary = AdrSummary {
             path: file_path.to_string_lossy().to_string(),
+            namespace: adr_doc.namespace,
             id: adr_doc.metadata.id,
             title: adr_doc.metadata.title,
             status: adr_doc.metadata.status,
@@ -165,7 +217,56 @@ impl InventoryCommand {
             tags: adr_doc.metadata.tags,
             file_size,
             line_count,
-        })
+            supersedes: adr_doc.metadata.supersedes,
+            superseded_by: Vec::new(),
+            related: Vec::new(),
+            history: None,
+        };
+
+        Ok((summary, node))
+    }
+
+    /// Fill in resolved relationships; `adrs` must be in graph node order
+    fn link_relationships(adrs: &mut [AdrSummary], graph: &AdrGraph) {
+        let labels = |nodes: Vec<usize>| -> Vec<String> {
+            nodes.into_iter().map(|n| graph.nodes[n].label()).collect()
+        };
+
+        for (node, adr) in adrs.iter_mut().enumerate() {
+            adr.superseded_by = labels(graph.superseded_by(node));
+            adr.related = labels(graph.related(node));
+        }
+    }
+
+    /// Attach git history to each ADR whose root is under git
+    fn load_history(adrs: &mut [AdrSummary], roots: &[AdrRoot]) {
+        let repos: Vec<Option<GitRepository>> = roots
+            .iter()
+            .map(|root| {
+                let repo = GitRepository::discover(&root.path);
+                if repo.is_none() {
+                    log::debug!("{} is not in a git repository", root.path.display());
+                }
+                repo
+            })
+            .collect();
+
+        for adr in adrs {
+            // The innermost root the ADR was found in
+            let repo = roots
+                .iter()
+                .zip(&repos)
+                .filter(|(root, _)| Path::new(&adr.path).starts_with(&root.path))
+                .max_by_key(|(root, _)| root.path.components().count())
+                .and_then(|(_, repo)| repo.as_ref());
+            let Some(repo) = repo else {
+                continue;
+            };
+            match AdrHistory::load(repo, Path::new(&adr.path)) {
+                Ok(history) => adr.history = Some(history.entries),
+                Err(e) => log::warn!("Failed to load git history for {}: {}", adr.path, e),
+            }
+        }
     }
 
     /// Check if ADR should be included based on filters
@@ -276,6 +377,7 @@ impl InventoryCommand {
             tag_breakdown,
             adrs,
             statistics,
+            relationship_issues: Vec::new(),
         })
     }
 
@@ -406,6 +508,15 @@ impl InventoryCommand {
             }
         }
 
+        // Relationship problems
+        if !inventory.relationship_issues.is_empty() {
+            println!();
+            println!("⚠️  Relationship Issues:");
+            for issue in &inventory.relationship_issues {
+                println!("  • {issue}");
+            }
+        }
+
         Ok(())
     }
 }
@@ -418,7 +529,7 @@ mod tests {
 
     fn create_test_config(adr_dir: &PathBuf) -> Config {
         Config {
-            adr_dir: adr_dir.clone(),
+            adr_dir: adr_dir.clone().into(),
             include_patterns: vec!["**/*.md".to_string()],
             exclude_patterns: vec![],
             snapshot_file: adr_dir.join(".adrscan_snapshot.json"),
@@ -429,7 +540,9 @@ mod tests {
             drift: crate::config::DriftConfig {
                 enabled: true,
                 detection_patterns: vec![],
+                ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -452,6 +565,7 @@ mod tests {
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
 
         let result = cmd.execute(&config);
@@ -506,6 +620,7 @@ This is the content of ADR 002.
             tag: None,
             sort_by: "date".to_string(),
             stats: true,
+            history: false,
         };
 
         let result = cmd.execute(&config);
@@ -529,6 +644,7 @@ This is the content of ADR 002.
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
 
         let files = cmd.scan_adr_files(&adr_dir).unwrap();
@@ -569,9 +685,17 @@ Line 3
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
 
-        let summary = cmd.parse_adr_file(&file_path).unwrap();
+        let mut cache = AdrCache::open(
+            &adr_dir,
+            &crate::config::CacheConfig {
+                enabled: false,
+                dir: None,
+            },
+        );
+        let (summary, _) = cmd.parse_adr_file(&mut cache, &file_path).unwrap();
         assert_eq!(summary.title, "Test ADR");
         assert_eq!(summary.status, "accepted");
         assert_eq!(summary.id, Some("001".to_string()));
@@ -617,6 +741,7 @@ status: proposed
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
 
         let result = cmd.execute(&config);
@@ -660,16 +785,62 @@ tags: ["backend", "database"]
             tag: Some("frontend".to_string()),
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
 
         let result = cmd.execute(&config);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_link_relationships() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        let first = create_test_adr(
+            &adr_dir,
+            "0001-first.md",
+            "---\ntitle: First\nstatus: superseded\n---\n",
+        );
+        let second = create_test_adr(
+            &adr_dir,
+            "0002-second.md",
+            "---\ntitle: Second\nstatus: accepted\nsupersedes: [\"1\"]\nrelates_to: [\"1\"]\n---\n",
+        );
+
+        let cmd = InventoryCommand {
+            adr_dir: None,
+            format: "json".to_string(),
+            status: None,
+            tag: None,
+            sort_by: "date".to_string(),
+            stats: false,
+            history: false,
+        };
+        let mut cache = AdrCache::open(
+            &adr_dir,
+            &crate::config::CacheConfig {
+                enabled: false,
+                dir: None,
+            },
+        );
+        let (first, first_node) = cmd.parse_adr_file(&mut cache, &first).unwrap();
+        let (second, second_node) = cmd.parse_adr_file(&mut cache, &second).unwrap();
+        let graph = AdrGraph::from_nodes(vec![first_node, second_node]);
+        let mut adrs = vec![first, second];
+        InventoryCommand::link_relationships(&mut adrs, &graph);
+
+        assert_eq!(adrs[0].superseded_by, vec!["0002-second"]);
+        assert_eq!(adrs[0].related, vec!["0002-second"]);
+        assert_eq!(adrs[1].supersedes, vec!["1"]);
+        assert!(adrs[1].superseded_by.is_empty());
+        assert!(graph.issues().is_empty());
+    }
+
     #[test]
     fn test_should_include_adr() {
         let adr = AdrSummary {
             path: "test.md".to_string(),
+            namespace: None,
             id: Some("001".to_string()),
             title: "Test ADR".to_string(),
             status: "accepted".to_string(),
@@ -678,6 +849,10 @@ tags: ["backend", "database"]
             tags: vec!["frontend".to_string(), "react".to_string()],
             file_size: 100,
             line_count: 10,
+            supersedes: vec![],
+            superseded_by: vec![],
+            related: vec![],
+            history: None,
         };
 
         // Test no filters
@@ -688,6 +863,7 @@ tags: ["backend", "database"]
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
         assert!(cmd.should_include_adr(&adr));
 
@@ -699,6 +875,7 @@ tags: ["backend", "database"]
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
         assert!(cmd.should_include_adr(&adr));
 
@@ -710,6 +887,7 @@ tags: ["backend", "database"]
             tag: None,
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
         assert!(!cmd.should_include_adr(&adr));
 
@@ -721,6 +899,7 @@ tags: ["backend", "database"]
             tag: Some("frontend".to_string()),
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
         assert!(cmd.should_include_adr(&adr));
 
@@ -732,6 +911,7 @@ tags: ["backend", "database"]
             tag: Some("backend".to_string()),
             sort_by: "date".to_string(),
             stats: false,
+            history: false,
         };
         assert!(!cmd.should_include_adr(&adr));
     }
@@ -741,6 +921,7 @@ tags: ["backend", "database"]
         let mut adrs = vec![
             AdrSummary {
                 path: "adr1.md".to_string(),
+                namespace: None,
                 id: Some("001".to_string()),
                 title: "Z Title".to_string(),
                 status: "proposed".to_string(),
@@ -749,9 +930,14 @@ tags: ["backend", "database"]
                 tags: vec![],
                 file_size: 100,
                 line_count: 10,
+                supersedes: vec![],
+                superseded_by: vec![],
+                related: vec![],
+                history: None,
             },
             AdrSummary {
                 path: "adr2.md".to_string(),
+                namespace: None,
                 id: Some("002".to_string()),
                 title: "A Title".to_string(),
                 status: "accepted".to_string(),
@@ -760,6 +946,10 @@ tags: ["backend", "database"]
                 tags: vec![],
                 file_size: 200,
                 line_count: 20,
+                supersedes: vec![],
+                superseded_by: vec![],
+                related: vec![],
+                history: None,
             },
         ];
 
@@ -770,6 +960,7 @@ tags: ["backend", "database"]
             tag: None,
             sort_by: "title".to_string(),
             stats: false,
+            history: false,
         };
 
         cmd.sort_adrs(&mut adrs);
@@ -782,6 +973,7 @@ tags: ["backend", "database"]
         let adrs = vec![
             AdrSummary {
                 path: "adr1.md".to_string(),
+                namespace: None,
                 id: Some("001".to_string()),
                 title: "ADR 1".to_string(),
                 status: "accepted".to_string(),
@@ -790,9 +982,14 @@ tags: ["backend", "database"]
                 tags: vec!["frontend".to_string()],
                 file_size: 100,
                 line_count: 10,
+                supersedes: vec![],
+                superseded_by: vec![],
+                related: vec![],
+                history: None,
             },
             AdrSummary {
                 path: "adr2.md".to_string(),
+                namespace: None,
                 id: Some("002".to_string()),
                 title: "ADR 2".to_string(),
                 status: "proposed".to_string(),
@@ -801,6 +998,10 @@ tags: ["backend", "database"]
                 tags: vec!["backend".to_string(), "frontend".to_string()],
                 file_size: 200,
                 line_count: 20,
+                supersedes: vec![],
+                superseded_by: vec![],
+                related: vec![],
+                history: None,
             },
         ];
 
@@ -811,6 +1012,7 @@ tags: ["backend", "database"]
             tag: None,
             sort_by: "date".to_string(),
             stats: true,
+            history: false,
         };
 
         let inventory = cmd.build_inventory(adrs).unwrap();
@@ -827,4 +1029,48 @@ tags: ["backend", "database"]
         assert_eq!(stats.average_file_size, 150.0);
         assert_eq!(stats.average_lines_per_adr, 15.0);
     }
+
+    #[test]
+    fn test_load_history_per_root() {
+        use crate::git::test_support::{commit_all, init_repo};
+
+        let cmd = InventoryCommand {
+            adr_dir: None,
+            format: "json".to_string(),
+            status: None,
+            tag: None,
+            sort_by: "date".to_string(),
+            stats: false,
+            history: true,
+        };
+        let config = Config::default();
+
+        // Two roots in separate repositories
+        let temp_dir = TempDir::new().unwrap();
+        let mut roots = Vec::new();
+        let mut adrs = Vec::new();
+        for name in ["billing", "search"] {
+            let repo_dir = temp_dir.path().join(name);
+            let adr_dir = repo_dir.join("docs/adr");
+            fs::create_dir_all(&adr_dir).unwrap();
+            init_repo(&repo_dir);
+            let path = create_test_adr(
+                &adr_dir,
+                "0001-use-postgres.md",
+                "---\ntitle: Use Postgres\nstatus: proposed\n---\n\n# Use Postgres\n",
+            );
+            commit_all(&repo_dir, "Alice Example", "Propose Postgres");
+
+            let mut cache = AdrCache::open(&adr_dir, &config.cache);
+            adrs.push(cmd.parse_adr_file(&mut cache, &path).unwrap().0);
+            roots.push(AdrRoot::single(&adr_dir, &config));
+        }
+
+        InventoryCommand::load_history(&mut adrs, &roots);
+        for adr in &adrs {
+            let history = adr.history.as_ref().unwrap();
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].summary, "Propose Postgres");
+        }
+    }
 }
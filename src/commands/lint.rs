@@ -0,0 +1,208 @@
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::{
+    config::{Config, LintLevel},
+    error::AdrscanError,
+    lint::{LintReport, Linter},
+    roots,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct LintCommand {
+    /// ADR directory to lint (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Output format (console, json, sarif)
+    #[arg(short, long, default_value = "console")]
+    pub format: String,
+
+    /// Write the report to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Exit with an error code on warnings as well as errors
+    #[arg(long)]
+    pub deny_warnings: bool,
+
+    /// List the available rules and their configured severity
+    #[arg(long)]
+    pub list_rules: bool,
+}
+
+impl LintCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let linter = Linter::new(&config.lint, &config.status);
+
+        if self.list_rules {
+            self.print_rules(&linter);
+            return Ok(());
+        }
+
+        let report = self.run(config, &linter)?;
+        let rendered = match self.format.as_str() {
+            "json" => Some(report.to_json()),
+            "sarif" => Some(report.to_sarif()),
+            "console" => None,
+            _ => {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Unsupported output format: {}. Use 'console', 'json', or 'sarif'",
+                    self.format
+                )));
+            }
+        };
+
+        match (rendered, &self.output) {
+            (Some(rendered), output) => {
+                let rendered =
+                    rendered.map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+                match output {
+                    Some(path) => {
+                        std::fs::write(path, rendered)?;
+                        println!("📝 Lint report written to {}", path.display());
+                    }
+                    None => println!("{rendered}"),
+                }
+            }
+            (None, Some(_)) => {
+                return Err(AdrscanError::InvalidArgument(
+                    "--output requires --format json or sarif".to_string(),
+                ));
+            }
+            (None, None) => report.print_console(),
+        }
+
+        // Exit with error code if the corpus fails the configured rules
+        if report.is_failure(self.deny_warnings) {
+            log::warn!(
+                "Lint failed with {} errors and {} warnings",
+                report.count(LintLevel::Error),
+                report.count(LintLevel::Warning)
+            );
+            std::process::exit(1);
+        }
+
+        Ok(())
+    }
+
+    /// Lint every ADR in the configured directories
+    fn run(&self, config: &Config, linter: &Linter) -> Result<LintReport> {
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+
+        for name in linter.unknown_rule_names() {
+            log::warn!("Unknown lint rule in configuration: {name}");
+        }
+
+        let report = linter.lint_roots(&roots, chrono::Local::now().date_naive());
+        log::info!("Linted {} ADR files", report.files_checked);
+
+        Ok(report)
+    }
+
+    fn print_rules(&self, linter: &Linter) {
+        println!("📋 Lint Rules");
+        println!("=============");
+        for rule in linter.rule_descriptors() {
+            let level = format!("{:?}", rule.level).to_lowercase();
+            println!("  {:<18} {:<8} {}", rule.name, level, rule.description);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn lint_command(adr_dir: PathBuf) -> LintCommand {
+        LintCommand {
+            adr_dir: Some(adr_dir),
+            format: "console".to_string(),
+            output: None,
+            deny_warnings: false,
+            list_rules: false,
+        }
+    }
+
+    #[test]
+    fn test_lint_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            adr_dir.join("0001-valid.md"),
+            "---\nstatus: accepted\n---\n# Valid\n\n## Context\n\nWhy.\n\n## Decision\n\nWhat.\n",
+        )
+        .unwrap();
+        fs::write(adr_dir.join("0002-broken.md"), "---\nstatus: [\n").unwrap();
+        fs::write(adr_dir.join("index.md"), "# ADR Index\n").unwrap();
+
+        let config = Config::default();
+        let cmd = lint_command(adr_dir.clone());
+        let report = cmd
+            .run(&config, &Linter::new(&config.lint, &config.status))
+            .unwrap();
+
+        assert_eq!(report.files_checked, 2);
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].rule, "parse-error");
+        assert_eq!(report.diagnostics[0].path, adr_dir.join("0002-broken.md"));
+        assert!(report.is_failure(false));
+    }
+
+    #[test]
+    fn test_lint_duplicate_ids_across_roots() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        for dir in ["docs/adr", "services/payments/adr"] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        let adr =
+            "---\nstatus: accepted\n---\n# Title\n\n## Context\n\nWhy.\n\n## Decision\n\nWhat.\n";
+        fs::write(base.join("docs/adr/0001-use-mysql.md"), adr).unwrap();
+        fs::write(base.join("docs/adr/0001-use-redis.md"), adr).unwrap();
+        fs::write(base.join("services/payments/adr/0001-use-postgres.md"), adr).unwrap();
+
+        let config = Config {
+            adr_dir: crate::config::AdrDirs::from(vec![
+                base.join("docs/adr").into(),
+                base.join("services/*/adr").into(),
+            ]),
+            ..Config::default()
+        };
+        let cmd = LintCommand {
+            adr_dir: None,
+            ..lint_command(PathBuf::new())
+        };
+        let report = cmd
+            .run(&config, &Linter::new(&config.lint, &config.status))
+            .unwrap();
+
+        let mut duplicates: Vec<_> = report
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.rule == "duplicate-id")
+            .map(|diagnostic| diagnostic.path.strip_prefix(base).unwrap().to_path_buf())
+            .collect();
+        duplicates.sort();
+        assert_eq!(
+            duplicates,
+            vec![
+                PathBuf::from("docs/adr/0001-use-mysql.md"),
+                PathBuf::from("docs/adr/0001-use-redis.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Config::default();
+        let cmd = lint_command(temp_dir.path().join("missing"));
+
+        let result = cmd.run(&config, &Linter::new(&config.lint, &config.status));
+        assert!(matches!(result, Err(AdrscanError::DirectoryNotFound(_))));
+    }
+}
@@ -1,6 +1,14 @@
+pub mod config;
 pub mod diff;
+pub mod graph;
+pub mod history;
 pub mod index;
 pub mod init;
 pub mod inventory;
+pub mod lint;
+pub mod new;
 pub mod plugin;
 pub mod propose;
+pub mod search;
+pub mod status;
+pub mod supersede;
@@ -0,0 +1,526 @@
+use chrono::NaiveDate;
+use clap::Args;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+use crate::{
+    commands::{propose::ProposeCommand, supersede::SupersedeCommand},
+    config::Config,
+    error::AdrscanError,
+    parser::AdrParser,
+    roots::{self, AdrRoot},
+    status::StatusModel,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Bundled MADR template, also written by `adrscan init`
+const MADR_TEMPLATE: &str = include_str!("../../templates/madr.md");
+
+#[derive(Args)]
+pub struct NewCommand {
+    /// Title of the decision (prompted for when omitted)
+    pub title: Option<String>,
+
+    /// ADR directory where the record will be created (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Namespace of the configured ADR root to create the record in
+    #[arg(short, long, conflicts_with = "adr_dir")]
+    pub namespace: Option<String>,
+
+    /// ADR template to use (madr, custom)
+    #[arg(short, long)]
+    pub template: Option<String>,
+
+    /// Initial status
+    #[arg(long, default_value = "proposed")]
+    pub status: String,
+
+    /// Decision date (YYYY-MM-DD, defaults to today)
+    #[arg(long)]
+    pub date: Option<NaiveDate>,
+
+    /// Decision makers (defaults to git user.name)
+    #[arg(long, value_delimiter = ',')]
+    pub deciders: Vec<String>,
+
+    /// Tags for categorization
+    #[arg(long, value_delimiter = ',')]
+    pub tags: Vec<String>,
+
+    /// ADR replaced by the new one (id, number or file name)
+    #[arg(long)]
+    pub supersedes: Option<String>,
+
+    /// Print the rendered ADR without creating a file
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl NewCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let roots = match self.adr_dir {
+            Some(ref adr_dir) => vec![AdrRoot::single(adr_dir, config)],
+            None => roots::resolve(config, None)?,
+        };
+        let root = self.target_root(&roots)?;
+
+        let interactive = self.title.is_none() && std::io::stdin().is_terminal();
+        let title = match self.title {
+            Some(ref title) => title.clone(),
+            None if interactive => prompt("Title", None)?,
+            None => {
+                return Err(AdrscanError::InvalidArgument(
+                    "A title is required when not running interactively".to_string(),
+                ));
+            }
+        };
+        let mut deciders = self.deciders.clone();
+        let mut tags = self.tags.clone();
+        if interactive {
+            let default = git_config("user.name");
+            if deciders.is_empty() {
+                deciders = split_list(&prompt("Deciders", default.as_deref())?);
+            }
+            if tags.is_empty() {
+                tags = split_list(&prompt("Tags", None)?);
+            }
+        }
+
+        if self.dry_run {
+            let status = self.initial_status(config)?;
+            let (path, content) = self.render(root, &title, &status, deciders, tags)?;
+            println!("📄 Would create {}\n", path.display());
+            print!("{content}");
+            return Ok(());
+        }
+
+        let path = self.create(config, &roots, root, &title, deciders, tags)?;
+        println!("✅ Created {}", path.display());
+        Ok(())
+    }
+
+    /// The root selected by `--namespace`, else the first root without a
+    /// namespace
+    fn target_root<'a>(&self, roots: &'a [AdrRoot]) -> Result<&'a AdrRoot> {
+        match self.namespace {
+            Some(ref namespace) => roots
+                .iter()
+                .find(|root| root.namespace.as_deref() == Some(namespace.as_str()))
+                .ok_or_else(|| {
+                    AdrscanError::InvalidArgument(format!(
+                        "No ADR root has namespace '{namespace}'"
+                    ))
+                }),
+            None => roots::default_root(roots),
+        }
+    }
+
+    /// Write the new ADR into `root` and link it to the one it supersedes,
+    /// which may live in any of `roots`
+    fn create(
+        &self,
+        config: &Config,
+        roots: &[AdrRoot],
+        root: &AdrRoot,
+        title: &str,
+        deciders: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<PathBuf> {
+        let status = self.initial_status(config)?;
+        let (path, content) = self.render(root, title, &status, deciders, tags)?;
+        if path.exists() {
+            return Err(AdrscanError::InvalidArgument(format!(
+                "ADR file already exists: {}",
+                path.display()
+            )));
+        }
+        std::fs::write(&path, content)?;
+
+        if let Some(ref old) = self.supersedes {
+            let supersede = SupersedeCommand {
+                old: old.clone(),
+                // By path: the number may be taken in another root
+                new: path.display().to_string(),
+                adr_dir: None,
+                force: false,
+            };
+            if let Err(e) = supersede.run(config, roots) {
+                // Leave no half-linked record behind
+                let _ = std::fs::remove_file(&path);
+                return Err(e);
+            }
+        }
+
+        Ok(path)
+    }
+
+    /// `--status` in the configured spelling, if it is a configured state
+    fn initial_status(&self, config: &Config) -> Result<String> {
+        let model = StatusModel::new(&config.status);
+        model
+            .canonical(&self.status)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                AdrscanError::InvalidArgument(format!(
+                    "Unknown status '{}' (expected one of: {})",
+                    self.status,
+                    model.states().join(", ")
+                ))
+            })
+    }
+
+    /// Render the ADR, returning the path it belongs at and its content
+    fn render(
+        &self,
+        root: &AdrRoot,
+        title: &str,
+        status: &str,
+        deciders: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<(PathBuf, String)> {
+        let adr_dir = root.path.as_path();
+        if !adr_dir.exists() {
+            return Err(AdrscanError::DirectoryNotFound(format!(
+                "ADR directory not found: {} (run `adrscan init` first)",
+                adr_dir.display()
+            )));
+        }
+        if title.trim().is_empty() {
+            return Err(AdrscanError::InvalidArgument(
+                "ADR title cannot be empty".to_string(),
+            ));
+        }
+
+        let adr_number = ProposeCommand::get_next_adr_number(adr_dir)?;
+        let path = adr_dir.join(format!(
+            "{adr_number:04}-{}.md",
+            ProposeCommand::slugify(title)
+        ));
+
+        let deciders = if deciders.is_empty() {
+            git_config("user.name").into_iter().collect()
+        } else {
+            deciders
+        };
+        let date = self
+            .date
+            .unwrap_or_else(|| chrono::Local::now().date_naive())
+            .format("%Y-%m-%d")
+            .to_string();
+
+        let template_name = self.template.as_ref().unwrap_or(&root.template.format);
+        let template = match template_name.as_str() {
+            "madr" => MADR_TEMPLATE.to_string(),
+            "custom" => {
+                let custom_path = root.template.custom_path.as_ref().ok_or_else(|| {
+                    AdrscanError::ConfigError(
+                        "Custom template specified but no custom_path configured".to_string(),
+                    )
+                })?;
+                std::fs::read_to_string(custom_path).map_err(|e| {
+                    AdrscanError::FileNotFound(format!(
+                        "Cannot read custom template {}: {}",
+                        custom_path.display(),
+                        e
+                    ))
+                })?
+            }
+            _ => {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Unsupported template: {template_name}. Use 'madr' or 'custom'"
+                )));
+            }
+        };
+
+        let replacements = [
+            ("{{ADR_NUMBER}}", format!("{adr_number:04}")),
+            ("{{ADR_TITLE}}", title.to_string()),
+            ("{{DATE}}", date.clone()),
+            ("{{STATUS}}", status.to_string()),
+            ("{{DECIDERS}}", deciders.join(", ")),
+            ("{{TAGS}}", tags.join(", ")),
+            (
+                "{{CONTEXT}}",
+                "What is the issue that is motivating this decision or change?".to_string(),
+            ),
+            (
+                "{{DECISION}}",
+                "What is the change that we are proposing and/or doing?".to_string(),
+            ),
+            (
+                "{{CONSEQUENCES}}",
+                "What becomes easier or more difficult to do because of this change?".to_string(),
+            ),
+        ];
+        let mut content = template;
+        for (placeholder, value) in &replacements {
+            content = content.replace(placeholder, value);
+        }
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+
+        // Record the metadata in frontmatter, keeping whatever format and
+        // extra fields a custom template already declares
+        let mut document = AdrParser::parse_content(&content, path.clone())?;
+        document.set_string_field("title", title)?;
+        document.set_string_field("status", status)?;
+        document.set_string_field("date", &date)?;
+        if !deciders.is_empty() {
+            document.set_list_field("deciders", &deciders)?;
+        }
+        if !tags.is_empty() {
+            document.set_list_field("tags", &tags)?;
+        }
+
+        Ok((path, document.to_markdown()))
+    }
+}
+
+/// Read a value from git config, if git is available and the key is set
+fn git_config(key: &str) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", key])
+        .output()
+        .ok()?;
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !value.is_empty()).then_some(value)
+}
+
+/// Ask for a value on stdin, falling back to `default` on an empty answer
+fn prompt(label: &str, default: Option<&str>) -> Result<String> {
+    match default {
+        Some(default) => print!("{label} [{default}]: "),
+        None => print!("{label}: "),
+    }
+    std::io::stdout().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    let answer = answer.trim();
+    Ok(if answer.is_empty() {
+        default.unwrap_or_default().to_string()
+    } else {
+        answer.to_string()
+    })
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TemplateConfig;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn new_command(title: &str) -> NewCommand {
+        NewCommand {
+            title: Some(title.to_string()),
+            adr_dir: None,
+            namespace: None,
+            template: None,
+            status: "proposed".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 3, 1),
+            deciders: vec!["Alice".to_string()],
+            tags: vec!["database".to_string()],
+            supersedes: None,
+            dry_run: false,
+        }
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_new_from_madr_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(adr_dir.join("0004-existing.md"), "# Existing\n").unwrap();
+
+        let cmd = new_command("Use PostgreSQL");
+        let config = Config::default();
+        let root = AdrRoot::single(adr_dir, &config);
+        let path = cmd
+            .create(
+                &config,
+                std::slice::from_ref(&root),
+                &root,
+                "Use PostgreSQL",
+                strings(&["Alice"]),
+                strings(&["database", "storage"]),
+            )
+            .unwrap();
+
+        assert_eq!(path, adr_dir.join("0005-use-postgresql.md"));
+        let document = AdrParser::parse_file(&path).unwrap();
+        assert_eq!(document.metadata.title, "Use PostgreSQL");
+        assert_eq!(document.metadata.status, "proposed");
+        assert_eq!(document.metadata.date, NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(document.metadata.deciders, vec!["Alice"]);
+        assert_eq!(document.metadata.tags, vec!["database", "storage"]);
+        assert!(document.content.contains("# 0005. Use PostgreSQL"));
+        assert!(document.content.contains("Date: 2024-03-01"));
+        assert!(!document.content.contains("{{"));
+    }
+
+    #[test]
+    fn test_new_from_custom_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().join("adr");
+        fs::create_dir(&adr_dir).unwrap();
+        let template_path = temp_dir.path().join("template.md");
+        fs::write(
+            &template_path,
+            "+++\nowner = \"platform\"\n+++\n\n# {{ADR_TITLE}}\n\nTags: {{TAGS}}\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            template: TemplateConfig {
+                format: "custom".to_string(),
+                custom_path: Some(template_path),
+            },
+            ..Config::default()
+        };
+        let cmd = new_command("Adopt Kafka");
+        let (path, content) = cmd
+            .render(
+                &AdrRoot::single(&adr_dir, &config),
+                "Adopt Kafka",
+                "proposed",
+                Vec::new(),
+                strings(&["messaging"]),
+            )
+            .unwrap();
+
+        assert_eq!(path, adr_dir.join("0001-adopt-kafka.md"));
+        assert!(content.starts_with("+++\nowner = \"platform\"\ntitle = \"Adopt Kafka\""));
+        assert!(content.contains("Tags: messaging"));
+    }
+
+    #[test]
+    fn test_new_validates_status() {
+        let config = Config::default();
+        let mut cmd = new_command("Use PostgreSQL");
+        cmd.status = "Accepted".to_string();
+        assert_eq!(cmd.initial_status(&config).unwrap(), "accepted");
+
+        cmd.status = "approved".to_string();
+        assert!(matches!(
+            cmd.initial_status(&config),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_supersedes_existing_adr() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(
+            adr_dir.join("0001-use-mysql.md"),
+            "---\ntitle: Use MySQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+
+        let config = Config::default();
+        let root = AdrRoot::single(adr_dir, &config);
+        let roots = std::slice::from_ref(&root);
+        let mut cmd = new_command("Use PostgreSQL");
+        cmd.supersedes = Some("0001".to_string());
+        let path = cmd
+            .create(
+                &config,
+                roots,
+                &root,
+                "Use PostgreSQL",
+                strings(&["Alice"]),
+                Vec::new(),
+            )
+            .unwrap();
+
+        let new = AdrParser::parse_file(&path).unwrap();
+        assert_eq!(new.metadata.supersedes, vec!["0001-use-mysql.md"]);
+        let old = AdrParser::parse_file(&adr_dir.join("0001-use-mysql.md")).unwrap();
+        assert_eq!(old.metadata.status, "superseded");
+
+        // An unknown reference leaves no new file behind
+        cmd.supersedes = Some("0042".to_string());
+        let result = cmd.create(
+            &config,
+            roots,
+            &root,
+            "Use CockroachDB",
+            strings(&["Alice"]),
+            Vec::new(),
+        );
+        assert!(matches!(result, Err(AdrscanError::InvalidArgument(_))));
+        assert!(!adr_dir.join("0003-use-cockroachdb.md").exists());
+        assert_eq!(crate::parser::find_adr_files(adr_dir).len(), 2);
+    }
+
+    #[test]
+    fn test_new_supersedes_in_namespace() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        for dir in ["docs/adr", "services/payments/adr"] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        let global = "---\ntitle: Global\nstatus: accepted\n---\n";
+        fs::write(base.join("docs/adr/0001-global-1.md"), global).unwrap();
+        fs::write(base.join("docs/adr/0002-global-2.md"), global).unwrap();
+        fs::write(
+            base.join("services/payments/adr/0001-pay-1.md"),
+            "---\ntitle: Pay\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            adr_dir: crate::config::AdrDirs::from(vec![
+                base.join("docs/adr").into(),
+                base.join("services/*/adr").into(),
+            ]),
+            ..Config::default()
+        };
+        let roots = roots::resolve(&config, None).unwrap();
+        let mut cmd = new_command("Pay replacement");
+        cmd.namespace = Some("payments".to_string());
+        cmd.supersedes = Some("payments/0001".to_string());
+        let root = cmd.target_root(&roots).unwrap();
+        let path = cmd
+            .create(
+                &config,
+                &roots,
+                root,
+                "Pay replacement",
+                Vec::new(),
+                Vec::new(),
+            )
+            .unwrap();
+
+        // The new ADR takes number 2 of its own root, not the global 0002
+        assert_eq!(
+            path,
+            base.join("services/payments/adr/0002-pay-replacement.md")
+        );
+        let new = AdrParser::parse_file(&path).unwrap();
+        assert_eq!(new.metadata.supersedes, vec!["0001-pay-1.md"]);
+        let old = fs::read_to_string(base.join("services/payments/adr/0001-pay-1.md")).unwrap();
+        assert!(old.contains("status: superseded\n"));
+        assert!(old.contains("superseded_by: [\"0002\"]\n"));
+        assert_eq!(
+            fs::read_to_string(base.join("docs/adr/0002-global-2.md")).unwrap(),
+            global
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use chrono::Utc;
 use clap::Args;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 #[cfg(feature = "tokio")]
 use tokio::runtime::Runtime;
 
@@ -8,6 +8,8 @@ use crate::{
     config::Config,
     drift::{DriftCategory, DriftEngine, DriftItem, DriftReport, DriftSeverity},
     error::AdrscanError,
+    parser::{AdrSections, SectionKind},
+    roots::{self, AdrRoot},
 };
 
 type Result<T> = std::result::Result<T, AdrscanError>;
@@ -58,11 +60,16 @@ impl ProposeCommand {
         })?;
 
         rt.block_on(async {
+            let roots = match self.adr_dir {
+                Some(ref adr_dir) => vec![AdrRoot::single(adr_dir, config)],
+                None => roots::resolve(config, None)?,
+            };
+
             // Get or generate drift report
             let drift_report = if let Some(ref drift_file) = self.drift_file {
                 self.load_drift_report(drift_file).await?
             } else {
-                self.generate_drift_report(config).await?
+                self.generate_drift_report(config, &roots).await?
             };
 
             // Filter drift items based on criteria
@@ -78,8 +85,8 @@ impl ProposeCommand {
                 filtered_items.len()
             );
 
-            // Get ADR directory
-            let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
+            // Proposals go where `adrscan new` puts ADRs
+            let adr_dir = &roots::default_root(&roots)?.path;
 
             // Ensure ADR directory exists
             if !adr_dir.exists() {
@@ -161,8 +168,15 @@ impl ProposeCommand {
     }
 
     /// Generate drift report using drift detection engine
-    async fn generate_drift_report(&self, config: &Config) -> Result<DriftReport> {
-        let drift_engine = DriftEngine::new();
+    async fn generate_drift_report(
+        &self,
+        config: &Config,
+        roots: &[AdrRoot],
+    ) -> Result<DriftReport> {
+        let drift_engine = DriftEngine::new()
+            .with_adr_cache(&config.cache)
+            .with_scan_patterns(&config.include_patterns, &config.exclude_patterns)?
+            .with_layers(&config.drift.layers);
 
         // Determine directories
         let scan_dir = self
@@ -170,7 +184,9 @@ impl ProposeCommand {
             .clone()
             .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
-        let adr_dir = self.adr_dir.as_ref().unwrap_or(&config.adr_dir);
+        // Check the codebase against the ADRs of every root that enables
+        // drift detection
+        let adr_dirs = roots::drift_adr_dirs(roots);
 
         // Get detection patterns from config
         let detection_patterns = &config.drift.detection_patterns;
@@ -178,7 +194,7 @@ impl ProposeCommand {
         // Perform drift detection
         drift_engine
             .detect_drift(
-                adr_dir,
+                &adr_dirs,
                 &scan_dir,
                 None, // No baseline for proposals
                 detection_patterns,
@@ -265,6 +281,7 @@ impl ProposeCommand {
             DriftCategory::Infrastructure => true,
             DriftCategory::Framework => true,
             DriftCategory::Configuration => false, // Usually too granular
+            DriftCategory::VersionDrift => true,
             DriftCategory::Other => {
                 item.severity == DriftSeverity::Critical || item.severity == DriftSeverity::High
             }
@@ -275,14 +292,14 @@ impl ProposeCommand {
     async fn generate_adr_proposal(
         &self,
         drift_item: &DriftItem,
-        adr_dir: &PathBuf,
+        adr_dir: &Path,
         config: &Config,
     ) -> Result<PathBuf> {
         // Get next ADR number
-        let adr_number = self.get_next_adr_number(adr_dir).await?;
+        let adr_number = Self::get_next_adr_number(adr_dir)?;
 
         // Generate ADR filename
-        let title_slug = self.slugify(&drift_item.title);
+        let title_slug = Self::slugify(&drift_item.title);
         let filename = format!("{adr_number:04}-{title_slug}.md");
         let adr_path = adr_dir.join(&filename);
 
@@ -308,7 +325,7 @@ impl ProposeCommand {
     }
 
     /// Get the next available ADR number
-    async fn get_next_adr_number(&self, adr_dir: &PathBuf) -> Result<u32> {
+    pub fn get_next_adr_number(adr_dir: &Path) -> Result<u32> {
         let mut max_number = 0;
 
         // Scan existing ADR files
@@ -336,7 +353,7 @@ impl ProposeCommand {
     }
 
     /// Convert title to URL-friendly slug
-    fn slugify(&self, title: &str) -> String {
+    pub fn slugify(title: &str) -> String {
         title
             .to_lowercase()
             .chars()
@@ -513,6 +530,13 @@ Proposed - Generated from drift detection
             content = content.replace(placeholder, value);
         }
 
+        if !AdrSections::parse(&content).has(SectionKind::DecisionOutcome) {
+            log::warn!(
+                "Custom template {} has no Decision section; the proposed ADR will not record a decision",
+                template_path.display()
+            );
+        }
+
         Ok(content)
     }
 
@@ -775,7 +799,7 @@ mod tests {
 
     fn create_test_config(adr_dir: &PathBuf) -> Config {
         Config {
-            adr_dir: adr_dir.clone(),
+            adr_dir: adr_dir.clone().into(),
             include_patterns: vec!["**/*.md".to_string()],
             exclude_patterns: vec![],
             snapshot_file: adr_dir.join(".adrscan_snapshot.json"),
@@ -786,7 +810,9 @@ mod tests {
             drift: DriftConfig {
                 enabled: true,
                 detection_patterns: vec![],
+                ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -1130,29 +1156,18 @@ This is an existing ADR.
         )));
     }
 
-    #[tokio::test]
-    async fn test_get_next_adr_number_empty_directory() {
+    #[test]
+    fn test_get_next_adr_number_empty_directory() {
         let temp_dir = TempDir::new().unwrap();
         let adr_dir = temp_dir.path().to_path_buf();
         fs::create_dir_all(&adr_dir).unwrap();
 
-        let cmd = ProposeCommand {
-            drift_file: None,
-            template: None,
-            directory: None,
-            adr_dir: None,
-            severity: None,
-            category: None,
-            dry_run: false,
-            force: false,
-        };
-
-        let next_number = cmd.get_next_adr_number(&adr_dir).await.unwrap();
+        let next_number = ProposeCommand::get_next_adr_number(&adr_dir).unwrap();
         assert_eq!(next_number, 1);
     }
 
-    #[tokio::test]
-    async fn test_get_next_adr_number_with_existing_adrs() {
+    #[test]
+    fn test_get_next_adr_number_with_existing_adrs() {
         let temp_dir = TempDir::new().unwrap();
         let adr_dir = temp_dir.path().to_path_buf();
         fs::create_dir_all(&adr_dir).unwrap();
@@ -1162,49 +1177,33 @@ This is an existing ADR.
         create_existing_adr(&adr_dir, 3, "third-adr");
         create_existing_adr(&adr_dir, 5, "fifth-adr");
 
-        let cmd = ProposeCommand {
-            drift_file: None,
-            template: None,
-            directory: None,
-            adr_dir: None,
-            severity: None,
-            category: None,
-            dry_run: false,
-            force: false,
-        };
-
-        let next_number = cmd.get_next_adr_number(&adr_dir).await.unwrap();
+        let next_number = ProposeCommand::get_next_adr_number(&adr_dir).unwrap();
         assert_eq!(next_number, 6); // Should be highest + 1
     }
 
     #[test]
     fn test_slugify() {
-        let cmd = ProposeCommand {
-            drift_file: None,
-            template: None,
-            directory: None,
-            adr_dir: None,
-            severity: None,
-            category: None,
-            dry_run: false,
-            force: false,
-        };
-
         assert_eq!(
-            cmd.slugify("Use Redis for Caching"),
+            ProposeCommand::slugify("Use Redis for Caching"),
             "use-redis-for-caching"
         );
         assert_eq!(
-            cmd.slugify("Handle Special!@#$%Characters"),
+            ProposeCommand::slugify("Handle Special!@#$%Characters"),
             "handle-special-characters"
         );
-        assert_eq!(cmd.slugify("Multiple   Spaces"), "multiple-spaces");
-        assert_eq!(cmd.slugify("UPPERCASE Title"), "uppercase-title");
+        assert_eq!(
+            ProposeCommand::slugify("Multiple   Spaces"),
+            "multiple-spaces"
+        );
+        assert_eq!(
+            ProposeCommand::slugify("UPPERCASE Title"),
+            "uppercase-title"
+        );
 
         // Test length limit
         let long_title =
             "This is a very long title that should be truncated to avoid extremely long filenames";
-        let slugified = cmd.slugify(long_title);
+        let slugified = ProposeCommand::slugify(long_title);
         assert!(slugified.len() <= 50);
     }
 
@@ -1710,8 +1709,8 @@ Detected in: {{DETECTED_FILE}}
         );
 
         // Manually simulate what happens inside generate_adr_proposal
-        let adr_number = cmd.get_next_adr_number(&adr_dir).await.unwrap();
-        let title_slug = cmd.slugify(&drift_item.title);
+        let adr_number = ProposeCommand::get_next_adr_number(&adr_dir).unwrap();
+        let title_slug = ProposeCommand::slugify(&drift_item.title);
         let filename = format!("{adr_number:04}-{title_slug}.md");
         let adr_path = adr_dir.join(&filename);
 
@@ -0,0 +1,243 @@
+use clap::Args;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::{
+    config::Config,
+    error::AdrscanError,
+    parser::{cache::WordIndex, AdrCache},
+    roots,
+    search::{Query, SearchHit},
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct SearchCommand {
+    /// Query, e.g. status:accepted tag:database "event sourcing" date:>2024-01-01;
+    /// an argument containing spaces is searched as a phrase
+    #[arg(required = true, num_args = 1..)]
+    pub query: Vec<String>,
+
+    /// ADR directory to search (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Output format (console, json)
+    #[arg(short, long, default_value = "console")]
+    pub format: String,
+
+    /// Maximum number of results
+    #[arg(short, long)]
+    pub limit: Option<usize>,
+}
+
+/// Search results as returned in JSON output
+#[derive(Debug, Serialize)]
+pub struct SearchResults {
+    pub query: String,
+    /// Matches before `--limit` is applied
+    pub total: usize,
+    pub hits: Vec<SearchHit>,
+}
+
+impl SearchCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let results = self.run(config)?;
+
+        match self.format.as_str() {
+            "json" => {
+                let json = serde_json::to_string_pretty(&results)
+                    .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+                println!("{json}");
+            }
+            "console" => print_console(&results),
+            _ => {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Unsupported output format: {}. Use 'console' or 'json'",
+                    self.format
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the query and search every ADR in the directory
+    fn run(&self, config: &Config) -> Result<SearchResults> {
+        let query_text = query_text(&self.query);
+        let query = Query::parse(&query_text)?;
+        if query.is_empty() {
+            return Err(AdrscanError::InvalidArgument(
+                "Search query is empty".to_string(),
+            ));
+        }
+
+        // One word index across all roots keeps scores comparable
+        let mut documents = Vec::new();
+        let mut index = WordIndex::default();
+        for root in roots::resolve(config, self.adr_dir.as_ref())? {
+            let mut cache = AdrCache::open(&root.path, &config.cache);
+            for mut document in cache.parse_all() {
+                document.namespace = root.namespace.clone();
+                documents.push(document);
+            }
+            cache.save_or_warn();
+            index.merge(cache.word_index());
+        }
+
+        let mut hits = query.search_indexed(&documents, &index);
+        let total = hits.len();
+        if let Some(limit) = self.limit {
+            hits.truncate(limit);
+        }
+
+        Ok(SearchResults {
+            query: query_text,
+            total,
+            hits,
+        })
+    }
+}
+
+/// Join the query arguments, quoting those the shell kept together as a
+/// single argument so that they stay a phrase
+fn query_text(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| arg.trim())
+        .map(|arg| {
+            if arg.contains(char::is_whitespace) && !arg.contains('"') {
+                format!("\"{arg}\"")
+            } else {
+                arg.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn print_console(results: &SearchResults) {
+    if results.hits.is_empty() {
+        println!("🔍 No ADRs match '{}'", results.query);
+        return;
+    }
+
+    println!(
+        "🔍 {} ADRs match '{}'{}",
+        results.total,
+        results.query,
+        if results.hits.len() < results.total {
+            format!(" (showing {})", results.hits.len())
+        } else {
+            String::new()
+        }
+    );
+    for hit in &results.hits {
+        println!("\n  {} [{}] {}", hit.path.display(), hit.status, hit.title);
+        if let Some(ref snippet) = hit.snippet {
+            println!("    {snippet}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn search_command(adr_dir: PathBuf, query: &[&str], limit: Option<usize>) -> SearchCommand {
+        SearchCommand {
+            query: query.iter().map(|q| q.to_string()).collect(),
+            adr_dir: Some(adr_dir),
+            format: "json".to_string(),
+            limit,
+        }
+    }
+
+    #[test]
+    fn test_search_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            adr_dir.join("0001-kafka.md"),
+            "---\ntitle: Use Kafka\nstatus: accepted\n---\n# Use Kafka\n\nKafka for events.\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0002-rabbit.md"),
+            "---\ntitle: Use RabbitMQ\nstatus: rejected\n---\n# Use RabbitMQ\n\nKafka won.\n",
+        )
+        .unwrap();
+        let config = Config::default();
+
+        let results = search_command(adr_dir.clone(), &["kafka"], Some(1))
+            .run(&config)
+            .unwrap();
+        assert_eq!(results.total, 2);
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].path, adr_dir.join("0001-kafka.md"));
+
+        let json = serde_json::to_value(&results).unwrap();
+        assert_eq!(json["query"], "kafka");
+        assert_eq!(json["hits"][0]["status"], "accepted");
+
+        let results = search_command(adr_dir.clone(), &["kafka", "status:rejected"], None)
+            .run(&config)
+            .unwrap();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].title, "Use RabbitMQ");
+
+        // The second run is served from the cache and ranks the same way;
+        // the cache is not written to the ADR directory
+        assert_eq!(fs::read_dir(&adr_dir).unwrap().count(), 2);
+        let cached = search_command(adr_dir.clone(), &["kafka"], None)
+            .run(&config)
+            .unwrap();
+        let uncached = search_command(adr_dir.clone(), &["kafka"], None)
+            .run(&Config {
+                cache: crate::config::CacheConfig {
+                    enabled: false,
+                    dir: None,
+                },
+                ..Config::default()
+            })
+            .unwrap();
+        assert_eq!(cached.hits.len(), 2);
+        for (cached, uncached) in cached.hits.iter().zip(&uncached.hits) {
+            assert_eq!(cached.path, uncached.path);
+            assert_eq!(cached.score, uncached.score);
+        }
+
+        let empty = search_command(adr_dir, &["  "], None).run(&config);
+        assert!(matches!(empty, Err(AdrscanError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_search_argument_with_spaces_is_a_phrase() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            adr_dir.join("0001-events.md"),
+            "---\ntitle: Use event sourcing\n---\n# Use event sourcing\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0002-audit.md"),
+            "---\ntitle: Audit log\n---\n# Audit log\n\nSourcing every event.\n",
+        )
+        .unwrap();
+        let config = Config::default();
+
+        let results = search_command(adr_dir.clone(), &["event sourcing"], None)
+            .run(&config)
+            .unwrap();
+        assert_eq!(results.query, "\"event sourcing\"");
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].path, adr_dir.join("0001-events.md"));
+
+        let results = search_command(adr_dir, &["event", "sourcing"], None)
+            .run(&config)
+            .unwrap();
+        assert_eq!(results.hits.len(), 2);
+    }
+}
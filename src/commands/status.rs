@@ -0,0 +1,199 @@
+use chrono::NaiveDate;
+use clap::Args;
+use std::path::PathBuf;
+
+use crate::{
+    commands::index::IndexCommand,
+    config::Config,
+    error::AdrscanError,
+    graph::AdrGraph,
+    parser::AdrDocument,
+    roots::{self, AdrRoot},
+    status::{self, record_transition, StatusModel},
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+#[derive(Args)]
+pub struct StatusCommand {
+    /// ADR to update (id, number or file name)
+    pub id: String,
+
+    /// Status to move the ADR to
+    #[arg(value_name = "NEW_STATUS")]
+    pub status: String,
+
+    /// ADR directory (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Transition date (YYYY-MM-DD, defaults to today)
+    #[arg(long)]
+    pub date: Option<NaiveDate>,
+
+    /// Allow transitions not listed in `status.transitions`
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl StatusCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+        let (from, document) = self.run(config, &roots)?;
+
+        println!(
+            "✅ {}: {} → {}",
+            document.path.display(),
+            from,
+            document.metadata.status
+        );
+        if status::normalize(&document.metadata.status) == "superseded" {
+            println!("💡 Use `adrscan supersede` to also link the replacing ADR");
+        }
+
+        if let Some(root) = roots::root_of(&roots, &document.path) {
+            IndexCommand::refresh(&root.path, config)?;
+        }
+        Ok(())
+    }
+
+    /// Apply the transition on disk, returning the previous status and the
+    /// updated document
+    fn run(&self, config: &Config, roots: &[AdrRoot]) -> Result<(String, AdrDocument)> {
+        let mut documents = roots::load_documents(roots, config);
+        let graph = AdrGraph::from_documents(&documents);
+        let index = graph.resolve(&self.id).ok_or_else(|| {
+            AdrscanError::InvalidArgument(format!("No ADR matches '{}'", self.id))
+        })?;
+        let mut document = documents.swap_remove(index);
+
+        let model = StatusModel::new(&config.status);
+        let to = model.canonical(&self.status).ok_or_else(|| {
+            AdrscanError::InvalidArgument(format!(
+                "Unknown status '{}' (expected one of: {})",
+                self.status,
+                model.states().join(", ")
+            ))
+        })?;
+        let from = document.metadata.status.clone();
+
+        if status::normalize(&from) == status::normalize(to) {
+            return Err(AdrscanError::InvalidArgument(format!(
+                "{} is already {}",
+                document.path.display(),
+                from
+            )));
+        }
+        if !self.force && !model.can_transition(&from, to) {
+            let allowed = model.transitions_from(&from);
+            return Err(AdrscanError::InvalidArgument(format!(
+                "Cannot move {} from '{}' to '{}' (allowed: {}). Use --force to override",
+                document.path.display(),
+                from,
+                to,
+                if allowed.is_empty() {
+                    "none".to_string()
+                } else {
+                    allowed.join(", ")
+                }
+            )));
+        }
+
+        let date = self
+            .date
+            .unwrap_or_else(|| chrono::Local::now().date_naive());
+        record_transition(&mut document, to, date)?;
+        document.write()?;
+
+        Ok((from, document))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StatusConfig;
+    use crate::parser::AdrParser;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn status_command(id: &str, status: &str) -> StatusCommand {
+        StatusCommand {
+            id: id.to_string(),
+            status: status.to_string(),
+            adr_dir: None,
+            date: NaiveDate::from_ymd_opt(2024, 3, 1),
+            force: false,
+        }
+    }
+
+    fn single_root(adr_dir: &Path) -> Vec<AdrRoot> {
+        vec![AdrRoot::single(adr_dir, &Config::default())]
+    }
+
+    #[test]
+    fn test_status_transition() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        let path = adr_dir.join("0001-use-kafka.md");
+        fs::write(
+            &path,
+            "+++\ntitle = \"Use Kafka\"\nstatus = \"proposed\"\n+++\n\n# Use Kafka\n",
+        )
+        .unwrap();
+        let config = Config::default();
+
+        let (from, _) = status_command("1", "Accepted")
+            .run(&config, &single_root(adr_dir))
+            .unwrap();
+        assert_eq!(from, "proposed");
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "+++\ntitle = \"Use Kafka\"\nstatus = \"accepted\"\nstatus_history = [{ date = \"2024-03-01\", from = \"proposed\", to = \"accepted\" }]\n+++\n\n# Use Kafka\n"
+        );
+
+        // Accepted decisions cannot go back to proposed without --force
+        let mut back = status_command("0001", "proposed");
+        assert!(matches!(
+            back.run(&config, &single_root(adr_dir)),
+            Err(AdrscanError::InvalidArgument(_))
+        ));
+        back.force = true;
+        back.run(&config, &single_root(adr_dir)).unwrap();
+
+        let document = AdrParser::parse_file(&path).unwrap();
+        assert_eq!(document.metadata.status, "proposed");
+        assert_eq!(status::status_history(&document).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_status_rejects_unknown_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(
+            adr_dir.join("0001-a.md"),
+            "---\ntitle: A\nstatus: draft\n---\n",
+        )
+        .unwrap();
+
+        let default = Config::default();
+        let unknown = status_command("0001", "in review").run(&default, &single_root(adr_dir));
+        assert!(matches!(unknown, Err(AdrscanError::InvalidArgument(_))));
+        let missing = status_command("0009", "accepted").run(&default, &single_root(adr_dir));
+        assert!(matches!(missing, Err(AdrscanError::InvalidArgument(_))));
+
+        // Custom states become valid once configured
+        let config = Config {
+            status: StatusConfig {
+                states: vec!["draft".to_string(), "In Review".to_string()],
+                transitions: HashMap::from([("draft".to_string(), vec!["in-review".to_string()])]),
+            },
+            ..Config::default()
+        };
+        let (_, document) = status_command("0001", "in_review")
+            .run(&config, &single_root(adr_dir))
+            .unwrap();
+        assert_eq!(document.metadata.status, "In Review");
+    }
+}
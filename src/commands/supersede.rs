@@ -0,0 +1,375 @@
+use clap::Args;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    commands::index::IndexCommand,
+    config::Config,
+    error::AdrscanError,
+    graph::{AdrGraph, EdgeKind, GLOBAL_PREFIX},
+    parser::{writer::write_documents, AdrDocument},
+    roots::{self, AdrRoot},
+    status::{self, record_transition, StatusModel},
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Frontmatter key holding the back-link from a superseded ADR
+const SUPERSEDED_BY_FIELD: &str = "superseded_by";
+
+/// Status given to the replaced ADR, in the configured spelling
+const SUPERSEDED_STATUS: &str = "superseded";
+
+#[derive(Args)]
+pub struct SupersedeCommand {
+    /// ADR being replaced (id, number, file name or path)
+    pub old: String,
+
+    /// ADR replacing it (id, number, file name or path)
+    pub new: String,
+
+    /// ADR directory (overrides config)
+    #[arg(short, long)]
+    pub adr_dir: Option<PathBuf>,
+
+    /// Supersede the ADR even if `status.transitions` does not allow it
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl SupersedeCommand {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let roots = roots::resolve(config, self.adr_dir.as_ref())?;
+        let (old, new) = self.run(config, &roots)?;
+
+        println!(
+            "✅ {} now supersedes {}",
+            new.path.display(),
+            old.path.display()
+        );
+
+        // Keep previously generated indexes in sync with the new statuses
+        let mut adr_dirs: Vec<&Path> = [&old.path, &new.path]
+            .into_iter()
+            .filter_map(|path| roots::root_of(&roots, path))
+            .map(|root| root.path.as_path())
+            .collect();
+        adr_dirs.dedup();
+        for adr_dir in adr_dirs {
+            IndexCommand::refresh(adr_dir, config)?;
+        }
+
+        Ok(())
+    }
+
+    /// Update both ADRs on disk, returning the edited documents
+    pub(crate) fn run(
+        &self,
+        config: &Config,
+        roots: &[AdrRoot],
+    ) -> Result<(AdrDocument, AdrDocument)> {
+        let documents = roots::load_documents(roots, config);
+        let graph = AdrGraph::from_documents(&documents);
+
+        // A path names exactly one file, whatever its number
+        let resolve = |reference: &str| {
+            let by_path = graph.node_by_path(Path::new(reference));
+            by_path.or_else(|| graph.resolve(reference)).ok_or_else(|| {
+                AdrscanError::InvalidArgument(format!("No ADR matches '{reference}'"))
+            })
+        };
+        let old_index = resolve(&self.old)?;
+        let new_index = resolve(&self.new)?;
+        if old_index == new_index {
+            return Err(AdrscanError::InvalidArgument(format!(
+                "'{}' and '{}' refer to the same ADR",
+                self.old, self.new
+            )));
+        }
+
+        let mut old = documents[old_index].clone();
+        let mut new = documents[new_index].clone();
+
+        let model = StatusModel::new(&config.status);
+        let superseded = model.canonical(SUPERSEDED_STATUS).ok_or_else(|| {
+            AdrscanError::ConfigError(format!(
+                "Status '{SUPERSEDED_STATUS}' is not configured (states: {})",
+                model.states().join(", ")
+            ))
+        })?;
+        let from = old.metadata.status.clone();
+        if status::normalize(&from) != status::normalize(superseded) {
+            if !self.force && !model.can_transition(&from, superseded) {
+                return Err(AdrscanError::InvalidArgument(format!(
+                    "Cannot supersede {}: moving from '{}' to '{}' is not allowed. Use --force to override",
+                    old.path.display(),
+                    from,
+                    superseded
+                )));
+            }
+            record_transition(&mut old, superseded, chrono::Local::now().date_naive())?;
+        }
+        let mut superseded_by = string_list(old.metadata.custom_fields.get(SUPERSEDED_BY_FIELD));
+        if !superseded_by
+            .iter()
+            .any(|r| graph.resolve(r) == Some(new_index))
+        {
+            superseded_by.push(reference_for(&new, &old));
+            old.set_list_field(SUPERSEDED_BY_FIELD, &superseded_by)?;
+        }
+
+        if !graph
+            .outgoing(new_index, EdgeKind::Supersedes)
+            .contains(&old_index)
+        {
+            let mut supersedes = new.metadata.supersedes.clone();
+            supersedes.push(reference_for(&old, &new));
+            new.set_list_field("supersedes", &supersedes)?;
+        }
+
+        write_documents(&[&old, &new])?;
+        Ok((old, new))
+    }
+}
+
+/// How `from` refers to `document`: its explicit id, else its file name,
+/// qualified with its namespace, or marked as global, when the two live in
+/// different namespaces
+fn reference_for(document: &AdrDocument, from: &AdrDocument) -> String {
+    let reference = document.metadata.id.clone().unwrap_or_else(|| {
+        document
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| document.path.display().to_string())
+    });
+    if document.namespace == from.namespace {
+        return reference;
+    }
+    match document.namespace {
+        Some(ref namespace) => format!("{namespace}/{reference}"),
+        None => format!("{GLOBAL_PREFIX}{reference}"),
+    }
+}
+
+/// Read a frontmatter value written either as a single string or a list
+fn string_list(value: Option<&serde_yaml::Value>) -> Vec<String> {
+    match value {
+        Some(serde_yaml::Value::String(s)) => vec![s.clone()],
+        Some(serde_yaml::Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::AdrParser;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn single_root(adr_dir: &Path) -> Vec<AdrRoot> {
+        vec![AdrRoot::single(adr_dir, &Config::default())]
+    }
+
+    fn supersede_command(adr_dir: &Path, old: &str, new: &str) -> SupersedeCommand {
+        SupersedeCommand {
+            old: old.to_string(),
+            new: new.to_string(),
+            adr_dir: Some(adr_dir.to_path_buf()),
+            force: false,
+        }
+    }
+
+    #[test]
+    fn test_supersede_updates_both_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(
+            adr_dir.join("0001-use-mysql.md"),
+            "---\ntitle: Use MySQL\nstatus: accepted # reviewed\n---\n\n# Use MySQL\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0002-use-postgres.md"),
+            "+++\ntitle = \"Use PostgreSQL\"\nstatus = \"accepted\"\n+++\n\n# Use PostgreSQL\n",
+        )
+        .unwrap();
+
+        let cmd = supersede_command(adr_dir, "1", "ADR-0002");
+        cmd.run(&Config::default(), &single_root(adr_dir)).unwrap();
+
+        let old = fs::read_to_string(adr_dir.join("0001-use-mysql.md")).unwrap();
+        let today = chrono::Local::now().date_naive();
+        assert_eq!(
+            old,
+            format!("---\ntitle: Use MySQL\nstatus: superseded # reviewed\nstatus_history:\n  - from: accepted\n    to: superseded\n    date: {today}\nsuperseded_by: [\"0002-use-postgres.md\"]\n---\n\n# Use MySQL\n")
+        );
+        let new = AdrParser::parse_file(&adr_dir.join("0002-use-postgres.md")).unwrap();
+        assert_eq!(
+            new.frontmatter_format,
+            Some(crate::parser::FrontmatterFormat::Toml)
+        );
+        assert_eq!(new.metadata.supersedes, vec!["0001-use-mysql.md"]);
+        assert_eq!(new.metadata.status, "accepted");
+
+        // Running again does not duplicate the links
+        cmd.run(&Config::default(), &single_root(adr_dir)).unwrap();
+        assert_eq!(
+            fs::read_to_string(adr_dir.join("0001-use-mysql.md")).unwrap(),
+            old
+        );
+        let new = AdrParser::parse_file(&adr_dir.join("0002-use-postgres.md")).unwrap();
+        assert_eq!(new.metadata.supersedes.len(), 1);
+    }
+
+    #[test]
+    fn test_supersede_across_namespaces() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        for dir in ["docs/adr", "services/payments/adr"] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        fs::write(
+            base.join("docs/adr/0001-use-mysql.md"),
+            "---\ntitle: Use MySQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            base.join("services/payments/adr/0001-use-postgres.md"),
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            adr_dir: crate::config::AdrDirs::from(vec![
+                base.join("docs/adr").into(),
+                base.join("services/*/adr").into(),
+            ]),
+            ..Config::default()
+        };
+        let roots = roots::resolve(&config, None).unwrap();
+        let cmd = SupersedeCommand {
+            old: "0001".to_string(),
+            new: "payments/0001".to_string(),
+            adr_dir: None,
+            force: false,
+        };
+        cmd.run(&config, &roots).unwrap();
+
+        let old = AdrParser::parse_file(&base.join("docs/adr/0001-use-mysql.md")).unwrap();
+        assert_eq!(old.metadata.status, "superseded");
+        assert_eq!(
+            string_list(old.metadata.custom_fields.get(SUPERSEDED_BY_FIELD)),
+            vec!["payments/0001-use-postgres.md"]
+        );
+        let new = AdrParser::parse_file(&base.join("services/payments/adr/0001-use-postgres.md"))
+            .unwrap();
+        // Marked as global, or it would name payments/0001 itself
+        assert_eq!(new.metadata.supersedes, vec!["/0001-use-mysql.md"]);
+
+        let graph = AdrGraph::from_documents(&roots::load_documents(&roots, &config));
+        let global = graph
+            .node_by_path(&base.join("docs/adr/0001-use-mysql.md"))
+            .unwrap();
+        let payments = graph
+            .node_by_path(&base.join("services/payments/adr/0001-use-postgres.md"))
+            .unwrap();
+        assert_eq!(graph.superseded_by(global), vec![payments]);
+        assert!(graph.superseded_by(payments).is_empty());
+        assert!(graph.accepted_while_superseded().is_empty());
+    }
+
+    #[test]
+    fn test_supersede_validates_references() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        let original = "---\ntitle: Use MySQL\nstatus: accepted\n---\n";
+        fs::write(adr_dir.join("0001-use-mysql.md"), original).unwrap();
+
+        let missing = supersede_command(adr_dir, "0001", "0009")
+            .run(&Config::default(), &single_root(adr_dir));
+        assert!(matches!(missing, Err(AdrscanError::InvalidArgument(_))));
+
+        let same = supersede_command(adr_dir, "0001", "0001-use-mysql.md")
+            .run(&Config::default(), &single_root(adr_dir));
+        assert!(matches!(same, Err(AdrscanError::InvalidArgument(_))));
+
+        assert_eq!(
+            fs::read_to_string(adr_dir.join("0001-use-mysql.md")).unwrap(),
+            original
+        );
+    }
+
+    #[test]
+    fn test_supersede_checks_status_transition() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        let proposed = "---\ntitle: Use MySQL\nstatus: proposed\n---\n";
+        fs::write(adr_dir.join("0001-use-mysql.md"), proposed).unwrap();
+        fs::write(
+            adr_dir.join("0002-use-postgres.md"),
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+
+        // The default lifecycle does not go from proposed to superseded
+        let mut cmd = supersede_command(adr_dir, "0001", "0002");
+        let result = cmd.run(&Config::default(), &single_root(adr_dir));
+        assert!(matches!(result, Err(AdrscanError::InvalidArgument(_))));
+        assert_eq!(
+            fs::read_to_string(adr_dir.join("0001-use-mysql.md")).unwrap(),
+            proposed
+        );
+
+        // Without a superseded state there is nothing to move to
+        let config = Config {
+            status: crate::config::StatusConfig {
+                states: vec!["Proposed".to_string(), "Accepted".to_string()],
+                transitions: Default::default(),
+            },
+            ..Config::default()
+        };
+        cmd.force = true;
+        let result = cmd.run(&config, &single_root(adr_dir));
+        assert!(matches!(result, Err(AdrscanError::ConfigError(_))));
+
+        // The configured spelling is written
+        let config = Config {
+            status: crate::config::StatusConfig {
+                states: vec!["Proposed".to_string(), "Superseded".to_string()],
+                transitions: Default::default(),
+            },
+            ..Config::default()
+        };
+        cmd.force = false;
+        let (old, _) = cmd.run(&config, &single_root(adr_dir)).unwrap();
+        assert_eq!(old.metadata.status, "Superseded");
+    }
+
+    #[test]
+    fn test_supersede_regenerates_index() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(
+            adr_dir.join("0001-use-mysql.md"),
+            "---\ntitle: Use MySQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            adr_dir.join("0002-use-postgres.md"),
+            "---\ntitle: Use PostgreSQL\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+        fs::write(adr_dir.join("index.md"), "# stale\n").unwrap();
+
+        supersede_command(adr_dir, "0001", "0002")
+            .execute(&Config::default())
+            .unwrap();
+
+        let index = fs::read_to_string(adr_dir.join("index.md")).unwrap();
+        assert!(index.contains("superseded"));
+        assert!(!index.contains("# stale"));
+    }
+}
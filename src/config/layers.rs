@@ -0,0 +1,681 @@
+//! Configuration Layers
+//!
+//! The effective configuration is merged from layers, lowest precedence
+//! first: the built-in defaults, then every config file from the outermost
+//! directory down to the working directory, like `.editorconfig`. A file
+//! containing `root: true` stops the search in parent directories.
+//!
+//! Tables merge key by key and any other value replaces the inherited one.
+//! Writing a list key with a `+` suffix (`exclude_patterns+:`) appends to
+//! the inherited list instead of replacing it.
+//!
+//! Files can also define named profiles under `profiles:`, e.g. one for CI
+//! with stricter thresholds. The selected profile is merged on top of all
+//! files like another layer.
+//!
+//! Every merged value remembers the layer that set it, which is what
+//! `adrscan config show --resolved` prints.
+
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::migrate::{self, file_version, VERSION_KEY};
+use super::schema::{self, Severity};
+use super::Config;
+use crate::error::AdrscanError;
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Config file names, in order of preference within one directory
+pub const CONFIG_FILE_NAMES: [&str; 3] = [".adrscan.yml", ".adrscan.yaml", ".adrscan.toml"];
+
+/// Key that stops the search for config files in parent directories
+pub(super) const ROOT_KEY: &str = "root";
+
+/// Key holding the named profiles
+pub(super) const PROFILES_KEY: &str = "profiles";
+
+/// Suffix of list keys whose items are appended to the inherited list
+pub(super) const APPEND_SUFFIX: char = '+';
+
+/// Where a configuration value was set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    Default,
+    File(PathBuf),
+    Profile(String),
+    Environment,
+    CommandLine,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File(path) => write!(f, "{}", path.display()),
+            ConfigSource::Profile(name) => write!(f, "profile {name}"),
+            ConfigSource::Environment => write!(f, "environment"),
+            ConfigSource::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// Configuration values merged from several layers
+#[derive(Debug, Clone)]
+pub struct ConfigLayers {
+    value: Value,
+    /// Sources by dotted key path; a key without an entry has the sources
+    /// of its closest ancestor, and the empty path is the root
+    sources: BTreeMap<String, Vec<ConfigSource>>,
+    files: Vec<PathBuf>,
+    /// Profiles defined by the files, merged by name
+    profiles: Mapping,
+    /// The applied profile
+    profile: Option<String>,
+}
+
+/// The effective configuration with the origin of every value
+#[derive(Debug, Serialize)]
+pub struct ResolvedConfig {
+    /// Config files that were merged, lowest precedence first
+    pub files: Vec<PathBuf>,
+    /// The applied profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub values: Vec<ResolvedValue>,
+}
+
+/// A single configuration value
+#[derive(Debug, Serialize)]
+pub struct ResolvedValue {
+    /// Dotted key path, e.g. `drift.enabled`
+    pub key: String,
+    pub value: Value,
+    /// Layers that set the value; several when a list was appended to
+    pub sources: Vec<ConfigSource>,
+}
+
+impl Default for ConfigLayers {
+    fn default() -> Self {
+        Self {
+            value: serde_yaml::to_value(Config::default())
+                .expect("default configuration is serializable"),
+            sources: BTreeMap::from([(String::new(), vec![ConfigSource::Default])]),
+            files: Vec::new(),
+            profiles: Mapping::new(),
+            profile: None,
+        }
+    }
+}
+
+impl ConfigLayers {
+    /// Defaults merged with the config files of `dir` and its parents, up
+    /// to the first file marked `root: true`
+    pub fn discover(dir: &Path) -> Result<Self> {
+        let mut layers = Self::default();
+        for path in discover_files(dir) {
+            layers.add_file(&path)?;
+        }
+        Ok(layers)
+    }
+
+    /// Merge a config file on top of the current values
+    pub fn add_file(&mut self, path: &Path) -> Result<()> {
+        let content = read_content(path)?;
+        check_schema(&content, path)?;
+        self.merge_file(&content, path)
+    }
+
+    /// Defaults merged with a config file that was already validated
+    /// against the schema, so that its warnings are not logged again
+    pub(crate) fn with_checked_file(path: &Path) -> Result<Self> {
+        let mut layers = Self::default();
+        layers.merge_file(&read_content(path)?, path)?;
+        Ok(layers)
+    }
+
+    fn merge_file(&mut self, content: &str, path: &Path) -> Result<()> {
+        let value = parse_file(content, path)?;
+        self.merge(value, ConfigSource::File(path.to_path_buf()))?;
+        self.files.push(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Merge a layer on top of the current values
+    pub fn merge(&mut self, mut layer: Value, source: ConfigSource) -> Result<()> {
+        if let Value::Mapping(ref mut mapping) = layer {
+            mapping.remove(ROOT_KEY);
+            // Files are read in whatever version they are in; the merged
+            // config is always the current one
+            mapping.remove(VERSION_KEY);
+            match mapping.remove(PROFILES_KEY) {
+                Some(Value::Mapping(profiles)) => merge_tables(&mut self.profiles, profiles),
+                Some(Value::Null) | None => {}
+                Some(_) => {
+                    return Err(AdrscanError::ConfigError(format!(
+                        "'{PROFILES_KEY}' in {source} must be a table of profiles"
+                    )));
+                }
+            }
+        }
+        merge_value(&mut self.value, layer, "", &source, &mut self.sources)
+    }
+
+    /// Merge the profile `name` on top of the current values
+    pub fn apply_profile(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self.profiles.get(name).cloned() else {
+            let names: Vec<&str> = self.profiles.keys().filter_map(Value::as_str).collect();
+            return Err(AdrscanError::ConfigError(if names.is_empty() {
+                format!("Unknown profile '{name}': no profiles are defined")
+            } else {
+                format!(
+                    "Unknown profile '{name}'. Available profiles: {}",
+                    names.join(", ")
+                )
+            }));
+        };
+
+        let source = ConfigSource::Profile(name.to_string());
+        let mut profile = match profile {
+            Value::Mapping(profile) => profile,
+            Value::Null => Mapping::new(),
+            _ => {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Profile '{name}' must be a table of settings"
+                )));
+            }
+        };
+        // Profiles cannot select other profiles or stop the file search
+        profile.remove(PROFILES_KEY);
+        profile.remove(ROOT_KEY);
+        merge_value(
+            &mut self.value,
+            Value::Mapping(profile),
+            "",
+            &source,
+            &mut self.sources,
+        )?;
+        self.profile = Some(name.to_string());
+        Ok(())
+    }
+
+    /// The merged configuration
+    pub fn config(&self) -> Result<Config> {
+        serde_yaml::from_value(self.value.clone()).map_err(|e| {
+            let files: Vec<String> = self
+                .files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            AdrscanError::ConfigError(if files.is_empty() {
+                format!("Invalid configuration: {e}")
+            } else {
+                format!("Invalid configuration in {}: {e}", files.join(", "))
+            })
+        })
+    }
+
+    /// Attribute every value in which `config` differs from the merged
+    /// layers to `source`, e.g. after environment overrides were applied
+    pub fn record(&mut self, config: &Config, source: ConfigSource) {
+        let Ok(value) = serde_yaml::to_value(config) else {
+            return;
+        };
+
+        let before = leaves(&self.normalized());
+        for (key, leaf) in leaves(&value) {
+            if before.get(&key) != Some(&leaf) {
+                set_source(&mut self.sources, &key, &source);
+            }
+        }
+        self.value = value;
+    }
+
+    /// Every leaf value with the layers that set it
+    pub fn resolved(&self) -> ResolvedConfig {
+        let values = leaves(&self.normalized())
+            .into_iter()
+            .map(|(key, value)| ResolvedValue {
+                sources: inherited_sources(&self.sources, &key),
+                key,
+                value,
+            })
+            .collect();
+
+        ResolvedConfig {
+            files: self.files.clone(),
+            profile: self.profile.clone(),
+            values,
+        }
+    }
+
+    /// The merged values as `Config` serializes them, so that equivalent
+    /// spellings (e.g. a one-element `adr_dir` list) compare equal
+    fn normalized(&self) -> Value {
+        self.config()
+            .ok()
+            .and_then(|config| serde_yaml::to_value(config).ok())
+            .unwrap_or_else(|| self.value.clone())
+    }
+}
+
+/// Config files of `dir` and its parents, up to the first file marked
+/// `root: true`, lowest precedence first
+pub fn discover_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for dir in dir.ancestors() {
+        let Some(path) = CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+        else {
+            continue;
+        };
+
+        let is_root = is_root(&path);
+        files.push(path);
+        if is_root {
+            break;
+        }
+    }
+    files.reverse();
+    files
+}
+
+/// Whether a config file sets `root: true`; unreadable files are not, and
+/// fail later when they are loaded
+fn is_root(path: &Path) -> bool {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return false;
+    };
+    let value = if path.extension().and_then(|s| s.to_str()) == Some("toml") {
+        toml::from_str::<toml::Table>(&content)
+            .ok()
+            .and_then(|table| serde_yaml::to_value(table).ok())
+    } else {
+        serde_yaml::from_str::<Value>(&content).ok()
+    };
+    value
+        .and_then(|value| value.get(ROOT_KEY).and_then(Value::as_bool))
+        .unwrap_or(false)
+}
+
+/// Read a config file
+fn read_content(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        AdrscanError::ConfigError(format!(
+            "Failed to read config file {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Validate a config file against the schema, logging its warnings
+fn check_schema(content: &str, path: &Path) -> Result<()> {
+    if let Ok(located) = schema::parse(content, path) {
+        file_version(&located.value).map_err(|message| {
+            AdrscanError::ConfigError(format!("Invalid config file {}: {message}", path.display()))
+        })?;
+    }
+
+    let mut errors = Vec::new();
+    for diagnostic in schema::validate_str(content, path) {
+        match diagnostic.severity {
+            Severity::Error => errors.push(diagnostic.to_string()),
+            Severity::Warning => log::warn!("{diagnostic}"),
+        }
+    }
+    if !errors.is_empty() {
+        // Point at `config migrate` only if a migration would fix something
+        if let Ok(plan) = migrate::plan_str(content, path) {
+            if !plan.applied.is_empty() {
+                errors.push(format!(
+                    "The file is written for config version {}, run `adrscan config migrate` to upgrade it",
+                    plan.from
+                ));
+            }
+        }
+        return Err(AdrscanError::ConfigError(format!(
+            "Invalid config file {}:\n{}",
+            path.display(),
+            errors.join("\n")
+        )));
+    }
+    Ok(())
+}
+
+/// Parse a YAML or TOML config file into a table
+fn parse_file(content: &str, path: &Path) -> Result<Value> {
+    let value = if path.extension().and_then(|s| s.to_str()) == Some("toml") {
+        let table: toml::Table = toml::from_str(content).map_err(|e| {
+            AdrscanError::ConfigError(format!("Invalid TOML config {}: {e}", path.display()))
+        })?;
+        serde_yaml::to_value(table).map_err(|e| {
+            AdrscanError::ConfigError(format!("Invalid TOML config {}: {e}", path.display()))
+        })?
+    } else {
+        serde_yaml::from_str(content).map_err(|e| {
+            AdrscanError::ConfigError(format!("Invalid YAML config {}: {e}", path.display()))
+        })?
+    };
+
+    match value {
+        // An empty file sets nothing
+        Value::Null => Ok(Value::Mapping(Mapping::new())),
+        Value::Mapping(_) => Ok(value),
+        _ => Err(AdrscanError::ConfigError(format!(
+            "Config file {} must contain a table of settings",
+            path.display()
+        ))),
+    }
+}
+
+fn merge_value(
+    base: &mut Value,
+    layer: Value,
+    path: &str,
+    source: &ConfigSource,
+    sources: &mut BTreeMap<String, Vec<ConfigSource>>,
+) -> Result<()> {
+    let (Value::Mapping(base), Value::Mapping(layer)) = (&mut *base, &layer) else {
+        *base = layer;
+        set_source(sources, path, source);
+        return Ok(());
+    };
+
+    for (key, value) in layer {
+        let Some(name) = key.as_str() else {
+            return Err(AdrscanError::ConfigError(format!(
+                "Config keys must be strings, found {key:?} in '{path}'"
+            )));
+        };
+
+        let Some(name) = name.strip_suffix(APPEND_SUFFIX) else {
+            let key_path = join(path, name);
+            match base.get_mut(name) {
+                Some(existing) => merge_value(existing, value.clone(), &key_path, source, sources)?,
+                None => {
+                    base.insert(key.clone(), value.clone());
+                    set_source(sources, &key_path, source);
+                }
+            }
+            continue;
+        };
+
+        let key_path = join(path, name);
+        let Value::Sequence(items) = value else {
+            return Err(AdrscanError::ConfigError(format!(
+                "'{key_path}{APPEND_SUFFIX}' must be a list"
+            )));
+        };
+        match base.get_mut(name) {
+            Some(Value::Sequence(existing)) => {
+                existing.extend(items.iter().cloned());
+                let mut appended = inherited_sources(sources, &key_path);
+                if !appended.contains(source) {
+                    appended.push(source.clone());
+                }
+                sources.insert(key_path, appended);
+            }
+            None | Some(Value::Null) => {
+                base.insert(Value::String(name.to_string()), value.clone());
+                set_source(sources, &key_path, source);
+            }
+            Some(_) => {
+                return Err(AdrscanError::ConfigError(format!(
+                    "'{key_path}{APPEND_SUFFIX}' appends to a list, but '{key_path}' is not a list"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Merge tables key by key, keeping keys verbatim; used for profiles,
+/// whose `key+` entries apply only once the profile is selected
+fn merge_tables(base: &mut Mapping, layer: Mapping) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Mapping(existing)), Value::Mapping(nested)) => {
+                merge_tables(existing, nested)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Make `source` the only source of `path` and everything below it
+fn set_source(
+    sources: &mut BTreeMap<String, Vec<ConfigSource>>,
+    path: &str,
+    source: &ConfigSource,
+) {
+    let prefix = format!("{path}.");
+    sources.retain(|key, _| path.is_empty() || (key != path && !key.starts_with(&prefix)));
+    sources.insert(path.to_string(), vec![source.clone()]);
+}
+
+/// Sources of `path`, inherited from its closest ancestor with an entry
+fn inherited_sources(
+    sources: &BTreeMap<String, Vec<ConfigSource>>,
+    path: &str,
+) -> Vec<ConfigSource> {
+    let mut key = path;
+    loop {
+        if let Some(found) = sources.get(key) {
+            return found.clone();
+        }
+        if key.is_empty() {
+            return vec![ConfigSource::Default];
+        }
+        key = key.rfind('.').map_or("", |end| &key[..end]);
+    }
+}
+
+/// Non-table values by dotted key path; lists are single values
+fn leaves(value: &Value) -> BTreeMap<String, Value> {
+    fn collect(value: &Value, path: &str, out: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Mapping(mapping) if !mapping.is_empty() => {
+                for (key, value) in mapping {
+                    if let Some(key) = key.as_str() {
+                        collect(value, &join(path, key), out);
+                    }
+                }
+            }
+            _ => {
+                out.insert(path.to_string(), value.clone());
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    collect(value, "", &mut out);
+    out
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drift::DriftSeverity;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn value_of<'a>(resolved: &'a ResolvedConfig, key: &str) -> &'a ResolvedValue {
+        resolved.values.iter().find(|v| v.key == key).unwrap()
+    }
+
+    #[test]
+    fn test_nested_configs_cascade() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("repo");
+        let service = root.join("services/payments");
+        fs::create_dir_all(&service).unwrap();
+
+        // Ignored: above the config marked as root
+        fs::write(temp_dir.path().join(".adrscan.yml"), "adr_dir: outside\n").unwrap();
+        let root_config = root.join(".adrscan.yml");
+        fs::write(
+            &root_config,
+            "root: true\nadr_dir: docs/adr\nexclude_patterns: [\"**/target/**\"]\ndrift:\n  enabled: false\n",
+        )
+        .unwrap();
+        let service_config = service.join(".adrscan.toml");
+        fs::write(
+            &service_config,
+            "snapshot_file = \"payments.json\"\n\"exclude_patterns+\" = [\"**/fixtures/**\"]\n\n[template]\nformat = \"madr\"\n",
+        )
+        .unwrap();
+
+        let layers = ConfigLayers::discover(&service).unwrap();
+        let resolved = layers.resolved();
+        assert_eq!(
+            resolved.files,
+            [root_config.clone(), service_config.clone()]
+        );
+
+        let config = layers.config().unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("docs/adr"));
+        assert_eq!(config.snapshot_file, PathBuf::from("payments.json"));
+        assert_eq!(
+            config.exclude_patterns,
+            vec!["**/target/**", "**/fixtures/**"]
+        );
+        assert!(!config.drift.enabled);
+        // Keys a layer does not mention keep their inherited values
+        assert_eq!(config.include_patterns, Config::default().include_patterns);
+
+        let source = |key| value_of(&resolved, key).sources.clone();
+        assert_eq!(source("adr_dir"), [ConfigSource::File(root_config.clone())]);
+        assert_eq!(
+            source("exclude_patterns"),
+            [
+                ConfigSource::File(root_config.clone()),
+                ConfigSource::File(service_config.clone())
+            ]
+        );
+        assert_eq!(
+            source("template.format"),
+            [ConfigSource::File(service_config)]
+        );
+        assert_eq!(source("include_patterns"), [ConfigSource::Default]);
+    }
+
+    #[test]
+    fn test_record_and_merge_errors() {
+        let mut layers = ConfigLayers::default();
+        layers
+            .merge(
+                serde_yaml::from_str("include_patterns+: [\"**/*.go\"]").unwrap(),
+                ConfigSource::File(PathBuf::from("a.yml")),
+            )
+            .unwrap();
+        let mut config = layers.config().unwrap();
+        assert_eq!(config.include_patterns.last().unwrap(), "**/*.go");
+
+        config.drift.enabled = false;
+        layers.record(&config, ConfigSource::Environment);
+        let resolved = layers.resolved();
+        assert_eq!(
+            value_of(&resolved, "drift.enabled").sources,
+            [ConfigSource::Environment]
+        );
+        assert_eq!(
+            value_of(&resolved, "include_patterns").sources,
+            [
+                ConfigSource::Default,
+                ConfigSource::File(PathBuf::from("a.yml"))
+            ]
+        );
+
+        let not_a_list = layers.merge(
+            serde_yaml::from_str("snapshot_file+: [x]").unwrap(),
+            ConfigSource::Default,
+        );
+        assert!(matches!(not_a_list, Err(AdrscanError::ConfigError(_))));
+        let bad_value = layers.merge(
+            serde_yaml::from_str("exclude_patterns+: x").unwrap(),
+            ConfigSource::Default,
+        );
+        assert!(matches!(bad_value, Err(AdrscanError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_migration_hint() {
+        let temp_dir = TempDir::new().unwrap();
+        let hint = "run `adrscan config migrate`";
+        let error = |content: &str| {
+            let path = temp_dir.path().join(".adrscan.yml");
+            fs::write(&path, content).unwrap();
+            ConfigLayers::default()
+                .add_file(&path)
+                .unwrap_err()
+                .to_string()
+        };
+
+        let legacy = "drift:\n  detection_patterns:\n    - pattern: \"axum\"\n";
+        assert!(error(legacy).contains(hint));
+        // Nothing to migrate, the mistake is the user's
+        assert!(!error("adr_dir: 3\n").contains(hint));
+    }
+
+    #[test]
+    fn test_profiles_overlay_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let service = temp_dir.path().join("service");
+        fs::create_dir_all(&service).unwrap();
+        fs::write(
+            temp_dir.path().join(".adrscan.yml"),
+            "root: true\nprofiles:\n  ci:\n    drift:\n      fail_on: Medium\n    exclude_patterns+: [\"**/fixtures/**\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            service.join(".adrscan.yml"),
+            "drift:\n  fail_on: High\nprofiles:\n  ci:\n    drift:\n      enabled: false\n  strict: {}\n",
+        )
+        .unwrap();
+
+        // Without a profile, the profiles have no effect
+        let layers = ConfigLayers::discover(&service).unwrap();
+        let config = layers.config().unwrap();
+        assert_eq!(config.drift.fail_on, DriftSeverity::High);
+        assert!(config.drift.enabled);
+
+        // Profiles of all files merge by name, and apply on top of every file
+        let mut layers = ConfigLayers::discover(&service).unwrap();
+        layers.apply_profile("ci").unwrap();
+        let config = layers.config().unwrap();
+        assert_eq!(config.drift.fail_on, DriftSeverity::Medium);
+        assert!(!config.drift.enabled);
+        assert_eq!(config.exclude_patterns.last().unwrap(), "**/fixtures/**");
+
+        let resolved = layers.resolved();
+        assert_eq!(resolved.profile.as_deref(), Some("ci"));
+        let ci = ConfigSource::Profile("ci".to_string());
+        assert_eq!(
+            value_of(&resolved, "exclude_patterns").sources,
+            [ConfigSource::Default, ci.clone()]
+        );
+        assert_eq!(value_of(&resolved, "drift.fail_on").sources, [ci]);
+        assert!(resolved
+            .values
+            .iter()
+            .all(|v| !v.key.starts_with("profiles")));
+
+        let error = layers.apply_profile("local").unwrap_err().to_string();
+        assert!(error.contains("Available profiles: ci, strict"));
+    }
+}
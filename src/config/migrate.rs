@@ -0,0 +1,495 @@
+//! Config File Migrations
+//!
+//! Config files carry a `version`; files without one predate versioning and
+//! count as version 1. A file written for an older version is upgraded by
+//! the chain of migrations from its version to [`CONFIG_VERSION`].
+//!
+//! Migrations edit the text of the file rather than serializing it again,
+//! so comments, key order and formatting survive. A change that cannot be
+//! made this way, e.g. inside a flow-style table, is reported as a note for
+//! the user to apply by hand.
+
+use serde_json::Value;
+use similar::TextDiff;
+use std::path::{Path, PathBuf};
+
+use super::schema::{self, item_path, key_path, Located, Position};
+use crate::error::AdrscanError;
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Version of the config file format written by this adrscan
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Version of config files without a `version` key
+const UNVERSIONED: u32 = 1;
+
+/// Key holding the version of a config file
+pub(super) const VERSION_KEY: &str = "version";
+
+/// A step from one config version to the next
+struct Migration {
+    from: u32,
+    description: &'static str,
+    migrate: fn(&Located, &mut Editor),
+}
+
+/// Every migration, oldest first
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    description:
+        "Convert `pattern:` detection patterns to name, file_pattern, content_pattern and category",
+    migrate: legacy_detection_patterns,
+}];
+
+/// The result of upgrading one config file
+#[derive(Debug)]
+pub struct MigrationPlan {
+    pub file: PathBuf,
+    /// Version of the file before migrating
+    pub from: u32,
+    pub original: String,
+    pub migrated: String,
+    /// Descriptions of the migrations that changed something
+    pub applied: Vec<&'static str>,
+    /// Changes that have to be made by hand
+    pub notes: Vec<String>,
+}
+
+impl MigrationPlan {
+    /// Whether the file is already at the current version
+    pub fn is_current(&self) -> bool {
+        self.original == self.migrated
+    }
+
+    /// Unified diff from the original to the migrated file
+    pub fn diff(&self) -> String {
+        let name = self.file.display().to_string();
+        TextDiff::from_lines(&self.original, &self.migrated)
+            .unified_diff()
+            .header(&name, &name)
+            .to_string()
+    }
+
+    /// Replace the file with the migrated content
+    pub fn write(&self) -> Result<()> {
+        std::fs::write(&self.file, &self.migrated).map_err(|e| {
+            AdrscanError::ConfigError(format!(
+                "Failed to write config file {}: {e}",
+                self.file.display()
+            ))
+        })
+    }
+}
+
+/// Upgrade the config file `path` to the current version, without writing
+/// it
+pub fn plan(path: &Path) -> Result<MigrationPlan> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AdrscanError::ConfigError(format!(
+            "Failed to read config file {}: {e}",
+            path.display()
+        ))
+    })?;
+    plan_str(&content, path)
+}
+
+/// Upgrade the content of the config file `path` to the current version
+pub fn plan_str(content: &str, path: &Path) -> Result<MigrationPlan> {
+    let error = |message: String| {
+        AdrscanError::ConfigError(format!("Cannot migrate {}: {message}", path.display()))
+    };
+    let parse = |content: &str| schema::parse(content, path).map_err(|(_, message)| error(message));
+
+    let located = parse(content)?;
+    let from = file_version(&located.value).map_err(error)?;
+    let mut plan = MigrationPlan {
+        file: path.to_path_buf(),
+        from,
+        original: content.to_string(),
+        migrated: content.to_string(),
+        applied: Vec::new(),
+        notes: Vec::new(),
+    };
+    if from == CONFIG_VERSION {
+        return Ok(plan);
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= from) {
+        let located = parse(&plan.migrated)?;
+        let mut editor = Editor::new(&plan.migrated, path);
+        (migration.migrate)(&located, &mut editor);
+        if !editor.edits.is_empty() || !editor.notes.is_empty() {
+            plan.applied.push(migration.description);
+        }
+        plan.notes.append(&mut editor.notes);
+        plan.migrated = editor.apply();
+    }
+
+    let located = parse(&plan.migrated)?;
+    let mut editor = Editor::new(&plan.migrated, path);
+    editor.set_version(&located, CONFIG_VERSION);
+    plan.notes.append(&mut editor.notes);
+    plan.migrated = editor.apply();
+
+    // Never hand out a broken file
+    parse(&plan.migrated).map_err(|e| {
+        error(format!(
+            "the migrated file does not parse ({e}), please report this"
+        ))
+    })?;
+    Ok(plan)
+}
+
+/// Version of a parsed config file
+pub(super) fn file_version(value: &Value) -> std::result::Result<u32, String> {
+    let version = match value.get(VERSION_KEY) {
+        None | Some(Value::Null) => return Ok(UNVERSIONED),
+        Some(version) => version,
+    };
+    let version = version
+        .as_u64()
+        .and_then(|v| u32::try_from(v).ok())
+        .filter(|v| *v >= UNVERSIONED)
+        .ok_or_else(|| format!("'{VERSION_KEY}' must be a positive integer, found {version}"))?;
+    if version > CONFIG_VERSION {
+        return Err(format!(
+            "config version {version} is newer than the supported version {CONFIG_VERSION}, upgrade adrscan"
+        ));
+    }
+    Ok(version)
+}
+
+/// Rewrites detection patterns of the early documentation, which only had a
+/// `pattern` plus an unused `severity` and `description`
+fn legacy_detection_patterns(located: &Located, editor: &mut Editor) {
+    for (path, patterns) in find_lists(&located.value, "", "detection_patterns") {
+        for (index, pattern) in patterns.iter().enumerate() {
+            let Some(table) = pattern.as_object() else {
+                continue;
+            };
+            if !table.contains_key("pattern") || table.contains_key("content_pattern") {
+                continue;
+            }
+            let item = item_path(&path, index);
+            let old = key_path(&item, "pattern");
+
+            editor.rename(located, &old, "content_pattern");
+            if !table.contains_key("name") {
+                match table.get("description") {
+                    Some(Value::String(_)) => {
+                        editor.rename(located, &key_path(&item, "description"), "name")
+                    }
+                    _ => editor.insert_after(located, &old, "name", &table["pattern"]),
+                }
+            }
+            if !table.contains_key("file_pattern") {
+                editor.insert_after(located, &old, "file_pattern", &Value::from("**/*"));
+            }
+            if !table.contains_key("category") {
+                editor.insert_after(located, &old, "category", &Value::from("general"));
+            }
+            if table.contains_key("severity") {
+                editor.comment_out(
+                    located,
+                    &key_path(&item, "severity"),
+                    "detection patterns no longer have a severity",
+                );
+            }
+        }
+    }
+}
+
+/// Lists stored under `key` anywhere in the file, with their paths
+fn find_lists<'a>(value: &'a Value, path: &str, key: &str) -> Vec<(String, &'a Vec<Value>)> {
+    let mut found = Vec::new();
+    match value {
+        Value::Object(table) => {
+            for (name, value) in table {
+                let path = key_path(path, name);
+                match value {
+                    Value::Array(items) if name == key => found.push((path, items)),
+                    _ => found.extend(find_lists(value, &path, key)),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                found.extend(find_lists(item, &item_path(path, index), key));
+            }
+        }
+        _ => {}
+    }
+    found
+}
+
+/// Text edits to one config file
+struct Editor<'a> {
+    content: &'a str,
+    toml: bool,
+    /// Replacements of byte ranges, applied together
+    edits: Vec<(std::ops::Range<usize>, String)>,
+    notes: Vec<String>,
+}
+
+impl<'a> Editor<'a> {
+    fn new(content: &'a str, path: &Path) -> Self {
+        Self {
+            content,
+            toml: path.extension().and_then(|s| s.to_str()) == Some("toml"),
+            edits: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    /// Rename the key at `path`
+    fn rename(&mut self, located: &Located, path: &str, to: &str) {
+        let Some(start) = self.offset(located.keys.get(path)) else {
+            self.notes.push(format!("Rename '{path}' to '{to}'"));
+            return;
+        };
+        let end = token_end(self.content, start, &[':', '=', ' ', '\t', ',', '}']);
+        self.edits.push((start..end, to.to_string()));
+    }
+
+    /// Add `key: value` to the table containing `sibling`, on the line after
+    /// it; the sibling must be a single-line entry of a block table
+    fn insert_after(&mut self, located: &Located, sibling: &str, key: &str, value: &Value) {
+        let entry = self.entry(key, value);
+        let Some((start, position)) = self.single_line_entry(located, sibling) else {
+            let table = sibling.rfind('.').map_or("", |end| &sibling[..end]);
+            self.notes.push(format!("Add `{entry}` to '{table}'"));
+            return;
+        };
+        let line_end = self.content[start..]
+            .find('\n')
+            .map_or(self.content.len(), |i| start + i);
+        let indent = " ".repeat(position.column - 1);
+        let edit = if line_end == self.content.len() {
+            (line_end..line_end, format!("\n{indent}{entry}"))
+        } else {
+            (line_end + 1..line_end + 1, format!("{indent}{entry}\n"))
+        };
+        self.edits.push(edit);
+    }
+
+    /// Turn the single-line entry at `path` into a comment
+    fn comment_out(&mut self, located: &Located, path: &str, reason: &str) {
+        match self.single_line_entry(located, path) {
+            Some((start, _)) if self.at_line_start(start) => {
+                self.edits.push((start..start, "# ".to_string()));
+                self.notes.push(format!("Commented out '{path}': {reason}"));
+            }
+            _ => self.notes.push(format!("Remove '{path}': {reason}")),
+        }
+    }
+
+    /// Set the version, adding the key before the first top-level entry if
+    /// the file has none
+    fn set_version(&mut self, located: &Located, version: u32) {
+        if let Some(start) = self.offset(located.values.get(VERSION_KEY)) {
+            let end = token_end(self.content, start, &['#', ',', '}', '\n']);
+            let end = start + self.content[start..end].trim_end().len();
+            self.edits.push((start..end, version.to_string()));
+            return;
+        }
+
+        let entry = self.entry(VERSION_KEY, &Value::from(version));
+        let first = located
+            .keys
+            .iter()
+            .filter(|(path, _)| !path.is_empty() && !path.contains(['.', '[']))
+            .map(|(_, position)| position.line)
+            .min();
+        match first {
+            Some(line) => {
+                let start = line_start(self.content, line);
+                if self.content[start..].starts_with('{') {
+                    self.notes.push(format!("Add `{entry}` to the file"));
+                } else {
+                    self.edits.push((start..start, format!("{entry}\n")));
+                }
+            }
+            None => {
+                let separator = if self.content.is_empty() || self.content.ends_with('\n') {
+                    ""
+                } else {
+                    "\n"
+                };
+                let end = self.content.len();
+                self.edits.push((end..end, format!("{separator}{entry}\n")));
+            }
+        }
+    }
+
+    /// The edited content
+    fn apply(self) -> String {
+        let mut edits: Vec<_> = self.edits.into_iter().enumerate().collect();
+        // Back to front, so that earlier offsets stay valid; insertions at
+        // the same offset keep their order
+        edits.sort_by(|(a_seq, a), (b_seq, b)| (b.0.start, b_seq).cmp(&(a.0.start, a_seq)));
+        let mut content = self.content.to_string();
+        for (_, (range, replacement)) in edits {
+            content.replace_range(range, &replacement);
+        }
+        content
+    }
+
+    fn entry(&self, key: &str, value: &Value) -> String {
+        if self.toml {
+            format!("{key} = {value}")
+        } else {
+            format!("{key}: {value}")
+        }
+    }
+
+    fn offset(&self, position: Option<&Position>) -> Option<usize> {
+        let position = position?;
+        let start = line_start(self.content, position.line);
+        let column = self.content[start..]
+            .char_indices()
+            .nth(position.column - 1)
+            .map_or(self.content.len() - start, |(i, _)| i);
+        Some(start + column)
+    }
+
+    /// Offset and position of the key at `path`, if its entry is a scalar
+    /// on one line of a block table
+    fn single_line_entry(&self, located: &Located, path: &str) -> Option<(usize, Position)> {
+        let key = *located.keys.get(path)?;
+        let value = located.values.get(path)?;
+        let start = self.offset(Some(&key))?;
+        let before = self.content[line_start(self.content, key.line)..start].trim_end();
+        let in_flow = before.ends_with(['{', ',']);
+        (value.line == key.line && !in_flow).then_some((start, key))
+    }
+
+    fn at_line_start(&self, offset: usize) -> bool {
+        let start = self.content[..offset].rfind('\n').map_or(0, |i| i + 1);
+        self.content[start..offset].trim().is_empty()
+    }
+}
+
+/// Byte offset of the start of a 1-based line
+fn line_start(content: &str, line: usize) -> usize {
+    if line <= 1 {
+        return 0;
+    }
+    content
+        .match_indices('\n')
+        .nth(line - 2)
+        .map_or(content.len(), |(i, _)| i + 1)
+}
+
+/// End of the key or scalar starting at `start`: the closing quote of a
+/// quoted one, else the first of `stops` or the end of the line
+fn token_end(content: &str, start: usize, stops: &[char]) -> usize {
+    let rest = &content[start..];
+    let mut chars = rest.char_indices();
+    if let Some((_, quote @ ('"' | '\''))) = chars.next() {
+        let mut escaped = false;
+        for (i, c) in chars {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if quote == '"' => escaped = true,
+                c if c == quote => return start + i + c.len_utf8(),
+                _ => {}
+            }
+        }
+        return content.len();
+    }
+    start
+        + rest
+            .find(|c: char| c == '\n' || stops.contains(&c))
+            .unwrap_or(rest.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_yaml_preserves_comments() {
+        let content = "# Project config
+adr_dir: docs/adr   # decisions live here
+drift:
+  detection_patterns:
+    # Caches
+    - pattern: \"redis\"
+      severity: high
+      description: Redis usage
+    - {pattern: kafka, severity: low}
+";
+        let plan = plan_str(content, Path::new(".adrscan.yml")).unwrap();
+        assert_eq!(plan.from, 1);
+        assert_eq!(plan.applied.len(), 1);
+        assert_eq!(
+            plan.migrated,
+            "# Project config
+version: 2
+adr_dir: docs/adr   # decisions live here
+drift:
+  detection_patterns:
+    # Caches
+    - content_pattern: \"redis\"
+      file_pattern: \"**/*\"
+      category: \"general\"
+      # severity: high
+      name: Redis usage
+    - {content_pattern: kafka, severity: low}
+"
+        );
+        // The flow-style table is left to the user
+        assert!(plan
+            .notes
+            .iter()
+            .any(|note| note == "Add `name: \"kafka\"` to 'drift.detection_patterns[1]'"));
+        assert!(plan
+            .notes
+            .iter()
+            .any(|note| note.starts_with("Remove 'drift.detection_patterns[1].severity'")));
+        assert!(plan.diff().contains("+version: 2\n"));
+
+        let migrated = plan_str(&plan.migrated, Path::new(".adrscan.yml")).unwrap();
+        assert!(migrated.is_current());
+        assert_eq!(migrated.from, CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_toml() {
+        let content = "[[drift.detection_patterns]]
+pattern = \"redis\" # caches
+severity = \"high\"";
+        let plan = plan_str(content, Path::new("adrscan.toml")).unwrap();
+        assert_eq!(
+            plan.migrated,
+            "version = 2
+[[drift.detection_patterns]]
+content_pattern = \"redis\" # caches
+name = \"redis\"
+file_pattern = \"**/*\"
+category = \"general\"
+# severity = \"high\""
+        );
+        let config: crate::config::Config = toml::from_str(&plan.migrated).unwrap();
+        assert_eq!(config.drift.detection_patterns[0].name, "redis");
+
+        let outdated = plan_str(
+            "version = 1\nadr_dir = \"adr\"\n",
+            Path::new("adrscan.toml"),
+        );
+        assert_eq!(
+            outdated.unwrap().migrated,
+            "version = 2\nadr_dir = \"adr\"\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_versions() {
+        for content in ["version: 3\n", "version: two\n", "version: 0\n"] {
+            let error = plan_str(content, Path::new(".adrscan.yml")).unwrap_err();
+            assert!(matches!(error, AdrscanError::ConfigError(_)), "{content}");
+        }
+        let empty = plan_str("", Path::new(".adrscan.yml")).unwrap();
+        assert_eq!(empty.migrated, "version: 2\n");
+    }
+}
@@ -0,0 +1,1414 @@
+use clap::{builder::BoolishValueParser, Args};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::drift::DriftSeverity;
+use crate::error::AdrscanError;
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+pub mod layers;
+pub mod migrate;
+pub mod schema;
+
+pub use layers::{discover_files, ConfigLayers, ConfigSource, ResolvedConfig};
+pub use migrate::CONFIG_VERSION;
+
+/// ADRScan configuration
+///
+/// Every key is optional in a config file; missing keys keep the value
+/// inherited from parent configs or the defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct Config {
+    /// Version of the config file format; older files are upgraded with
+    /// `adrscan config migrate`
+    pub version: u32,
+
+    /// ADR directory, or a list of ADR roots
+    pub adr_dir: AdrDirs,
+
+    /// File patterns to include in scans
+    pub include_patterns: Vec<String>,
+
+    /// File patterns to exclude from scans
+    pub exclude_patterns: Vec<String>,
+
+    /// Snapshot file location
+    pub snapshot_file: PathBuf,
+
+    /// ADR template configuration
+    pub template: TemplateConfig,
+
+    /// Drift detection configuration
+    pub drift: DriftConfig,
+
+    /// ADR lint rule configuration
+    pub lint: LintConfig,
+
+    /// ADR status lifecycle
+    pub status: StatusConfig,
+
+    /// Persistent cache of parsed ADRs
+    pub cache: CacheConfig,
+
+    /// LSP-specific templates for completion
+    #[cfg(feature = "lsp")]
+    pub templates: Option<Vec<LspTemplate>>,
+}
+
+/// One or more ADR roots, written as a single path or as a list
+///
+/// ```yaml
+/// adr_dir:
+///   - docs/adr
+///   - path: services/*/docs/adr   # namespace taken from the `*` segment
+///   - path: legacy/decisions
+///     namespace: legacy
+///     template: { format: custom, custom_path: legacy/template.md }
+///     drift: { enabled: false }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdrDirs(Vec<AdrRootConfig>);
+
+/// An entry of `adr_dir`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AdrRootConfig {
+    /// Directory, or a glob matching several directories
+    pub path: PathBuf,
+
+    /// Prefix of this root's ADR ids (`payments/0007`); for globs it
+    /// defaults to the directory name matched by the first wildcard
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Template settings replacing the top-level ones for this root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<TemplateOverride>,
+
+    /// Whether this root's ADRs take part in drift detection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drift: Option<DriftOverride>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TemplateOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_path: Option<PathBuf>,
+}
+
+/// Per-root drift settings
+///
+/// There are no per-root detection patterns: the codebase is scanned once for
+/// all roots, and an ADR directory does not tell which part of the code its
+/// decisions govern, so such patterns would still apply to every file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DriftOverride {
+    /// Check the codebase against this root's ADRs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl AdrDirs {
+    pub fn roots(&self) -> &[AdrRootConfig] {
+        &self.0
+    }
+}
+
+impl AdrRootConfig {
+    fn is_plain(&self) -> bool {
+        self.namespace.is_none() && self.template.is_none() && self.drift.is_none()
+    }
+}
+
+impl From<PathBuf> for AdrRootConfig {
+    fn from(path: PathBuf) -> Self {
+        Self {
+            path,
+            namespace: None,
+            template: None,
+            drift: None,
+        }
+    }
+}
+
+impl From<PathBuf> for AdrDirs {
+    fn from(path: PathBuf) -> Self {
+        Self(vec![path.into()])
+    }
+}
+
+impl From<Vec<AdrRootConfig>> for AdrDirs {
+    fn from(roots: Vec<AdrRootConfig>) -> Self {
+        Self(roots)
+    }
+}
+
+impl PartialEq<PathBuf> for AdrDirs {
+    fn eq(&self, other: &PathBuf) -> bool {
+        matches!(&self.0[..], [root] if root.is_plain() && root.path == *other)
+    }
+}
+
+impl Serialize for AdrDirs {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match &self.0[..] {
+            [root] if root.is_plain() => root.path.serialize(serializer),
+            roots => roots.serialize(serializer),
+        }
+    }
+}
+
+/// How `adr_dir` is written in config files
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum AdrDirsRepr {
+    Path(PathBuf),
+    Roots(Vec<AdrRootEntry>),
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum AdrRootEntry {
+    Path(PathBuf),
+    Root(AdrRootConfig),
+}
+
+impl<'de> Deserialize<'de> for AdrDirs {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let roots: Vec<AdrRootConfig> = match AdrDirsRepr::deserialize(deserializer)? {
+            AdrDirsRepr::Path(path) => return Ok(path.into()),
+            AdrDirsRepr::Roots(entries) => entries
+                .into_iter()
+                .map(|entry| match entry {
+                    AdrRootEntry::Path(path) => path.into(),
+                    AdrRootEntry::Root(root) => root,
+                })
+                .collect(),
+        };
+        if roots.is_empty() {
+            return Err(serde::de::Error::custom(
+                "adr_dir must list at least one directory",
+            ));
+        }
+        Ok(Self(roots))
+    }
+}
+
+impl JsonSchema for AdrDirs {
+    fn schema_name() -> String {
+        "AdrDirs".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        AdrDirsRepr::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TemplateConfig {
+    /// ADR template format (madr, custom)
+    pub format: String,
+
+    /// Custom template path
+    pub custom_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DriftConfig {
+    /// Enable drift detection
+    pub enabled: bool,
+
+    /// Patterns for detecting architectural elements
+    pub detection_patterns: Vec<DetectionPattern>,
+
+    /// Lowest drift severity that makes `adrscan diff` fail
+    pub fail_on: DriftSeverity,
+
+    /// Layers of the codebase and which layers each may import
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<LayerRule>,
+}
+
+impl Default for TemplateConfig {
+    fn default() -> Self {
+        Self {
+            format: "madr".to_string(),
+            custom_path: None,
+        }
+    }
+}
+
+impl Default for DriftConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            detection_patterns: vec![
+                DetectionPattern {
+                    name: "Database Dependencies".to_string(),
+                    file_pattern: "**/Cargo.toml".to_string(),
+                    content_pattern: r#"(postgres|mysql|sqlite|mongodb)"#.to_string(),
+                    category: "database".to_string(),
+                    structure: None,
+                },
+                DetectionPattern {
+                    name: "Cloud Provider".to_string(),
+                    file_pattern: "**/*.tf".to_string(),
+                    content_pattern: r#"(aws|azure|gcp|google)"#.to_string(),
+                    category: "cloud".to_string(),
+                    structure: None,
+                },
+            ],
+            fail_on: DriftSeverity::Critical,
+            layers: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DetectionPattern {
+    /// Pattern name/description
+    pub name: String,
+
+    /// File glob pattern
+    pub file_pattern: String,
+
+    /// Regex pattern to match in file content. Patterns with a `structure`
+    /// fall back to it for other files, and may leave it empty to only
+    /// match structurally.
+    #[serde(default)]
+    pub content_pattern: String,
+
+    /// Category for grouping (database, framework, cloud, etc.)
+    pub category: String,
+
+    /// Structural query matched against the syntax tree of Rust, Java,
+    /// Python and TypeScript/JavaScript files instead of `content_pattern`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structure: Option<StructuralQuery>,
+}
+
+/// What a structural detection pattern matches; set exactly one field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StructuralQuery {
+    /// Calls of a function, method or constructor by path, e.g.
+    /// `reqwest::Client::new` or `requests.get`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call: Option<String>,
+
+    /// Declarations carrying an annotation, decorator or attribute, e.g.
+    /// `Entity`, `app.route` or `tokio::main`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<String>,
+
+    /// A tree-sitter query; each match reports the node captured as
+    /// `@match`, or its first capture
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// A layer of the codebase and the layers it may import
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LayerRule {
+    /// Layer name, referenced by the rules of other layers
+    pub name: String,
+
+    /// Globs of the files in the layer, relative to the scanned directory.
+    /// A layer without paths only adds rules to a layer defined elsewhere.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+
+    /// Module prefixes that refer to the layer in imports, such as
+    /// `crate::domain` or `com.acme.domain`. Without them, an import
+    /// refers to the layer when one of its path segments is the layer name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<String>,
+
+    /// Layers this layer must not import
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub must_not_import: Vec<String>,
+
+    /// The only other layers this layer may import, if set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub may_only_import: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintConfig {
+    /// Per-rule severity overrides keyed by rule name
+    #[serde(default)]
+    pub rules: HashMap<String, LintLevel>,
+
+    /// Sections every ADR must contain with non-empty content
+    #[serde(default = "default_required_sections")]
+    pub required_sections: Vec<String>,
+}
+
+/// Severity of a lint rule
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// Rule is disabled
+    Off,
+    /// Reported, never fails the run
+    Info,
+    /// Reported, fails the run with `--deny-warnings`
+    Warning,
+    /// Reported and fails the run
+    Error,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::new(),
+            required_sections: default_required_sections(),
+        }
+    }
+}
+
+fn default_required_sections() -> Vec<String> {
+    vec!["context".to_string(), "decision".to_string()]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StatusConfig {
+    /// Allowed status values, in display order
+    #[serde(default = "default_status_states")]
+    pub states: Vec<String>,
+
+    /// Allowed transitions keyed by source status; states without an entry
+    /// are final. An empty map allows any transition between known states.
+    #[serde(default = "default_status_transitions")]
+    pub transitions: HashMap<String, Vec<String>>,
+}
+
+impl Default for StatusConfig {
+    fn default() -> Self {
+        Self {
+            states: default_status_states(),
+            transitions: default_status_transitions(),
+        }
+    }
+}
+
+fn default_status_states() -> Vec<String> {
+    [
+        "proposed",
+        "accepted",
+        "rejected",
+        "deprecated",
+        "superseded",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn default_status_transitions() -> HashMap<String, Vec<String>> {
+    [
+        ("proposed", &["accepted", "rejected"][..]),
+        ("accepted", &["deprecated", "superseded"][..]),
+        ("deprecated", &["superseded"][..]),
+    ]
+    .iter()
+    .map(|(from, to)| (from.to_string(), to.iter().map(|s| s.to_string()).collect()))
+    .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CacheConfig {
+    /// Reuse parsed ADRs from a cache file outside the ADR directory
+    #[serde(default = "default_cache_enabled")]
+    pub enabled: bool,
+
+    /// Directory holding the cache files; defaults to `adrscan` in the
+    /// user's cache directory, e.g. `~/.cache/adrscan`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<PathBuf>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cache_enabled(),
+            dir: None,
+        }
+    }
+}
+
+fn default_cache_enabled() -> bool {
+    true
+}
+
+/// LSP Template definition for completion
+#[cfg(feature = "lsp")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LspTemplate {
+    /// Template name
+    pub name: String,
+    /// Template description
+    pub description: String,
+    /// Template content with snippet placeholders
+    pub content: String,
+}
+
+/// Configuration overrides given on the command line
+///
+/// They take precedence over environment variables, the selected profile
+/// and config files; `--set` assignments are applied after the other flags.
+#[derive(Debug, Clone, Default, Args)]
+#[command(next_help_heading = "Config overrides")]
+pub struct ConfigOverrides {
+    /// Profile from the config files to apply (overrides ADRSCAN_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// ADR directory; the same flag as the commands' own --adr-dir, the last
+    /// one given wins
+    #[arg(long, global = true, value_name = "DIR")]
+    pub adr_dir: Option<PathBuf>,
+    /// File patterns to include in scans (comma-separated)
+    #[arg(long, global = true, value_name = "GLOBS", value_delimiter = ',')]
+    pub include_patterns: Option<Vec<String>>,
+    /// File patterns to exclude from scanning (comma-separated)
+    #[arg(long, global = true, value_name = "GLOBS", value_delimiter = ',')]
+    pub exclude_patterns: Option<Vec<String>>,
+    /// Snapshot file for drift detection
+    #[arg(long, global = true, value_name = "FILE")]
+    pub snapshot_file: Option<PathBuf>,
+    /// ADR template format (madr, custom)
+    #[arg(long, global = true, value_name = "FORMAT")]
+    pub template_format: Option<String>,
+    /// Custom template path
+    #[arg(long, global = true, value_name = "FILE")]
+    pub template_custom_path: Option<PathBuf>,
+    /// Enable or disable drift detection
+    #[arg(long, global = true, value_name = "BOOL", value_parser = BoolishValueParser::new())]
+    pub drift_enabled: Option<bool>,
+    /// Set any config value, e.g. `--set drift.enabled=false` or
+    /// `--set exclude_patterns+=[vendor/**]`; the value is parsed as YAML
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    pub assignments: Vec<ConfigAssignment>,
+}
+
+/// A `--set key.path=value` assignment
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigAssignment {
+    /// Dotted key path, optionally with the `+` suffix that appends to a list
+    pub key: String,
+    pub value: serde_yaml::Value,
+}
+
+impl FromStr for ConfigAssignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected KEY=VALUE, found '{s}'"))?;
+        let key = key.trim();
+        if key.is_empty() || key.split('.').any(str::is_empty) {
+            return Err(format!("invalid key '{key}'"));
+        }
+        let value =
+            serde_yaml::from_str(value).map_err(|e| format!("invalid value for '{key}': {e}"))?;
+        Ok(Self {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+impl ConfigAssignment {
+    /// The assignment as a config layer, e.g. `{drift: {enabled: false}}`
+    fn layer(&self) -> Result<serde_yaml::Value> {
+        let layer = self.key.rsplit('.').fold(self.value.clone(), |value, key| {
+            let mut table = serde_yaml::Mapping::new();
+            table.insert(serde_yaml::Value::String(key.to_string()), value);
+            serde_yaml::Value::Mapping(table)
+        });
+
+        let problems = schema::validate_layer(&layer);
+        if !problems.is_empty() {
+            return Err(AdrscanError::ConfigError(format!(
+                "Invalid --set {}: {}",
+                self.key,
+                problems.join("; ")
+            )));
+        }
+        Ok(layer)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            adr_dir: PathBuf::from("docs/adr").into(),
+            include_patterns: vec![
+                "**/*.md".to_string(),
+                "**/*.rs".to_string(),
+                "**/*.py".to_string(),
+                "**/*.js".to_string(),
+                "**/*.ts".to_string(),
+                "**/*.java".to_string(),
+                "**/*.tf".to_string(),
+                "**/*.yml".to_string(),
+                "**/*.yaml".to_string(),
+                "**/*.json".to_string(),
+                "**/*.toml".to_string(),
+                // Dependency manifests
+                "**/Cargo.lock".to_string(),
+                "**/yarn.lock".to_string(),
+                "**/requirements*.txt".to_string(),
+                "**/go.mod".to_string(),
+                "**/pom.xml".to_string(),
+                "**/build.gradle".to_string(),
+                "**/build.gradle.kts".to_string(),
+            ],
+            exclude_patterns: vec![
+                "**/target/**".to_string(),
+                "**/node_modules/**".to_string(),
+                "**/.git/**".to_string(),
+                "**/build/**".to_string(),
+                "**/dist/**".to_string(),
+            ],
+            snapshot_file: PathBuf::from(".adrscan_snapshot.json"),
+            template: TemplateConfig::default(),
+            drift: DriftConfig::default(),
+            lint: LintConfig::default(),
+            status: StatusConfig::default(),
+            cache: CacheConfig::default(),
+            #[cfg(feature = "lsp")]
+            templates: None,
+        }
+    }
+}
+
+impl Config {
+    /// Load configuration from files, environment variables and command-line
+    /// overrides, in increasing order of precedence
+    pub fn load_with_overrides(
+        config_path: Option<&Path>,
+        overrides: &ConfigOverrides,
+    ) -> Result<Self> {
+        Ok(Self::load_layers_with_overrides(config_path, overrides)?.0)
+    }
+
+    /// Load configuration like [`Config::load_with_overrides`], keeping track
+    /// of the file, environment variable, flag or default every value comes
+    /// from
+    pub fn resolve(
+        config_path: Option<&Path>,
+        overrides: &ConfigOverrides,
+    ) -> Result<ResolvedConfig> {
+        Ok(Self::load_layers_with_overrides(config_path, overrides)?
+            .1
+            .resolved())
+    }
+
+    fn load_layers_with_overrides(
+        config_path: Option<&Path>,
+        overrides: &ConfigOverrides,
+    ) -> Result<(Self, ConfigLayers)> {
+        let mut layers = Self::load_layers(config_path)?;
+        let profile = overrides
+            .profile
+            .clone()
+            .or_else(|| env::var("ADRSCAN_PROFILE").ok())
+            .filter(|name| !name.is_empty());
+        if let Some(ref name) = profile {
+            layers.apply_profile(name)?;
+        }
+        let mut config = layers.config()?;
+
+        // Apply environment variable overrides
+        config.apply_env_overrides()?;
+        layers.record(&config, ConfigSource::Environment);
+
+        // Apply command-line overrides, `--set` last
+        config.apply_overrides(overrides);
+        layers.record(&config, ConfigSource::CommandLine);
+        if !overrides.assignments.is_empty() {
+            for assignment in &overrides.assignments {
+                layers.merge(assignment.layer()?, ConfigSource::CommandLine)?;
+            }
+            config = layers.config()?;
+        }
+
+        // Validate the final configuration
+        config.validate()?;
+
+        Ok((config, layers))
+    }
+
+    /// Load configuration from the config files of a workspace root and its
+    /// parents, as if adrscan was run there
+    #[allow(dead_code)] // Library API, the CLI loads from the current directory
+    pub fn from_workspace_root(workspace_root: &Path) -> Result<Self> {
+        let config = ConfigLayers::discover(workspace_root)?.config()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Defaults merged with the given config file, or with every config file
+    /// from the current directory upward
+    fn load_layers(config_path: Option<&Path>) -> Result<ConfigLayers> {
+        match config_path {
+            Some(path) => {
+                let mut layers = ConfigLayers::default();
+                layers.add_file(path)?;
+                Ok(layers)
+            }
+            None => {
+                let current_dir = env::current_dir().map_err(|e| {
+                    AdrscanError::ConfigError(format!("Cannot get current directory: {e}"))
+                })?;
+                ConfigLayers::discover(&current_dir)
+            }
+        }
+    }
+
+    /// Save configuration to file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = if path.extension().and_then(|s| s.to_str()) == Some("toml") {
+            toml::to_string_pretty(self)
+                .map_err(|e| AdrscanError::ConfigError(format!("Failed to serialize TOML: {e}")))?
+        } else {
+            // Default to YAML
+            serde_yaml::to_string(self)
+                .map_err(|e| AdrscanError::ConfigError(format!("Failed to serialize YAML: {e}")))?
+        };
+
+        std::fs::write(path, content)
+            .map_err(|e| AdrscanError::ConfigError(format!("Failed to write config file: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Apply environment variable overrides to configuration
+    fn apply_env_overrides(&mut self) -> Result<()> {
+        // ADR_DIR or ADRSCAN_ADR_DIR
+        if let Ok(adr_dir) = env::var("ADRSCAN_ADR_DIR").or_else(|_| env::var("ADR_DIR")) {
+            self.adr_dir = PathBuf::from(adr_dir).into();
+        }
+
+        // ADRSCAN_INCLUDE_PATTERNS (comma-separated)
+        if let Ok(patterns) = env::var("ADRSCAN_INCLUDE_PATTERNS") {
+            self.include_patterns = patterns
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        // ADRSCAN_EXCLUDE_PATTERNS (comma-separated)
+        if let Ok(patterns) = env::var("ADRSCAN_EXCLUDE_PATTERNS") {
+            self.exclude_patterns = patterns
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        // ADRSCAN_SNAPSHOT_FILE
+        if let Ok(snapshot_file) = env::var("ADRSCAN_SNAPSHOT_FILE") {
+            self.snapshot_file = PathBuf::from(snapshot_file);
+        }
+
+        // ADRSCAN_TEMPLATE_FORMAT
+        if let Ok(format) = env::var("ADRSCAN_TEMPLATE_FORMAT") {
+            self.template.format = format;
+        }
+
+        // ADRSCAN_TEMPLATE_CUSTOM_PATH
+        if let Ok(custom_path) = env::var("ADRSCAN_TEMPLATE_CUSTOM_PATH") {
+            self.template.custom_path = Some(PathBuf::from(custom_path));
+        }
+
+        // ADRSCAN_DRIFT_ENABLED
+        if let Ok(enabled) = env::var("ADRSCAN_DRIFT_ENABLED") {
+            match enabled.to_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => self.drift.enabled = true,
+                "false" | "0" | "no" | "off" => self.drift.enabled = false,
+                _ => return Err(AdrscanError::ConfigError(
+                    format!("Invalid value for ADRSCAN_DRIFT_ENABLED: '{enabled}'. Expected true/false, 1/0, yes/no, or on/off")
+                )),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply the typed command-line overrides
+    fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        if let Some(ref adr_dir) = overrides.adr_dir {
+            self.adr_dir = adr_dir.clone().into();
+        }
+        if let Some(ref include_patterns) = overrides.include_patterns {
+            self.include_patterns = include_patterns.clone();
+        }
+        if let Some(ref exclude_patterns) = overrides.exclude_patterns {
+            self.exclude_patterns = exclude_patterns.clone();
+        }
+        if let Some(ref snapshot_file) = overrides.snapshot_file {
+            self.snapshot_file = snapshot_file.clone();
+        }
+        if let Some(ref template_format) = overrides.template_format {
+            self.template.format = template_format.clone();
+        }
+        if let Some(ref template_custom_path) = overrides.template_custom_path {
+            self.template.custom_path = Some(template_custom_path.clone());
+        }
+        if let Some(drift_enabled) = overrides.drift_enabled {
+            self.drift.enabled = drift_enabled;
+        }
+    }
+
+    /// Validate configuration values
+    pub(crate) fn validate(&self) -> Result<()> {
+        // Validate ADR directory paths
+        for root in self.adr_dir.roots() {
+            if root.path.to_string_lossy().is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "ADR directory path cannot be empty".to_string(),
+                ));
+            }
+            if let Some(ref namespace) = root.namespace {
+                if namespace.is_empty() || namespace.contains('/') {
+                    return Err(AdrscanError::ConfigError(format!(
+                        "Invalid namespace '{namespace}' for {}: must be non-empty and contain no '/'",
+                        root.path.display()
+                    )));
+                }
+            }
+        }
+
+        // Validate include patterns
+        if self.include_patterns.is_empty() {
+            return Err(AdrscanError::ConfigError(
+                "At least one include pattern must be specified".to_string(),
+            ));
+        }
+
+        // Validate patterns for basic glob syntax
+        for pattern in &self.include_patterns {
+            if pattern.is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "Include patterns cannot be empty".to_string(),
+                ));
+            }
+        }
+
+        for pattern in &self.exclude_patterns {
+            if pattern.is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "Exclude patterns cannot be empty".to_string(),
+                ));
+            }
+        }
+
+        // Validate snapshot file path
+        if self.snapshot_file.to_string_lossy().is_empty() {
+            return Err(AdrscanError::ConfigError(
+                "Snapshot file path cannot be empty".to_string(),
+            ));
+        }
+
+        // Validate template format
+        match self.template.format.as_str() {
+            "madr" | "custom" => {}
+            _ => {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Invalid template format '{}'. Supported formats: 'madr', 'custom'",
+                    self.template.format
+                )))
+            }
+        }
+
+        // Validate custom template path if format is custom
+        if self.template.format == "custom" && self.template.custom_path.is_none() {
+            return Err(AdrscanError::ConfigError(
+                "Custom template path must be specified when format is 'custom'".to_string(),
+            ));
+        }
+
+        // Validate custom template path exists if specified
+        if let Some(ref custom_path) = self.template.custom_path {
+            if !custom_path.exists() {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Custom template file does not exist: {}",
+                    custom_path.display()
+                )));
+            }
+        }
+
+        // Validate detection patterns
+        for pattern in &self.drift.detection_patterns {
+            if pattern.name.is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "Detection pattern name cannot be empty".to_string(),
+                ));
+            }
+            if pattern.file_pattern.is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "Detection pattern file_pattern cannot be empty".to_string(),
+                ));
+            }
+            match pattern.structure {
+                None if pattern.content_pattern.is_empty() => {
+                    return Err(AdrscanError::ConfigError(
+                        "Detection pattern content_pattern cannot be empty".to_string(),
+                    ));
+                }
+                Some(ref structure)
+                    if [&structure.call, &structure.annotation, &structure.query]
+                        .iter()
+                        .filter(|field| field.is_some())
+                        .count()
+                        != 1 =>
+                {
+                    return Err(AdrscanError::ConfigError(format!(
+                        "Detection pattern '{}' structure must set exactly one of call, annotation and query",
+                        pattern.name
+                    )));
+                }
+                _ => {}
+            }
+            if pattern.category.is_empty() {
+                return Err(AdrscanError::ConfigError(
+                    "Detection pattern category cannot be empty".to_string(),
+                ));
+            }
+
+            // Validate regex pattern
+            if let Err(e) = regex::Regex::new(&pattern.content_pattern) {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Invalid regex pattern '{}': {}",
+                    pattern.content_pattern, e
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create a sample configuration file
+    #[allow(dead_code)] // Planned for CLI integration
+    pub fn create_sample_config(path: &Path, format: &str) -> Result<()> {
+        let config = Self::default();
+
+        let content = match format {
+            "toml" => toml::to_string_pretty(&config).map_err(|e| {
+                AdrscanError::ConfigError(format!("Failed to serialize sample TOML: {e}"))
+            })?,
+            "yaml" | "yml" => serde_yaml::to_string(&config).map_err(|e| {
+                AdrscanError::ConfigError(format!("Failed to serialize sample YAML: {e}"))
+            })?,
+            _ => {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Unsupported config format: {format}. Use 'toml' or 'yaml'"
+                )))
+            }
+        };
+
+        std::fs::write(path, content).map_err(|e| {
+            AdrscanError::ConfigError(format!("Failed to write sample config: {e}"))
+        })?;
+
+        Ok(())
+    }
+
+    /// Get configuration from environment variables only (for testing/debugging)
+    #[allow(dead_code)] // Planned for CLI integration
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+        config.apply_env_overrides()?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_default_config() {
+        let config = Config::default();
+        assert_eq!(config.adr_dir, PathBuf::from("docs/adr"));
+        assert!(config.include_patterns.contains(&"**/*.md".to_string()));
+        assert!(config
+            .exclude_patterns
+            .contains(&"**/target/**".to_string()));
+        assert_eq!(config.template.format, "madr");
+        assert!(config.drift.enabled);
+    }
+
+    #[test]
+    fn test_load_yaml_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".adrscan.yml");
+
+        let yaml_content = r#"
+adr_dir: "custom/adr"
+include_patterns:
+  - "**/*.md"
+  - "**/*.txt"
+exclude_patterns:
+  - "**/build/**"
+snapshot_file: "custom_snapshot.json"
+template:
+  format: "custom"
+  custom_path: "templates/custom.md"
+drift:
+  enabled: false
+  detection_patterns: []
+"#;
+
+        fs::write(&config_path, yaml_content).unwrap();
+
+        let config = Config::load_layers(Some(&config_path))
+            .and_then(|layers| layers.config())
+            .unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("custom/adr"));
+        assert_eq!(config.include_patterns, vec!["**/*.md", "**/*.txt"]);
+        assert_eq!(config.exclude_patterns, vec!["**/build/**"]);
+        assert_eq!(config.template.format, "custom");
+        assert!(!config.drift.enabled);
+    }
+
+    #[test]
+    fn test_load_multiple_adr_roots() {
+        let yaml_content = r#"
+- docs/adr
+- path: services/*/docs/adr
+  drift:
+    enabled: false
+- path: legacy/decisions
+  namespace: legacy
+  template:
+    format: custom
+    custom_path: legacy/template.md
+"#;
+
+        let adr_dirs: AdrDirs = serde_yaml::from_str(yaml_content).unwrap();
+        let roots = adr_dirs.roots();
+        assert_eq!(roots.len(), 3);
+        assert_eq!(roots[0].path, PathBuf::from("docs/adr"));
+        assert_eq!(roots[1].drift.as_ref().unwrap().enabled, Some(false));
+        assert_eq!(roots[2].namespace.as_deref(), Some("legacy"));
+        assert_eq!(
+            roots[2].template.as_ref().unwrap().format.as_deref(),
+            Some("custom")
+        );
+
+        // A single plain root round-trips as a plain path
+        let yaml = serde_yaml::to_string(&Config::default()).unwrap();
+        assert!(yaml.contains("adr_dir: docs/adr"));
+        assert!(serde_yaml::from_str::<AdrDirs>("[]").is_err());
+
+        let invalid = Config {
+            adr_dir: AdrDirs::from(vec![AdrRootConfig {
+                namespace: Some("a/b".to_string()),
+                ..PathBuf::from("docs/adr").into()
+            }]),
+            ..Config::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_load_toml_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".adrscan.toml");
+
+        let toml_content = r#"
+adr_dir = "toml/adr"
+include_patterns = ["**/*.md"]
+exclude_patterns = ["**/node_modules/**"]
+snapshot_file = "toml_snapshot.json"
+
+[template]
+format = "madr"
+
+[drift]
+enabled = true
+detection_patterns = []
+"#;
+
+        fs::write(&config_path, toml_content).unwrap();
+
+        let config = Config::load_layers(Some(&config_path))
+            .and_then(|layers| layers.config())
+            .unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("toml/adr"));
+        assert_eq!(config.include_patterns, vec!["**/*.md"]);
+        assert_eq!(config.template.format, "madr");
+        assert!(config.drift.enabled);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        env::set_var("ADRSCAN_ADR_DIR", "env/adr");
+        env::set_var("ADRSCAN_INCLUDE_PATTERNS", "*.md,*.txt");
+        env::set_var("ADRSCAN_EXCLUDE_PATTERNS", "build/**,tmp/**");
+        env::set_var("ADRSCAN_TEMPLATE_FORMAT", "custom");
+        env::set_var("ADRSCAN_DRIFT_ENABLED", "false");
+
+        let mut config = Config::default();
+        config.apply_env_overrides().unwrap();
+
+        assert_eq!(config.adr_dir, PathBuf::from("env/adr"));
+        assert_eq!(config.include_patterns, vec!["*.md", "*.txt"]);
+        assert_eq!(config.exclude_patterns, vec!["build/**", "tmp/**"]);
+        assert_eq!(config.template.format, "custom");
+        assert!(!config.drift.enabled);
+
+        // Clean up
+        env::remove_var("ADRSCAN_ADR_DIR");
+        env::remove_var("ADRSCAN_INCLUDE_PATTERNS");
+        env::remove_var("ADRSCAN_EXCLUDE_PATTERNS");
+        env::remove_var("ADRSCAN_TEMPLATE_FORMAT");
+        env::remove_var("ADRSCAN_DRIFT_ENABLED");
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        let mut config = Config::default();
+        let overrides = ConfigOverrides {
+            adr_dir: Some(PathBuf::from("override/adr")),
+            include_patterns: Some(vec!["*.override".to_string()]),
+            template_format: Some("custom".to_string()),
+            drift_enabled: Some(false),
+            ..Default::default()
+        };
+
+        config.apply_overrides(&overrides);
+
+        assert_eq!(config.adr_dir, PathBuf::from("override/adr"));
+        assert_eq!(config.include_patterns, vec!["*.override"]);
+        assert_eq!(config.template.format, "custom");
+        assert!(!config.drift.enabled);
+    }
+
+    #[test]
+    fn test_validation_success() {
+        let config = Config::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validation_empty_adr_dir() {
+        let mut config = Config::default();
+        config.adr_dir = PathBuf::from("").into();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_empty_include_patterns() {
+        let mut config = Config::default();
+        config.include_patterns = vec![];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_invalid_template_format() {
+        let mut config = Config::default();
+        config.template.format = "invalid".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_custom_template_without_path() {
+        let mut config = Config::default();
+        config.template.format = "custom".to_string();
+        config.template.custom_path = None;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_invalid_regex_pattern() {
+        let mut config = Config::default();
+        config.drift.detection_patterns.push(DetectionPattern {
+            name: "Invalid Pattern".to_string(),
+            file_pattern: "*.rs".to_string(),
+            content_pattern: "[invalid_regex".to_string(), // Invalid regex
+            category: "test".to_string(),
+            structure: None,
+        });
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_structural_pattern() {
+        let mut config = Config::default();
+        config.drift.detection_patterns.push(DetectionPattern {
+            name: "Reqwest Client".to_string(),
+            file_pattern: "**/*.rs".to_string(),
+            content_pattern: String::new(),
+            category: "http".to_string(),
+            structure: Some(StructuralQuery {
+                call: Some("reqwest::Client::new".to_string()),
+                annotation: None,
+                query: None,
+            }),
+        });
+        assert!(config.validate().is_ok());
+
+        let pattern = config.drift.detection_patterns.last_mut().unwrap();
+        pattern.structure.as_mut().unwrap().annotation = Some("Entity".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_sample_config_yaml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("sample.yml");
+
+        Config::create_sample_config(&config_path, "yaml").unwrap();
+        assert!(config_path.exists());
+
+        // Verify it can be loaded back
+        let loaded_config = Config::load_layers(Some(&config_path))
+            .and_then(|layers| layers.config())
+            .unwrap();
+        let default_config = Config::default();
+        assert_eq!(loaded_config.adr_dir, default_config.adr_dir);
+    }
+
+    #[test]
+    fn test_create_sample_config_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("sample.toml");
+
+        Config::create_sample_config(&config_path, "toml").unwrap();
+        assert!(config_path.exists());
+
+        // Verify it can be loaded back
+        let loaded_config = Config::load_layers(Some(&config_path))
+            .and_then(|layers| layers.config())
+            .unwrap();
+        let default_config = Config::default();
+        assert_eq!(
+            loaded_config.template.format,
+            default_config.template.format
+        );
+    }
+
+    #[test]
+    fn test_env_drift_enabled_variations() {
+        let test_cases = vec![
+            ("true", true),
+            ("TRUE", true),
+            ("1", true),
+            ("yes", true),
+            ("YES", true),
+            ("on", true),
+            ("ON", true),
+            ("false", false),
+            ("FALSE", false),
+            ("0", false),
+            ("no", false),
+            ("NO", false),
+            ("off", false),
+            ("OFF", false),
+        ];
+
+        for (env_val, expected) in test_cases {
+            // Clear any existing environment variables
+            env::remove_var("ADRSCAN_DRIFT_ENABLED");
+
+            env::set_var("ADRSCAN_DRIFT_ENABLED", env_val);
+            let mut config = Config::default();
+            // Set initial state to opposite of expected to ensure the env var actually changes it
+            config.drift.enabled = !expected;
+            config.apply_env_overrides().unwrap();
+            assert_eq!(
+                config.drift.enabled, expected,
+                "Failed for value: {} (original: {})",
+                env_val, !expected
+            );
+
+            // Clean up after each test case
+            env::remove_var("ADRSCAN_DRIFT_ENABLED");
+        }
+
+        // Test invalid value
+        env::set_var("ADRSCAN_DRIFT_ENABLED", "maybe");
+        let mut config = Config::default();
+        assert!(config.apply_env_overrides().is_err());
+
+        env::remove_var("ADRSCAN_DRIFT_ENABLED");
+    }
+
+    #[test]
+    fn test_load_with_overrides() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".adrscan.yml");
+
+        let yaml_content = r#"
+adr_dir: "yaml/adr"
+include_patterns: ["**/*.md"]
+exclude_patterns: []
+snapshot_file: "test_snapshot.json"
+template:
+  format: "madr"
+drift:
+  enabled: true
+  detection_patterns: []
+"#;
+        fs::write(&config_path, yaml_content).unwrap();
+
+        let overrides = ConfigOverrides {
+            adr_dir: Some(PathBuf::from("override/adr")),
+            include_patterns: Some(vec!["*.override".to_string()]),
+            ..Default::default()
+        };
+
+        let config = Config::load_with_overrides(Some(&config_path), &overrides).unwrap();
+
+        // Overrides should take precedence
+        assert_eq!(config.adr_dir, PathBuf::from("override/adr"));
+        assert_eq!(config.include_patterns, vec!["*.override"]);
+    }
+
+    #[test]
+    fn test_set_assignments() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".adrscan.yml");
+        fs::write(
+            &config_path,
+            "adr_dir: yaml/adr\nsnapshot_file: file.json\n",
+        )
+        .unwrap();
+
+        let assignment: ConfigAssignment = "drift.enabled=false".parse().unwrap();
+        assert_eq!(assignment.key, "drift.enabled");
+        assert_eq!(assignment.value, serde_yaml::Value::Bool(false));
+        assert!("drift.enabled".parse::<ConfigAssignment>().is_err());
+        assert!("drift..enabled=true".parse::<ConfigAssignment>().is_err());
+
+        // `--set` is applied after the typed flags
+        let overrides = ConfigOverrides {
+            adr_dir: Some(PathBuf::from("flag/adr")),
+            drift_enabled: Some(true),
+            assignments: vec![
+                assignment,
+                "exclude_patterns+=[\"**/vendor/**\"]".parse().unwrap(),
+                "lint.rules.title=off".parse().unwrap(),
+            ],
+            ..Default::default()
+        };
+        let config = Config::load_with_overrides(Some(&config_path), &overrides).unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("flag/adr"));
+        assert_eq!(config.snapshot_file, PathBuf::from("file.json"));
+        assert!(!config.drift.enabled);
+        assert_eq!(config.exclude_patterns.last().unwrap(), "**/vendor/**");
+        assert_eq!(config.lint.rules["title"], LintLevel::Off);
+
+        let resolved = Config::resolve(Some(&config_path), &overrides).unwrap();
+        let sources = |key: &str| {
+            resolved
+                .values
+                .iter()
+                .find(|value| value.key == key)
+                .unwrap()
+                .sources
+                .clone()
+        };
+        assert_eq!(sources("adr_dir"), [ConfigSource::CommandLine]);
+        assert_eq!(
+            sources("snapshot_file"),
+            [ConfigSource::File(config_path.clone())]
+        );
+        assert_eq!(sources("drift.enabled"), [ConfigSource::CommandLine]);
+
+        for invalid in ["drift.enabld=false", "drift.enabled=maybe"] {
+            let overrides = ConfigOverrides {
+                assignments: vec![invalid.parse().unwrap()],
+                ..Default::default()
+            };
+            assert!(matches!(
+                Config::load_with_overrides(Some(&config_path), &overrides),
+                Err(AdrscanError::ConfigError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_config_search_in_parent_directories() {
+        // This test is more complex to set up, so we'll just test the logic
+        // In a real scenario, it would search parent directories for config files
+        let temp_dir = TempDir::new().unwrap();
+        let sub_dir = temp_dir.path().join("subdir");
+        fs::create_dir(&sub_dir).unwrap();
+
+        let config_path = temp_dir.path().join(".adrscan.yml");
+        let yaml_content = r#"
+adr_dir: "parent/adr"
+include_patterns: ["**/*.md"]
+exclude_patterns: []
+snapshot_file: "parent_snapshot.json"
+template:
+  format: "madr"
+drift:
+  enabled: true
+  detection_patterns: []
+"#;
+        fs::write(&config_path, yaml_content).unwrap();
+
+        // Change to subdirectory
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(&sub_dir).unwrap();
+
+        // Should find config in parent directory
+        let config = Config::load_layers(None).unwrap().config().unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("parent/adr"));
+
+        // Restore original directory
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[test]
+    fn test_from_workspace_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let service = temp_dir.path().join("service");
+        fs::create_dir(&service).unwrap();
+        fs::write(
+            temp_dir.path().join(".adrscan.yml"),
+            "root: true\nadr_dir: decisions\n",
+        )
+        .unwrap();
+        fs::write(
+            service.join(".adrscan.yml"),
+            "include_patterns: [\"**/*.md\"]\n",
+        )
+        .unwrap();
+
+        let config = Config::from_workspace_root(&service).unwrap();
+        assert_eq!(config.adr_dir, PathBuf::from("decisions"));
+        assert_eq!(config.include_patterns, vec!["**/*.md"]);
+
+        fs::write(service.join(".adrscan.yml"), "include_patterns: []\n").unwrap();
+        assert!(Config::from_workspace_root(&service).is_err());
+    }
+}
@@ -0,0 +1,846 @@
+//! Configuration Schema
+//!
+//! A JSON Schema for config files is generated from the `Config` types, so
+//! it cannot drift from what adrscan actually reads. Editors can use it for
+//! completion (`adrscan config schema`), and every config file is validated
+//! against it when loaded: type errors are reported with the line and
+//! column of the offending key, and unknown keys, which serde would
+//! silently ignore, produce warnings.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::layers::{APPEND_SUFFIX, PROFILES_KEY, ROOT_KEY};
+use super::migrate::VERSION_KEY;
+use super::Config;
+use crate::error::AdrscanError;
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Severity of a config diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The file cannot be loaded
+    Error,
+    /// The file loads, but probably does not do what was intended
+    Warning,
+}
+
+/// A problem found in a config file
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigDiagnostic {
+    pub file: PathBuf,
+    /// 1-based position of the offending key or value, when known
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// Dotted key path with list indices, e.g. `drift.detection_patterns[0].name`;
+    /// empty for problems with the whole file
+    pub key: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, ": {severity}: {}", self.message)
+    }
+}
+
+/// JSON Schema of a config file
+///
+/// Besides the `Config` fields this allows the keys only meaningful while
+/// merging layers: `root`, `profiles` with the same fields as the top level,
+/// and a `key+` variant of every list.
+pub fn json_schema() -> Value {
+    let mut schema =
+        serde_json::to_value(schemars::schema_for!(Config)).expect("config schema is serializable");
+
+    if let Some(definitions) = schema.get_mut("definitions").and_then(Value::as_object_mut) {
+        for definition in definitions.values_mut() {
+            add_append_keys(definition);
+        }
+    }
+    add_append_keys(&mut schema);
+
+    let mut profile_properties = schema["properties"].clone();
+    if let Some(properties) = profile_properties.as_object_mut() {
+        properties.remove(VERSION_KEY);
+    }
+    let profile = serde_json::json!({
+        "description": "Settings applied on top of the config files when the profile is selected",
+        "type": "object",
+        "properties": profile_properties,
+    });
+    if let Some(definitions) = schema.get_mut("definitions").and_then(Value::as_object_mut) {
+        definitions.insert("Profile".to_string(), profile);
+    }
+    if let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) {
+        properties.insert(
+            PROFILES_KEY.to_string(),
+            serde_json::json!({
+                "description": "Named profiles, selected with `--profile` or ADRSCAN_PROFILE",
+                "type": "object",
+                "additionalProperties": { "$ref": "#/definitions/Profile" }
+            }),
+        );
+        properties.insert(
+            ROOT_KEY.to_string(),
+            serde_json::json!({
+                "description": "Stop looking for config files in parent directories",
+                "type": "boolean"
+            }),
+        );
+    }
+    schema
+}
+
+/// Allow `key+` next to every list property of an object schema
+fn add_append_keys(schema: &mut Value) {
+    let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) else {
+        return;
+    };
+    let lists: Vec<(String, Value)> = properties
+        .iter()
+        .filter(|(_, property)| allows_type(property, "array"))
+        .map(|(name, property)| {
+            let mut property = property.clone();
+            if let Some(property) = property.as_object_mut() {
+                property.remove("default");
+                property.insert(
+                    "description".to_string(),
+                    Value::String(format!("Items appended to the inherited `{name}`")),
+                );
+            }
+            (format!("{name}{APPEND_SUFFIX}"), property)
+        })
+        .collect();
+    properties.extend(lists);
+}
+
+/// Validate a config file against the schema
+///
+/// Problems with the file content, including syntax errors, are returned as
+/// diagnostics; only failing to read the file is an error.
+pub fn validate_file(path: &Path) -> Result<Vec<ConfigDiagnostic>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AdrscanError::ConfigError(format!(
+            "Failed to read config file {}: {e}",
+            path.display()
+        ))
+    })?;
+    Ok(validate_str(&content, path))
+}
+
+/// Validate the content of the config file `path`; TOML files are
+/// recognized by their extension
+pub fn validate_str(content: &str, path: &Path) -> Vec<ConfigDiagnostic> {
+    let diagnostic =
+        |key: String, position: Option<Position>, severity, message| ConfigDiagnostic {
+            file: path.to_path_buf(),
+            line: position.map(|p| p.line),
+            column: position.map(|p| p.column),
+            key,
+            severity,
+            message,
+        };
+
+    let Located {
+        value,
+        keys: positions,
+        ..
+    } = match parse(content, path) {
+        Ok(parsed) => parsed,
+        Err((position, message)) => {
+            return vec![diagnostic(
+                String::new(),
+                position,
+                Severity::Error,
+                message,
+            )]
+        }
+    };
+    // An empty file sets nothing
+    if value.is_null() {
+        return Vec::new();
+    }
+
+    let schema = json_schema();
+    let mut issues = Vec::new();
+    Validator { root: &schema }.check(&schema, &value, "", &mut issues);
+
+    let mut diagnostics: Vec<ConfigDiagnostic> = issues
+        .into_iter()
+        .map(|issue| {
+            let message = if issue.key.is_empty() {
+                issue.message
+            } else {
+                format!("'{}': {}", issue.key, issue.message)
+            };
+            let position = locate(&positions, &issue.key);
+            diagnostic(issue.key, position, issue.severity, message)
+        })
+        .collect();
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    diagnostics
+}
+
+/// Validate a config layer that does not come from a file, e.g. a `--set`
+/// assignment
+///
+/// Unknown keys are problems too: nothing else would point out the typo.
+pub fn validate_layer(layer: &serde_yaml::Value) -> Vec<String> {
+    let Ok(value) = serde_json::to_value(layer) else {
+        return vec!["cannot be represented as JSON".to_string()];
+    };
+    let schema = json_schema();
+    let mut issues = Vec::new();
+    Validator { root: &schema }.check(&schema, &value, "", &mut issues);
+    issues
+        .into_iter()
+        .map(|issue| format!("'{}': {}", issue.key, issue.message))
+        .collect()
+}
+
+/// 1-based line and column in a config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Positions by key path
+pub(super) type Positions = BTreeMap<String, Position>;
+
+/// A config file with the positions of what it contains
+pub(super) struct Located {
+    pub value: Value,
+    /// Positions of the keys, and of the items of lists
+    pub keys: Positions,
+    /// Positions of the scalar values
+    pub values: Positions,
+}
+
+type Parsed = std::result::Result<Located, (Option<Position>, String)>;
+
+/// Parse the content of the config file `path`; TOML files are recognized
+/// by their extension
+pub(super) fn parse(content: &str, path: &Path) -> Parsed {
+    if path.extension().and_then(|s| s.to_str()) == Some("toml") {
+        parse_toml(content)
+    } else {
+        parse_yaml(content)
+    }
+}
+
+/// Position of `key`, or of its closest ancestor that has one
+fn locate(positions: &Positions, key: &str) -> Option<Position> {
+    let mut key = key;
+    loop {
+        if let Some(position) = positions.get(key) {
+            return Some(*position);
+        }
+        if key.is_empty() {
+            return None;
+        }
+        key = key.rfind(['.', '[']).map_or("", |end| &key[..end]);
+    }
+}
+
+pub(super) fn item_path(path: &str, index: usize) -> String {
+    format!("{path}[{index}]")
+}
+
+pub(super) fn key_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn parse_yaml(content: &str) -> Parsed {
+    let value: serde_yaml::Value = serde_yaml::from_str(content).map_err(|e| {
+        let position = e.location().map(|location| Position {
+            line: location.line(),
+            column: location.column(),
+        });
+        (position, format!("Invalid YAML: {e}"))
+    })?;
+    let value = serde_json::to_value(value).map_err(|e| (None, format!("Invalid YAML: {e}")))?;
+
+    let mut receiver = YamlPositions::default();
+    let mut parser = yaml_rust2::parser::Parser::new(content.chars());
+    // Only positions are collected here; serde_yaml already accepted the file
+    if let Err(e) = parser.load(&mut receiver, false) {
+        log::debug!("Could not locate config keys: {e}");
+    }
+    Ok(Located {
+        value,
+        keys: receiver.positions,
+        values: receiver.values,
+    })
+}
+
+/// Collects key positions from YAML parser events
+#[derive(Default)]
+struct YamlPositions {
+    positions: Positions,
+    values: Positions,
+    /// Open mappings and sequences
+    stack: Vec<YamlFrame>,
+}
+
+enum YamlFrame {
+    /// A mapping at `path`, with the key whose value comes next
+    Mapping { path: String, key: Option<String> },
+    /// A sequence at `path`, with the index of the next item
+    Sequence { path: String, index: usize },
+}
+
+impl YamlPositions {
+    /// Path of the value that starts at `position`, or `None` when the
+    /// event is a mapping key
+    fn next_value(&mut self, scalar: Option<&str>, position: Position) -> Option<String> {
+        match self.stack.last_mut() {
+            None => Some(String::new()),
+            Some(YamlFrame::Sequence { path, index }) => {
+                // Block collections start at their first entry, not where
+                // the parser reports them
+                self.positions.entry(path.clone()).or_insert(position);
+                let item = item_path(path, *index);
+                *index += 1;
+                if scalar.is_some() {
+                    self.positions.insert(item.clone(), position);
+                }
+                Some(item)
+            }
+            Some(YamlFrame::Mapping { path, key }) => match key.take() {
+                Some(key) => Some(key_path(path, &key)),
+                None => {
+                    self.positions.entry(path.clone()).or_insert(position);
+                    // Complex keys cannot be addressed and are rejected later
+                    let name = scalar.unwrap_or("?").to_string();
+                    self.positions.insert(key_path(path, &name), position);
+                    *key = Some(name);
+                    None
+                }
+            },
+        }
+    }
+}
+
+impl yaml_rust2::parser::MarkedEventReceiver for YamlPositions {
+    fn on_event(&mut self, event: yaml_rust2::Event, marker: yaml_rust2::scanner::Marker) {
+        use yaml_rust2::Event;
+
+        // The scanner counts lines from 1 and columns from 0
+        let position = Position {
+            line: marker.line(),
+            column: marker.col() + 1,
+        };
+        match event {
+            Event::Scalar(ref value, ..) => {
+                if let Some(path) = self.next_value(Some(value), position) {
+                    self.values.insert(path, position);
+                }
+            }
+            Event::Alias(_) => {
+                self.next_value(Some("*"), position);
+            }
+            Event::MappingStart(..) => {
+                let path = self.next_value(None, position).unwrap_or_default();
+                self.stack.push(YamlFrame::Mapping { path, key: None });
+            }
+            Event::SequenceStart(..) => {
+                let path = self.next_value(None, position).unwrap_or_default();
+                self.stack.push(YamlFrame::Sequence { path, index: 0 });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_toml(content: &str) -> Parsed {
+    let table = toml::de::DeTable::parse(content).map_err(|e| {
+        let position = e.span().map(|span| offset_position(content, span.start));
+        (position, format!("Invalid TOML: {}", e.message()))
+    })?;
+    let value: toml::Table =
+        toml::from_str(content).map_err(|e| (None, format!("Invalid TOML: {}", e.message())))?;
+    let value = serde_json::to_value(value).map_err(|e| (None, format!("Invalid TOML: {e}")))?;
+
+    let mut located = Located {
+        value,
+        keys: Positions::new(),
+        values: Positions::new(),
+    };
+    toml_positions(content, table.get_ref(), "", &mut located);
+    Ok(located)
+}
+
+fn toml_positions(content: &str, table: &toml::de::DeTable<'_>, path: &str, located: &mut Located) {
+    for (key, value) in table {
+        let path = key_path(path, key.get_ref());
+        located
+            .keys
+            .insert(path.clone(), offset_position(content, key.span().start));
+        toml_value_positions(content, value, &path, located);
+    }
+}
+
+fn toml_value_positions(
+    content: &str,
+    value: &toml::Spanned<toml::de::DeValue<'_>>,
+    path: &str,
+    located: &mut Located,
+) {
+    match value.get_ref() {
+        toml::de::DeValue::Table(table) => toml_positions(content, table, path, located),
+        toml::de::DeValue::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let path = item_path(path, index);
+                located
+                    .keys
+                    .insert(path.clone(), offset_position(content, item.span().start));
+                toml_value_positions(content, item, &path, located);
+            }
+        }
+        _ => {
+            located.values.insert(
+                path.to_string(),
+                offset_position(content, value.span().start),
+            );
+        }
+    }
+}
+
+/// Line and column of a byte offset
+fn offset_position(content: &str, offset: usize) -> Position {
+    let before = &content[..offset.min(content.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+struct Issue {
+    key: String,
+    severity: Severity,
+    message: String,
+}
+
+/// Checks values against the subset of JSON Schema that schemars emits
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+impl Validator<'_> {
+    fn check(&self, schema: &Value, value: &Value, path: &str, issues: &mut Vec<Issue>) {
+        let schema = self.resolve(schema);
+        let Some(keywords) = schema.as_object() else {
+            // `true` accepts anything
+            return;
+        };
+        let error = |issues: &mut Vec<Issue>, message: String| {
+            issues.push(Issue {
+                key: path.to_string(),
+                severity: Severity::Error,
+                message,
+            })
+        };
+
+        if let Some(all) = keywords.get("allOf").and_then(Value::as_array) {
+            for schema in all {
+                self.check(schema, value, path, issues);
+            }
+        }
+        if let Some(any) = keywords
+            .get("anyOf")
+            .or_else(|| keywords.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            if !self.check_any(any, value, path, issues) {
+                return;
+            }
+        }
+
+        if !declares_type(schema, value) {
+            error(
+                issues,
+                format!(
+                    "expected {}, found {}",
+                    self.expected(schema),
+                    type_name(value)
+                ),
+            );
+            return;
+        }
+        if let Some(allowed) = keywords.get("enum").and_then(Value::as_array) {
+            if !allowed.contains(value) {
+                let names: Vec<String> = allowed.iter().map(Value::to_string).collect();
+                error(
+                    issues,
+                    format!("{value} is not one of {}", names.join(", ")),
+                );
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = keywords.get("minimum").and_then(Value::as_f64) {
+                if number < minimum {
+                    error(issues, format!("must be at least {minimum}"));
+                }
+            }
+            if let Some(maximum) = keywords.get("maximum").and_then(Value::as_f64) {
+                if number > maximum {
+                    error(issues, format!("must be at most {maximum}"));
+                }
+            }
+        }
+
+        match value {
+            Value::Object(object) => self.check_object(keywords, object, path, issues),
+            Value::Array(items) => {
+                if let Some(item_schema) = keywords.get("items") {
+                    for (index, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &item_path(path, index), issues);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_object(
+        &self,
+        keywords: &Map<String, Value>,
+        object: &Map<String, Value>,
+        path: &str,
+        issues: &mut Vec<Issue>,
+    ) {
+        let properties = keywords.get("properties").and_then(Value::as_object);
+        let additional = keywords.get("additionalProperties");
+
+        if let Some(required) = keywords.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    issues.push(Issue {
+                        key: path.to_string(),
+                        severity: Severity::Error,
+                        message: format!("missing required key '{name}'"),
+                    });
+                }
+            }
+        }
+
+        for (name, value) in object {
+            let key = key_path(path, name);
+            match (properties.and_then(|p| p.get(name)), additional) {
+                (Some(schema), _) => self.check(schema, value, &key, issues),
+                (None, Some(Value::Bool(false))) => issues.push(Issue {
+                    key,
+                    severity: Severity::Error,
+                    message: "unknown key".to_string(),
+                }),
+                (None, Some(schema)) => self.check(schema, value, &key, issues),
+                // Serde ignores unknown keys, which usually means a typo
+                (None, None) if properties.is_some() => issues.push(Issue {
+                    key,
+                    severity: Severity::Warning,
+                    message: "unknown key".to_string(),
+                }),
+                (None, None) => {}
+            }
+        }
+    }
+
+    /// Check a value against alternatives, reporting the problems of the
+    /// closest one; false when no alternative has the type of the value
+    fn check_any(
+        &self,
+        schemas: &[Value],
+        value: &Value,
+        path: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        let candidates: Vec<&Value> = schemas
+            .iter()
+            .filter(|schema| self.accepts_type(self.resolve(schema), value))
+            .collect();
+        if candidates.is_empty() {
+            let expected: Vec<String> = schemas
+                .iter()
+                .map(|schema| self.expected(self.resolve(schema)))
+                .collect();
+            issues.push(Issue {
+                key: path.to_string(),
+                severity: Severity::Error,
+                message: format!(
+                    "expected {}, found {}",
+                    expected.join(" or "),
+                    type_name(value)
+                ),
+            });
+            return false;
+        }
+
+        // Unit enum variants are one alternative each
+        let allowed: Option<Vec<&Value>> = schemas
+            .iter()
+            .map(|schema| self.resolve(schema).get("enum").and_then(Value::as_array))
+            .try_fold(Vec::new(), |mut allowed, values| {
+                allowed.extend(values?);
+                Some(allowed)
+            });
+        if let Some(allowed) = allowed {
+            if !allowed.contains(&value) {
+                let names: Vec<String> = allowed.iter().map(|v| v.to_string()).collect();
+                issues.push(Issue {
+                    key: path.to_string(),
+                    severity: Severity::Error,
+                    message: format!("{value} is not one of {}", names.join(", ")),
+                });
+            }
+            return true;
+        }
+
+        let best = candidates
+            .into_iter()
+            .map(|schema| {
+                let mut found = Vec::new();
+                self.check(schema, value, path, &mut found);
+                found
+            })
+            .min_by_key(|found| {
+                found
+                    .iter()
+                    .filter(|issue| issue.severity == Severity::Error)
+                    .count()
+            })
+            .unwrap_or_default();
+        issues.extend(best);
+        true
+    }
+
+    /// Follow `$ref`s into the schema definitions
+    fn resolve<'s>(&'s self, schema: &'s Value) -> &'s Value {
+        let mut schema = schema;
+        while let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let Some(name) = reference.strip_prefix("#/definitions/") else {
+                break;
+            };
+            match self.root.get("definitions").and_then(|d| d.get(name)) {
+                Some(definition) => schema = definition,
+                None => break,
+            }
+        }
+        schema
+    }
+
+    /// Whether the value has one of the types the schema allows, looking
+    /// through alternatives
+    fn accepts_type(&self, schema: &Value, value: &Value) -> bool {
+        if let Some(alternatives) = schema
+            .get("anyOf")
+            .or_else(|| schema.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            if !alternatives
+                .iter()
+                .any(|alternative| self.accepts_type(self.resolve(alternative), value))
+            {
+                return false;
+            }
+        }
+        declares_type(schema, value)
+    }
+
+    /// Human-readable description of the types a schema allows
+    fn expected(&self, schema: &Value) -> String {
+        if let Some(alternatives) = schema
+            .get("anyOf")
+            .or_else(|| schema.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            let names: Vec<String> = alternatives
+                .iter()
+                .map(|alternative| self.expected(self.resolve(alternative)))
+                .collect();
+            return names.join(" or ");
+        }
+        let names: Vec<&str> = match schema.get("type") {
+            Some(Value::String(name)) => vec![name.as_str()],
+            Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+            _ => vec!["any value"],
+        };
+        names
+            .into_iter()
+            .map(|name| if name == "object" { "table" } else { name })
+            .collect::<Vec<_>>()
+            .join(" or ")
+    }
+}
+
+/// Whether the `type` keyword of the schema, if any, allows the value
+fn declares_type(schema: &Value, value: &Value) -> bool {
+    match schema.get("type") {
+        Some(Value::String(name)) => has_type(value, name),
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| has_type(value, name)),
+        _ => true,
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn allows_type(schema: &Value, name: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(found)) => found == name,
+        Some(Value::Array(found)) => found.iter().any(|found| found == name),
+        _ => false,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "table",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(
+        diagnostics: &[ConfigDiagnostic],
+    ) -> Vec<(Option<usize>, Option<usize>, &str, Severity)> {
+        diagnostics
+            .iter()
+            .map(|d| (d.line, d.column, d.key.as_str(), d.severity))
+            .collect()
+    }
+
+    #[test]
+    fn test_schema_accepts_defaults_and_layer_keys() {
+        let schema = json_schema();
+        for name in ["DriftConfig", "DetectionPattern", "TemplateConfig"] {
+            assert!(schema["definitions"][name].is_object(), "{name}");
+        }
+        assert_eq!(schema["properties"]["root"]["type"], "boolean");
+        assert_eq!(schema["properties"]["exclude_patterns+"]["type"], "array");
+
+        let defaults = serde_yaml::to_string(&Config::default()).unwrap();
+        let path = Path::new(".adrscan.yml");
+        assert!(validate_str(&defaults, path).is_empty());
+        assert!(validate_str("", path).is_empty());
+        assert!(validate_str(
+            "root: true\nexclude_patterns+: [\"**/vendor/**\"]\ndrift:\n  enabled: false\n",
+            path
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_validate_yaml_with_locations() {
+        let content = "\
+adr_dir: docs/adr
+exclud_patterns: [\"**/target/**\"]
+drift:
+  enabled: \"yes\"
+  detection_patterns:
+    - name: Redis
+      file_pattern: \"*.rs\"
+      content_pattern: redis
+lint:
+  rules:
+    title: loud
+";
+        let diagnostics = validate_str(content, Path::new(".adrscan.yml"));
+        assert_eq!(
+            summary(&diagnostics),
+            vec![
+                (Some(2), Some(1), "exclud_patterns", Severity::Warning),
+                (Some(4), Some(3), "drift.enabled", Severity::Error),
+                (
+                    Some(6),
+                    Some(7),
+                    "drift.detection_patterns[0]",
+                    Severity::Error
+                ),
+                (Some(11), Some(5), "lint.rules.title", Severity::Error),
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            ".adrscan.yml:4:3: error: 'drift.enabled': expected boolean, found string"
+        );
+        assert!(diagnostics[2]
+            .message
+            .contains("missing required key 'category'"));
+        assert!(diagnostics[3].message.contains("\"off\", \"info\""));
+
+        let diagnostics = validate_str("adr_dir: [\n", Path::new(".adrscan.yml"));
+        assert_eq!(
+            summary(&diagnostics),
+            vec![(Some(2), Some(1), "", Severity::Error)]
+        );
+    }
+
+    #[test]
+    fn test_validate_toml_with_locations() {
+        let path = Path::new(".adrscan.toml");
+        let content = "adr_dir = 5\n\n[template]\nformat = \"madr\"\ndirectory = \"x\"\n";
+        let diagnostics = validate_str(content, path);
+        assert_eq!(
+            summary(&diagnostics),
+            vec![
+                (Some(1), Some(1), "adr_dir", Severity::Error),
+                (Some(5), Some(1), "template.directory", Severity::Warning),
+            ]
+        );
+        assert!(diagnostics[0]
+            .message
+            .contains("expected string or array, found integer"));
+
+        let diagnostics = validate_str("[drift\n", path);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].line, Some(1));
+    }
+}
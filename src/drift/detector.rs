@@ -6,12 +6,16 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
-use crate::config::DetectionPattern;
+use crate::config::{CacheConfig, DetectionPattern};
+use crate::drift::rules::AdrRules;
+use crate::drift::snapshot::SnapshotEntry;
+use crate::drift::versions::{self, VersionConstraint};
 use crate::drift::{
     DriftCategory, DriftItem, DriftLocation, DriftReport, DriftResult, DriftSeverity,
     ScanStatistics, Snapshot,
 };
-use crate::parser::AdrParser;
+use crate::parser::{AdrCache, SectionKind};
+use crate::status;
 
 /// Core drift detection engine
 pub struct DriftDetector {
@@ -22,6 +26,9 @@ pub struct DriftDetector {
     /// Maximum number of drift items to report per category
     #[allow(dead_code)] // Planned for result limiting
     max_items_per_category: usize,
+
+    /// Where to reuse parsed ADRs from, if at all
+    adr_cache: CacheConfig,
 }
 
 /// Parsed ADR decision information
@@ -45,6 +52,12 @@ pub struct AdrDecision {
 
     /// Decision type (accepts, rejects, supersedes, etc.)
     pub decision_type: DecisionType,
+
+    /// Versions the decision pins technologies to
+    pub version_constraints: Vec<VersionConstraint>,
+
+    /// Technologies the decision allows and forbids, from `enforces`
+    pub rules: Option<AdrRules>,
 }
 
 /// Types of architectural decisions
@@ -62,15 +75,32 @@ pub enum DecisionType {
     Documents,
 }
 
+impl AdrDecision {
+    /// Whether the decision is in effect
+    pub fn is_accepted(&self) -> bool {
+        status::normalize(&self.status) == "accepted"
+    }
+}
+
 impl DriftDetector {
     /// Create a new drift detector with default settings
     pub fn new() -> Self {
         Self {
             significance_threshold: 0.7,
             max_items_per_category: 50,
+            adr_cache: CacheConfig {
+                enabled: false,
+                dir: None,
+            },
         }
     }
 
+    /// Read and update the persistent ADR cache when parsing decisions
+    pub fn with_adr_cache(mut self, cache: &CacheConfig) -> Self {
+        self.adr_cache = cache.clone();
+        self
+    }
+
     /// Configure the drift detector
     #[allow(dead_code)] // Planned for advanced configuration
     pub fn with_config(
@@ -92,6 +122,7 @@ impl DriftDetector {
             return Ok(decisions);
         }
 
+        let mut cache = AdrCache::open(adr_dir, &self.adr_cache);
         for entry in walkdir::WalkDir::new(adr_dir)
             .follow_links(true)
             .into_iter()
@@ -101,7 +132,7 @@ impl DriftDetector {
             if path.is_file() {
                 if let Some(extension) = path.extension() {
                     if extension == "md" || extension == "markdown" {
-                        match self.parse_single_adr(path).await {
+                        match self.parse_single_adr(&mut cache, path).await {
                             Ok(decision) => decisions.push(decision),
                             Err(e) => {
                                 log::warn!("Failed to parse ADR {}: {}", path.display(), e);
@@ -112,13 +143,19 @@ impl DriftDetector {
             }
         }
 
+        cache.save_or_warn();
+
         log::info!("Parsed {} ADR decisions", decisions.len());
         Ok(decisions)
     }
 
     /// Parse a single ADR file
-    async fn parse_single_adr(&self, file_path: &Path) -> DriftResult<AdrDecision> {
-        let adr_doc = AdrParser::parse_file(file_path)?;
+    async fn parse_single_adr(
+        &self,
+        cache: &mut AdrCache,
+        file_path: &Path,
+    ) -> DriftResult<AdrDecision> {
+        let adr_doc = cache.parse_file(file_path)?;
 
         let relative_path = file_path
             .file_name()
@@ -126,12 +163,36 @@ impl DriftDetector {
             .unwrap_or("unknown")
             .to_string();
 
-        // Extract technologies mentioned in the ADR content
-        let mentioned_technologies = self.extract_technologies_from_content(&adr_doc.content);
+        // Extract technologies mentioned in the ADR content; pinning a
+        // version of a technology or declaring rules for it also counts as
+        // mentioning it
+        let version_constraints = versions::constraints(&adr_doc);
+        let rules = AdrRules::from_document(&adr_doc)?;
+        let mut mentioned_technologies = self.extract_technologies_from_content(&adr_doc.content);
+        let declared = version_constraints
+            .iter()
+            .map(|c| &c.technology)
+            .chain(rules.iter().flat_map(|r| r.technologies()));
+        for technology in declared {
+            let technology = technology.to_lowercase();
+            if !mentioned_technologies.contains(&technology) {
+                mentioned_technologies.push(technology);
+            }
+        }
 
-        // Determine decision type based on status and content
-        let decision_type =
-            self.determine_decision_type(&adr_doc.metadata.status, &adr_doc.content);
+        // Determine decision type based on status and content. Declared
+        // rules say what the ADR allows and forbids, so the wording of its
+        // body is not consulted for those. Otherwise the decision section is
+        // read, as the context and considered options also name alternatives.
+        let decision_type = if rules.is_some() {
+            Self::decision_type_from_status(&adr_doc.metadata.status)
+        } else {
+            let decision_text = adr_doc
+                .sections
+                .text(SectionKind::DecisionOutcome)
+                .unwrap_or(&adr_doc.content);
+            self.determine_decision_type(&adr_doc.metadata.status, decision_text)
+        };
 
         // Extract categories from tags or infer from content
         let categories = if !adr_doc.metadata.tags.is_empty() {
@@ -147,6 +208,8 @@ impl DriftDetector {
             mentioned_technologies,
             categories,
             decision_type,
+            version_constraints,
+            rules,
         })
     }
 
@@ -243,6 +306,17 @@ impl DriftDetector {
         }
     }
 
+    /// Determine the type of decision from its status alone
+    fn decision_type_from_status(status: &str) -> DecisionType {
+        match status.to_lowercase().as_str() {
+            "accepted" => DecisionType::Accepts,
+            "rejected" => DecisionType::Rejects,
+            "proposed" => DecisionType::Proposes,
+            "superseded" => DecisionType::Supersedes,
+            _ => DecisionType::Documents,
+        }
+    }
+
     /// Infer categories from ADR content
     fn infer_categories_from_content(&self, content: &str) -> Vec<String> {
         let mut categories = HashSet::new();
@@ -325,11 +399,19 @@ impl DriftDetector {
         self.detect_adr_drift(current_snapshot, adr_decisions, &mut report)
             .await?;
 
-        // 3. Detect new technologies not covered by ADRs
+        // 3. Detect technologies forbidden by declared ADR rules
+        self.detect_rule_drift(current_snapshot, adr_decisions, &mut report)
+            .await?;
+
+        // 4. Detect dependency versions outside ADR constraints
+        self.detect_version_constraint_drift(current_snapshot, adr_decisions, &mut report)
+            .await?;
+
+        // 5. Detect new technologies not covered by ADRs
         self.detect_uncovered_technologies(current_snapshot, adr_decisions, &mut report)
             .await?;
 
-        // 4. Set final statistics
+        // 6. Set final statistics
         report.scan_stats = ScanStatistics {
             files_scanned: current_snapshot.statistics.files_scanned,
             lines_analyzed: current_snapshot.statistics.lines_of_code,
@@ -398,6 +480,238 @@ impl DriftDetector {
             }
         }
 
+        self.detect_version_bumps(current, baseline, report);
+
+        Ok(())
+    }
+
+    /// Detect dependencies that moved to a new major version since the baseline
+    ///
+    /// Entries are matched by file and package rather than by id, since the
+    /// id includes the line number and manifests get reordered.
+    fn detect_version_bumps(
+        &self,
+        current: &Snapshot,
+        baseline: &Snapshot,
+        report: &mut DriftReport,
+    ) {
+        let baseline_versions: HashMap<(&str, &str), &str> = baseline
+            .technology_entries()
+            .into_iter()
+            .filter_map(|entry| {
+                Some((
+                    (entry.file_path.as_str(), package_name(entry)?),
+                    entry.version.as_deref()?,
+                ))
+            })
+            .collect();
+
+        let mut reported = HashSet::new();
+        for entry in current.technology_entries() {
+            let (Some(package), Some(version)) = (package_name(entry), entry.version.as_deref())
+            else {
+                continue;
+            };
+            let Some(previous) = baseline_versions.get(&(entry.file_path.as_str(), package)) else {
+                continue;
+            };
+            let (Some(old), Some(new)) = (
+                versions::parse_version(previous),
+                versions::parse_version(version),
+            ) else {
+                continue;
+            };
+            if !versions::is_major_bump(&old, &new)
+                || !reported.insert((entry.file_path.as_str(), package))
+            {
+                continue;
+            }
+
+            let technology = entry
+                .technology
+                .clone()
+                .unwrap_or_else(|| package.to_string());
+            let drift_item = DriftItem::new(
+                format!("version_bump_{}", entry.id),
+                DriftSeverity::Medium,
+                DriftCategory::VersionDrift,
+                format!("Major version change: {package} {previous} -> {version}"),
+                format!(
+                    "'{}' ({}) moved from {} to {} in {} since the baseline snapshot",
+                    package, technology, previous, version, entry.file_path
+                ),
+                DriftLocation::new(PathBuf::from(&entry.file_path))
+                    .with_line(entry.line_number.unwrap_or(1)),
+            )
+            .with_technology(technology.clone())
+            .with_metadata("version".to_string(), version.to_string())
+            .with_metadata("previous_version".to_string(), previous.to_string())
+            .with_suggested_action(format!(
+                "Check that ADRs covering {technology} still hold for version {version}"
+            ));
+
+            report.add_item(drift_item);
+        }
+    }
+
+    /// Detect technologies forbidden by the `enforces` rules of accepted ADRs
+    ///
+    /// A technology allowed in the same place by any accepted ADR is not
+    /// reported, so a later ADR can make an exception to a broader ban.
+    async fn detect_rule_drift(
+        &self,
+        current_snapshot: &Snapshot,
+        adr_decisions: &[AdrDecision],
+        report: &mut DriftReport,
+    ) -> DriftResult<()> {
+        let enforcing: Vec<(&AdrDecision, &AdrRules)> = adr_decisions
+            .iter()
+            .filter(|d| d.is_accepted())
+            .filter_map(|d| Some((d, d.rules.as_ref()?)))
+            .collect();
+        if enforcing.is_empty() {
+            return Ok(());
+        }
+
+        for tech_entry in current_snapshot.technology_entries() {
+            let Some(technology) = tech_entry.technology.as_deref() else {
+                continue;
+            };
+            let package = package_name(tech_entry);
+            let file_path = tech_entry.file_path.as_str();
+
+            let allowed = enforcing
+                .iter()
+                .any(|(_, rules)| rules.in_scope(file_path) && rules.allows(technology, package));
+            if allowed {
+                continue;
+            }
+
+            for (decision, rules) in &enforcing {
+                if !rules.in_scope(file_path) {
+                    continue;
+                }
+                let Some(forbidden) = rules.forbidding(technology, package) else {
+                    continue;
+                };
+
+                let line = tech_entry.line_number.unwrap_or(1);
+                let scope = if rules.scope.is_empty() {
+                    "anywhere".to_string()
+                } else {
+                    format!("in {}", rules.scope.join(", "))
+                };
+                let mut location = DriftLocation::new(PathBuf::from(file_path)).with_line(line);
+                if let Some(ref matched) = tech_entry.matched_content {
+                    location = location.with_snippet(matched.clone());
+                }
+
+                let drift_item = DriftItem::new(
+                    format!(
+                        "forbidden_tech_{}_{}",
+                        tech_entry.id,
+                        decision.file_path.replace('.', "_")
+                    ),
+                    DriftSeverity::Critical,
+                    DriftCategory::ConflictingTechnology,
+                    format!("Forbidden technology in use: {technology}"),
+                    format!(
+                        "Technology '{}' is used at {}:{}, but ADR '{}' ({}) forbids '{}' {}",
+                        technology,
+                        file_path,
+                        line,
+                        decision.title,
+                        decision.file_path,
+                        forbidden,
+                        scope
+                    ),
+                    location,
+                )
+                .with_technology(technology.to_string())
+                .with_related_adr(decision.title.clone())
+                .with_metadata("adr_file".to_string(), decision.file_path.clone())
+                .with_metadata("rule".to_string(), format!("forbid {forbidden}"))
+                .with_suggested_action(format!(
+                    "Remove {technology} from {file_path} or record an ADR that allows it"
+                ));
+
+                report.add_item(drift_item);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Detect dependency versions that do not satisfy accepted ADR constraints
+    async fn detect_version_constraint_drift(
+        &self,
+        current_snapshot: &Snapshot,
+        adr_decisions: &[AdrDecision],
+        report: &mut DriftReport,
+    ) -> DriftResult<()> {
+        let pinning: Vec<&AdrDecision> = adr_decisions
+            .iter()
+            .filter(|d| !d.version_constraints.is_empty() && d.is_accepted())
+            .collect();
+        if pinning.is_empty() {
+            return Ok(());
+        }
+
+        for tech_entry in current_snapshot.technology_entries() {
+            let (Some(technology), Some(version)) = (
+                tech_entry.technology.as_deref(),
+                tech_entry.version.as_deref(),
+            ) else {
+                continue;
+            };
+            let Some(parsed) = versions::parse_version(version) else {
+                continue;
+            };
+            let Some(package) = package_name(tech_entry) else {
+                continue;
+            };
+
+            for decision in &pinning {
+                for constraint in &decision.version_constraints {
+                    if !constraint.applies_to(package) || constraint.allows(&parsed) {
+                        continue;
+                    }
+
+                    let drift_item = DriftItem::new(
+                        format!(
+                            "version_constraint_{}_{}",
+                            tech_entry.id,
+                            decision.file_path.replace('.', "_")
+                        ),
+                        DriftSeverity::High,
+                        DriftCategory::VersionDrift,
+                        format!("Version constraint violated: {package} {version}"),
+                        format!(
+                            "'{}' {} in {} does not satisfy '{} {}' required by ADR '{}'",
+                            package,
+                            version,
+                            tech_entry.file_path,
+                            constraint.technology,
+                            constraint.requirement,
+                            decision.title
+                        ),
+                        DriftLocation::new(PathBuf::from(&tech_entry.file_path))
+                            .with_line(tech_entry.line_number.unwrap_or(1)),
+                    )
+                    .with_technology(technology.to_string())
+                    .with_related_adr(decision.title.clone())
+                    .with_metadata("version".to_string(), version.to_string())
+                    .with_metadata("constraint".to_string(), constraint.requirement.clone())
+                    .with_suggested_action(format!(
+                        "Use a version of {package} matching '{}' or update the ADR",
+                        constraint.requirement
+                    ));
+
+                    report.add_item(drift_item);
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -550,6 +864,11 @@ impl DriftDetector {
     }
 }
 
+/// Package a technology entry was detected from, if it came from a manifest
+fn package_name(entry: &SnapshotEntry) -> Option<&str> {
+    entry.metadata.get("package").map(String::as_str)
+}
+
 impl Default for DriftDetector {
     fn default() -> Self {
         Self::new()
@@ -752,6 +1071,7 @@ We will not use MongoDB. Use PostgreSQL instead.
             category: "database".to_string(),
             line_number: Some(10),
             matched_content: Some("use mongodb::Client;".to_string()),
+            version: None,
             file_hash: None,
             file_size: None,
             modified_time: None,
@@ -812,6 +1132,7 @@ We will use PostgreSQL as our database.
                 category: "database".to_string(),
                 line_number: Some(i),
                 matched_content: Some("use redis::Client;".to_string()),
+                version: None,
                 file_hash: None,
                 file_size: None,
                 modified_time: None,
@@ -852,6 +1173,7 @@ We will use PostgreSQL as our database.
             category: "framework".to_string(),
             line_number: Some(1),
             matched_content: Some("use old_lib;".to_string()),
+            version: None,
             file_hash: Some("old_hash".to_string()),
             file_size: None,
             modified_time: None,
@@ -869,6 +1191,7 @@ We will use PostgreSQL as our database.
             category: "framework".to_string(),
             line_number: Some(1),
             matched_content: Some("use new_lib;".to_string()),
+            version: None,
             file_hash: Some("new_hash".to_string()),
             file_size: None,
             modified_time: None,
@@ -900,6 +1223,189 @@ We will use PostgreSQL as our database.
             .any(|item| item.title.contains("Old Library")));
     }
 
+    #[tokio::test]
+    async fn test_detect_rule_drift() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().join("adr");
+        fs::create_dir(&adr_dir).unwrap();
+        create_test_adr(
+            &adr_dir,
+            "0002-databases.md",
+            r#"---
+title: "Service Databases"
+status: accepted
+enforces:
+  allow: [postgres]
+  forbid: [mysql, mongodb]
+  scope: "services/**"
+---
+
+# Service Databases
+
+Services use PostgreSQL. We will not use MySQL and avoid MongoDB.
+"#,
+        );
+        create_test_adr(
+            &adr_dir,
+            "0005-analytics-store.md",
+            r#"---
+title: "Analytics Document Store"
+status: Accepted
+enforces:
+  allow: [mongodb]
+  scope: "services/analytics/**"
+---
+
+# Analytics Document Store
+"#,
+        );
+
+        let detector = create_test_detector();
+        let decisions = detector.parse_adr_decisions(&adr_dir).await.unwrap();
+        assert!(decisions
+            .iter()
+            .all(|d| d.decision_type == DecisionType::Accepts));
+
+        let tech_entry = |file_path: &str, technology: &str, package: &str| SnapshotEntry {
+            id: format!("tech_{}_3", file_path.replace('/', "_")),
+            entry_type: SnapshotEntryType::Technology,
+            file_path: file_path.to_string(),
+            technology: Some(technology.to_string()),
+            category: "database".to_string(),
+            line_number: Some(3),
+            matched_content: Some(package.to_string()),
+            version: None,
+            file_hash: None,
+            file_size: None,
+            modified_time: None,
+            metadata: HashMap::from([("package".to_string(), package.to_string())]),
+        };
+
+        let mut current = Snapshot::new(temp_dir.path().to_path_buf());
+        current.entries.extend([
+            tech_entry(
+                "services/api/Cargo.toml",
+                "PostgreSQL Database",
+                "tokio-postgres",
+            ),
+            tech_entry("services/api/package.json", "MySQL Database", "mysql2"),
+            tech_entry("tools/seed/package.json", "MySQL Database", "mysql2"),
+            tech_entry(
+                "services/analytics/package.json",
+                "MongoDB Database",
+                "mongodb",
+            ),
+        ]);
+
+        let report = detector
+            .detect_drift(&current, None, &decisions, &[])
+            .await
+            .unwrap();
+        let conflicts = report.items_by_category(&DriftCategory::ConflictingTechnology);
+        assert_eq!(conflicts.len(), 1);
+
+        let conflict = conflicts[0];
+        assert_eq!(conflict.severity, DriftSeverity::Critical);
+        assert_eq!(
+            conflict.location.file_path,
+            PathBuf::from("services/api/package.json")
+        );
+        assert_eq!(conflict.location.line_number, Some(3));
+        assert_eq!(conflict.related_adr.as_deref(), Some("Service Databases"));
+        assert_eq!(
+            conflict.metadata.get("adr_file").unwrap(),
+            "0002-databases.md"
+        );
+        assert!(conflict
+            .description
+            .contains("forbids 'mysql' in services/**"));
+    }
+
+    #[tokio::test]
+    async fn test_detect_version_drift() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path().join("adr");
+        fs::create_dir(&adr_dir).unwrap();
+        create_test_adr(
+            &adr_dir,
+            "frontend.md",
+            r#"---
+title: "Frontend Framework"
+status: accepted
+versions:
+  React: 18
+  PostgreSQL: 15
+---
+
+# Frontend Framework
+
+Pinned to the release line we reviewed.
+"#,
+        );
+
+        let detector = create_test_detector();
+        let decisions = detector.parse_adr_decisions(&adr_dir).await.unwrap();
+        assert_eq!(decisions[0].version_constraints.len(), 2);
+        assert!(decisions[0]
+            .mentioned_technologies
+            .contains(&"react".to_string()));
+
+        let react_entry = |line: usize, version: &str| SnapshotEntry {
+            id: format!("tech_package.json_{line}"),
+            entry_type: SnapshotEntryType::Technology,
+            file_path: "package.json".to_string(),
+            technology: Some("React".to_string()),
+            category: "frontend".to_string(),
+            line_number: Some(line),
+            matched_content: Some(format!("\"react\": \"{version}\"")),
+            version: Some(version.to_string()),
+            file_hash: None,
+            file_size: None,
+            modified_time: None,
+            metadata: HashMap::from([("package".to_string(), "react".to_string())]),
+        };
+
+        let mut baseline = Snapshot::new(temp_dir.path().to_path_buf());
+        baseline.entries.push(react_entry(4, "^18.2.0"));
+        let mut current = Snapshot::new(temp_dir.path().to_path_buf());
+        current.entries.push(react_entry(5, "^19.0.0"));
+
+        // A pin on the PostgreSQL server does not apply to client libraries
+        current.entries.push(SnapshotEntry {
+            id: "tech_Cargo.toml_8".to_string(),
+            file_path: "Cargo.toml".to_string(),
+            technology: Some("PostgreSQL Database".to_string()),
+            category: "database".to_string(),
+            line_number: Some(8),
+            matched_content: Some("tokio-postgres = \"0.7\"".to_string()),
+            version: Some("0.7".to_string()),
+            metadata: HashMap::from([("package".to_string(), "tokio-postgres".to_string())]),
+            ..react_entry(8, "0.7")
+        });
+
+        let report = detector
+            .detect_drift(&current, Some(&baseline), &decisions, &[])
+            .await
+            .unwrap();
+        let items = report.items_by_category(&DriftCategory::VersionDrift);
+        assert_eq!(items.len(), 2);
+
+        let violation = items
+            .iter()
+            .find(|item| item.severity == DriftSeverity::High)
+            .unwrap();
+        assert_eq!(violation.related_adr.as_deref(), Some("Frontend Framework"));
+        assert_eq!(violation.location.line_number, Some(5));
+        assert_eq!(violation.metadata.get("constraint").unwrap(), "18");
+
+        let bump = items
+            .iter()
+            .find(|item| item.severity == DriftSeverity::Medium)
+            .unwrap();
+        assert!(bump.title.contains("^18.2.0 -> ^19.0.0"));
+        assert_eq!(bump.metadata.get("previous_version").unwrap(), "^18.2.0");
+    }
+
     #[tokio::test]
     async fn test_full_drift_detection() {
         let temp_dir = TempDir::new().unwrap();
@@ -944,6 +1450,7 @@ We will NOT use:
             category: "framework".to_string(),
             line_number: Some(1),
             matched_content: Some("fn main()".to_string()),
+            version: None,
             file_hash: None,
             file_size: None,
             modified_time: None,
@@ -960,6 +1467,7 @@ We will NOT use:
             category: "framework".to_string(),
             line_number: Some(1),
             matched_content: Some("#!/usr/bin/env python".to_string()),
+            version: None,
             file_hash: None,
             file_size: None,
             modified_time: None,
@@ -977,6 +1485,7 @@ We will NOT use:
                 category: "database".to_string(),
                 line_number: Some(i),
                 matched_content: Some("use redis::Client;".to_string()),
+                version: None,
                 file_hash: None,
                 file_size: None,
                 modified_time: None,
@@ -0,0 +1,569 @@
+//! Import Extraction
+//!
+//! This module finds the imports of Rust, TypeScript/JavaScript, Python and
+//! Java source files, so that layering rules can check which parts of the
+//! codebase depend on which.
+
+use regex::Regex;
+use std::path::Path;
+
+/// Languages whose imports are extracted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// `.rs`
+    Rust,
+    /// TypeScript and JavaScript modules
+    TypeScript,
+    /// `.py`, `.pyi`
+    Python,
+    /// `.java`
+    Java,
+}
+
+impl Language {
+    /// Language of a source file, by extension
+    pub fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Language::Rust),
+            "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs" => {
+                Some(Language::TypeScript)
+            }
+            "py" | "pyi" => Some(Language::Python),
+            "java" => Some(Language::Java),
+            _ => None,
+        }
+    }
+}
+
+/// One imported module
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    /// Module path as written, e.g. `crate::domain::Order`, `../api/client`,
+    /// `.models` or `com.acme.domain.Order`
+    pub module: String,
+
+    /// Language of the importing file
+    pub language: Language,
+
+    /// 1-based line of the import statement
+    pub line: usize,
+
+    /// 1-based column of the import statement
+    pub column: usize,
+
+    /// The import statement, on one line
+    pub statement: String,
+}
+
+impl Import {
+    /// Path segments of the module
+    pub fn segments(&self) -> Vec<&str> {
+        segments(&self.module)
+    }
+
+    /// Names the import binds in the importing file, each with the path it
+    /// stands for: `Client` for `use reqwest::Client`, `get` for
+    /// `from requests import get` (`requests.get`) or `axios` for
+    /// `import axios from 'axios'`. Glob imports, re-exports and `require()`
+    /// bind no name.
+    #[cfg(feature = "ast")]
+    pub fn bindings(&self) -> Vec<(String, String)> {
+        let statement = self.statement.as_str();
+        let alias = |name: &str| {
+            Regex::new(&format!(r"\b{}\s+as\s+(\w+)", regex::escape(name)))
+                .unwrap()
+                .captures(statement)
+                .map(|captures| captures[1].to_string())
+        };
+
+        match self.language {
+            Language::Rust | Language::Java => {
+                if self.language == Language::Java && statement.contains(".*") {
+                    return Vec::new();
+                }
+                let Some(last) = self.segments().last().map(|s| s.to_string()) else {
+                    return Vec::new();
+                };
+                let name = alias(&last).unwrap_or(last);
+                vec![(name, self.module.clone())]
+            }
+            Language::Python => {
+                let Some((from, names)) = statement
+                    .strip_prefix("from")
+                    .and_then(|rest| rest.split_once(" import "))
+                else {
+                    // `import a.b` binds `a`, `import a.b as c` binds `c`
+                    return match alias(&self.module) {
+                        Some(name) => vec![(name, self.module.clone())],
+                        None => {
+                            let first = self.segments().first().map(|s| s.to_string());
+                            first.map(|s| (s.clone(), s)).into_iter().collect()
+                        }
+                    };
+                };
+                // `from .. import models` is extracted as one import per
+                // name, e.g. the module `..models`
+                let siblings = from.trim().trim_start_matches('.').is_empty();
+
+                let names = names.trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace());
+                let mut bindings = Vec::new();
+                for item in names.split(',') {
+                    let mut words = item.split_whitespace();
+                    let Some(name) = words.next().filter(|name| *name != "*") else {
+                        continue;
+                    };
+                    let bound = match (words.next(), words.next()) {
+                        (Some("as"), Some(alias)) => alias,
+                        _ => name,
+                    };
+                    if !siblings {
+                        bindings.push((bound.to_string(), format!("{}.{name}", self.module)));
+                    } else if self.segments().last() == Some(&name) {
+                        bindings.push((bound.to_string(), self.module.clone()));
+                    }
+                }
+                bindings
+            }
+            Language::TypeScript => {
+                let Some(clause) = statement
+                    .strip_prefix("import")
+                    .and_then(|rest| rest.rsplit_once("from"))
+                    .map(|(clause, _)| clause.trim())
+                else {
+                    return Vec::new();
+                };
+                let clause = clause.strip_prefix("type ").unwrap_or(clause);
+                let (default, named) = match clause.split_once('{') {
+                    Some((default, named)) => (default, named.split('}').next().unwrap_or("")),
+                    None => (clause, ""),
+                };
+
+                let mut bindings = Vec::new();
+                for name in default.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                    let name = name.strip_prefix("* as ").unwrap_or(name).trim();
+                    bindings.push((name.to_string(), self.module.clone()));
+                }
+                for item in named.split(',') {
+                    let mut words = item.split_whitespace().filter(|w| *w != "type");
+                    let Some(name) = words.next() else {
+                        continue;
+                    };
+                    let bound = match (words.next(), words.next()) {
+                        (Some("as"), Some(alias)) => alias,
+                        _ => name,
+                    };
+                    bindings.push((bound.to_string(), format!("{}.{name}", self.module)));
+                }
+                bindings
+            }
+        }
+    }
+
+    /// Path of a relative import, relative to the scanned directory like
+    /// `file_path`, the importing file
+    ///
+    /// Relative imports are `./` and `../` specifiers, Python imports with
+    /// leading dots and Rust paths starting with `crate`, `self` or
+    /// `super`. Rust `crate` paths start at the nearest `src` directory.
+    pub fn resolve(&self, file_path: &str) -> Option<String> {
+        let file = Path::new(file_path);
+        let dir: Vec<&str> = file_path
+            .rsplit_once('/')
+            .map(|(dir, _)| dir.split('/').filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        let (mut base, rest): (Vec<&str>, Vec<&str>) = match self.language {
+            Language::TypeScript => {
+                if !(self.module.starts_with("./") || self.module.starts_with("../")) {
+                    return None;
+                }
+                (dir, self.module.split('/').collect())
+            }
+            Language::Python => {
+                let dots = self.module.len() - self.module.trim_start_matches('.').len();
+                if dots == 0 {
+                    return None;
+                }
+                let mut base = dir;
+                base.truncate(base.len().saturating_sub(dots - 1));
+                (base, segments(&self.module[dots..]))
+            }
+            Language::Rust => {
+                let parts = segments(&self.module);
+                match parts.first().copied() {
+                    Some("crate") => {
+                        let root = dir.iter().rposition(|s| *s == "src").map_or(0, |i| i + 1);
+                        (dir[..root].to_vec(), parts[1..].to_vec())
+                    }
+                    Some("self") | Some("super") => {
+                        let mut module = dir;
+                        let stem = file.file_stem().and_then(|s| s.to_str());
+                        if let Some(stem) = stem.filter(|s| !["mod", "lib", "main"].contains(s)) {
+                            module.push(stem);
+                        }
+                        let mut parts = parts.as_slice();
+                        if parts[0] == "self" {
+                            parts = &parts[1..];
+                        }
+                        while parts.first() == Some(&"super") {
+                            module.pop();
+                            parts = &parts[1..];
+                        }
+                        (module, parts.to_vec())
+                    }
+                    _ => return None,
+                }
+            }
+            Language::Java => return None,
+        };
+
+        for part in rest {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    base.pop();
+                }
+                part => base.push(part),
+            }
+        }
+        Some(base.join("/"))
+    }
+}
+
+/// Imports of a source file, or `None` for languages that are not supported
+pub fn extract(path: &Path, content: &str) -> Option<Vec<Import>> {
+    let language = Language::of(path)?;
+    let mut imports = match language {
+        Language::Rust => rust_imports(content),
+        Language::TypeScript => typescript_imports(content),
+        Language::Python => python_imports(content),
+        Language::Java => java_imports(content),
+    };
+    imports.sort_by_key(|(offset, _, _)| *offset);
+
+    Some(
+        imports
+            .into_iter()
+            .map(|(offset, module, statement)| {
+                let before = &content[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                Import {
+                    module,
+                    language,
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                    statement: statement.split_whitespace().collect::<Vec<_>>().join(" "),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Byte offset, module and statement text of each import
+type RawImport = (usize, String, String);
+
+/// `use` declarations, with groups expanded
+fn rust_imports(content: &str) -> Vec<RawImport> {
+    let pattern = Regex::new(r"(?m)^[ \t]*((?:pub(?:\([^)]*\))?\s+)?use\s+([^;]+);)").unwrap();
+    let mut imports = Vec::new();
+    for captures in pattern.captures_iter(content) {
+        let statement = captures.get(1).unwrap();
+        let tree = captures[2].split_whitespace().collect::<Vec<_>>().join(" ");
+        for module in expand_use_tree(&tree) {
+            imports.push((statement.start(), module, statement.as_str().to_string()));
+        }
+    }
+    imports
+}
+
+/// Expand `a::{b, c::{d, e}}` into `a::b`, `a::c::d` and `a::c::e`
+fn expand_use_tree(tree: &str) -> Vec<String> {
+    let tree = tree.trim();
+    let Some(open) = tree.find('{') else {
+        let path = tree.split(" as ").next().unwrap_or(tree);
+        let path = path.trim_end_matches("::*").trim_end_matches("::self");
+        return vec![path.to_string()];
+    };
+    let prefix = tree[..open].trim_end_matches("::");
+    let inner = &tree[open + 1..];
+    let inner = inner.strip_suffix('}').unwrap_or(inner);
+
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (index, c) in inner.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&inner[start..]);
+
+    items
+        .into_iter()
+        .filter(|item| !item.trim().is_empty())
+        .flat_map(expand_use_tree)
+        .map(|item| match (prefix.is_empty(), item.as_str()) {
+            (true, _) => item,
+            (false, "self" | "") => prefix.to_string(),
+            (false, _) => format!("{prefix}::{item}"),
+        })
+        .collect()
+}
+
+/// `import`/`export ... from`, side-effect imports, `require()` and `import()`
+fn typescript_imports(content: &str) -> Vec<RawImport> {
+    let patterns = [
+        r#"(?m)^[ \t]*((?:import|export)\b[^;'"]*?\bfrom\s*['"]([^'"]+)['"])"#,
+        r#"(?m)^[ \t]*(import\s*['"]([^'"]+)['"])"#,
+        r#"\b((?:require|import)\s*\(\s*['"]([^'"]+)['"]\s*\))"#,
+    ];
+    let mut imports: Vec<RawImport> = Vec::new();
+    for pattern in patterns {
+        for captures in Regex::new(pattern).unwrap().captures_iter(content) {
+            let statement = captures.get(1).unwrap();
+            if imports
+                .iter()
+                .any(|(offset, _, _)| *offset == statement.start())
+            {
+                continue;
+            }
+            imports.push((
+                statement.start(),
+                captures[2].to_string(),
+                statement.as_str().to_string(),
+            ));
+        }
+    }
+    imports
+}
+
+/// `import a.b, c` and `from .x import y`
+fn python_imports(content: &str) -> Vec<RawImport> {
+    let mut imports = Vec::new();
+
+    let import = Regex::new(r"(?m)^[ \t]*(import[ \t]+([\w.]+(?:[ \t]+as[ \t]+\w+)?(?:[ \t]*,[ \t]*[\w.]+(?:[ \t]+as[ \t]+\w+)?)*))").unwrap();
+    for captures in import.captures_iter(content) {
+        let statement = captures.get(1).unwrap();
+        for module in captures[2].split(',') {
+            let module = module.split_whitespace().next().unwrap_or_default();
+            imports.push((
+                statement.start(),
+                module.to_string(),
+                statement.as_str().to_string(),
+            ));
+        }
+    }
+
+    let from =
+        Regex::new(r"(?m)^[ \t]*(from[ \t]+(\.*[\w.]*)[ \t]+import[ \t]+(\([^)]*\)|[^\n#]+))")
+            .unwrap();
+    for captures in from.captures_iter(content) {
+        let statement = captures.get(1).unwrap();
+        let module = &captures[2];
+        if module.trim_start_matches('.').is_empty() {
+            // `from .. import models` imports sibling modules
+            let names =
+                captures[3].trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace());
+            for name in names.split(',') {
+                if let Some(name) = name.split_whitespace().next() {
+                    imports.push((
+                        statement.start(),
+                        format!("{module}{name}"),
+                        statement.as_str().to_string(),
+                    ));
+                }
+            }
+        } else {
+            imports.push((
+                statement.start(),
+                module.to_string(),
+                statement.as_str().to_string(),
+            ));
+        }
+    }
+
+    imports
+}
+
+/// `import a.b.C;` and `import static a.b.C.d;`
+fn java_imports(content: &str) -> Vec<RawImport> {
+    Regex::new(r"(?m)^[ \t]*(import\s+(?:static\s+)?([\w.]+?)(?:\.\*)?\s*;)")
+        .unwrap()
+        .captures_iter(content)
+        .map(|captures| {
+            let statement = captures.get(1).unwrap();
+            (
+                statement.start(),
+                captures[2].to_string(),
+                statement.as_str().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Segments of a module path written with `::`, `.` or `/` separators
+fn segments(module: &str) -> Vec<&str> {
+    module
+        .split(['/', '.', ':'])
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modules(path: &str, content: &str) -> Vec<(String, usize)> {
+        extract(Path::new(path), content)
+            .unwrap()
+            .into_iter()
+            .map(|i| (i.module, i.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_imports() {
+        let rust = "use std::fmt;\npub(crate) use crate::{\n    domain::Order,\n    infra::{db, cache::Redis},\n};\n// use crate::ui;\n";
+        assert_eq!(
+            modules("src/app.rs", rust),
+            vec![
+                ("std::fmt".to_string(), 1),
+                ("crate::domain::Order".to_string(), 2),
+                ("crate::infra::db".to_string(), 2),
+                ("crate::infra::cache::Redis".to_string(), 2),
+            ]
+        );
+
+        let typescript = "import React from 'react';\nimport {\n  get,\n} from \"../api/client\";\nimport './styles.css';\nconst db = require('../infra/db');\nexport * from './types';\n";
+        assert_eq!(
+            modules("web/ui/App.tsx", typescript),
+            vec![
+                ("react".to_string(), 1),
+                ("../api/client".to_string(), 2),
+                ("./styles.css".to_string(), 5),
+                ("../infra/db".to_string(), 6),
+                ("./types".to_string(), 7),
+            ]
+        );
+
+        let python = "import os, app.infra.db as db\nfrom ..domain import (\n    models,\n    events,\n)\nfrom .. import infra, views as v\n";
+        assert_eq!(
+            modules("app/api/routes.py", python),
+            vec![
+                ("os".to_string(), 1),
+                ("app.infra.db".to_string(), 1),
+                ("..domain".to_string(), 2),
+                ("..infra".to_string(), 6),
+                ("..views".to_string(), 6),
+            ]
+        );
+
+        let java = "package com.acme.domain;\n\nimport java.util.List;\nimport static com.acme.infra.Db.connect;\nimport com.acme.infra.*;\n";
+        assert_eq!(
+            modules("src/main/java/com/acme/domain/Order.java", java),
+            vec![
+                ("java.util.List".to_string(), 3),
+                ("com.acme.infra.Db.connect".to_string(), 4),
+                ("com.acme.infra".to_string(), 5),
+            ]
+        );
+
+        assert!(extract(Path::new("README.md"), "import x").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "ast")]
+    fn test_import_bindings() {
+        let bindings = |path: &str, content: &str| {
+            extract(Path::new(path), content)
+                .unwrap()
+                .iter()
+                .flat_map(Import::bindings)
+                .collect::<Vec<_>>()
+        };
+        let pair = |name: &str, path: &str| (name.to_string(), path.to_string());
+
+        assert_eq!(
+            bindings(
+                "src/http.rs",
+                "use reqwest::Client as Http;\nuse std::{fmt, io::Write};\n"
+            ),
+            vec![
+                pair("Http", "reqwest::Client"),
+                pair("fmt", "std::fmt"),
+                pair("Write", "std::io::Write"),
+            ]
+        );
+        assert_eq!(
+            bindings(
+                "app/api/routes.py",
+                "import os.path, app.infra.db as db\nfrom requests import get, post as send\nfrom .. import infra\n"
+            ),
+            vec![
+                pair("os", "os"),
+                pair("db", "app.infra.db"),
+                pair("get", "requests.get"),
+                pair("send", "requests.post"),
+                pair("infra", "..infra"),
+            ]
+        );
+        assert_eq!(
+            bindings(
+                "web/App.tsx",
+                "import React, { useState as state, type FC } from 'react';\nimport * as api from './api';\nexport * from './types';\n"
+            ),
+            vec![
+                pair("React", "react"),
+                pair("state", "react.useState"),
+                pair("FC", "react.FC"),
+                pair("api", "./api"),
+            ]
+        );
+        assert!(bindings("A.java", "import com.acme.infra.*;\n").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_relative_imports() {
+        let import = |language, module: &str| Import {
+            module: module.to_string(),
+            language,
+            line: 1,
+            column: 1,
+            statement: String::new(),
+        };
+
+        assert_eq!(
+            import(Language::TypeScript, "../api/client").resolve("web/ui/App.tsx"),
+            Some("web/api/client".to_string())
+        );
+        assert_eq!(
+            import(Language::TypeScript, "react").resolve("web/ui/App.tsx"),
+            None
+        );
+        assert_eq!(
+            import(Language::Python, "..domain.models").resolve("app/api/routes.py"),
+            Some("app/domain/models".to_string())
+        );
+        assert_eq!(
+            import(Language::Rust, "crate::infra::db").resolve("core/src/domain/order.rs"),
+            Some("core/src/infra/db".to_string())
+        );
+        assert_eq!(
+            import(Language::Rust, "super::events").resolve("src/domain/order.rs"),
+            Some("src/domain/events".to_string())
+        );
+        assert_eq!(
+            import(Language::Rust, "self::lines").resolve("src/domain/mod.rs"),
+            Some("src/domain/lines".to_string())
+        );
+        assert_eq!(
+            import(Language::Java, "com.acme.infra").resolve("A.java"),
+            None
+        );
+    }
+}
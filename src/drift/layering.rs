@@ -0,0 +1,363 @@
+//! Layering Rules
+//!
+//! This module checks the imports of source files against the layers
+//! declared in the configuration (`drift.layers`) and in the `enforces`
+//! entries of accepted ADRs, such as "domain must not import infrastructure"
+//! or "ui may only import api".
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::config::LayerRule;
+use crate::drift::imports::{self, Import, Language};
+use crate::drift::{
+    DriftCategory, DriftItem, DriftLocation, DriftReport, DriftResult, DriftSeverity,
+};
+use crate::error::AdrscanError;
+
+/// Checks imports against layering rules
+#[derive(Debug, Default)]
+pub struct LayerAnalyzer {
+    layers: Vec<Layer>,
+}
+
+/// A layer rule with its compiled patterns
+#[derive(Debug)]
+struct Layer {
+    rule: LayerRule,
+
+    /// Title of the ADR declaring the rule, `None` for the configuration
+    adr: Option<String>,
+
+    /// Compiled `paths` globs
+    paths: Vec<glob::Pattern>,
+
+    /// `modules` prefixes split into segments
+    modules: Vec<Vec<String>>,
+}
+
+impl LayerAnalyzer {
+    /// Create an analyzer without layers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add layers declared in the configuration, or in the ADR with the given title
+    pub fn with_layers(mut self, rules: &[LayerRule], adr: Option<&str>) -> DriftResult<Self> {
+        for rule in rules {
+            let paths = rule
+                .paths
+                .iter()
+                .map(|glob| {
+                    glob::Pattern::new(glob).map_err(|e| {
+                        AdrscanError::ConfigError(format!(
+                            "Invalid path '{glob}' for layer '{}': {e}",
+                            rule.name
+                        ))
+                    })
+                })
+                .collect::<DriftResult<Vec<_>>>()?;
+            let modules = rule
+                .modules
+                .iter()
+                .map(|module| {
+                    module
+                        .split(['/', '.', ':'])
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .collect();
+
+            self.layers.push(Layer {
+                rule: rule.clone(),
+                adr: adr.map(str::to_string),
+                paths,
+                modules,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Whether any layer restricts its imports
+    pub fn has_rules(&self) -> bool {
+        self.layers.iter().any(Layer::has_rules)
+    }
+
+    /// Check the imports of `files` below `root`, adding violations to the report
+    pub fn analyze(&self, root: &Path, files: &[PathBuf], report: &mut DriftReport) {
+        for file in files {
+            let Ok(relative_path) = file.strip_prefix(root) else {
+                continue;
+            };
+            let relative_path = relative_path.to_string_lossy().replace('\\', "/");
+            if Language::of(file).is_none() || self.layer_of_file(&relative_path).is_none() {
+                continue;
+            }
+
+            match std::fs::read_to_string(file) {
+                Ok(content) => {
+                    for item in self.check_file(&relative_path, &content) {
+                        report.add_item(item);
+                    }
+                }
+                Err(e) => log::warn!("Could not read file {}: {}", file.display(), e),
+            }
+        }
+    }
+
+    /// Violations of the layering rules by one file
+    fn check_file(&self, file_path: &str, content: &str) -> Vec<DriftItem> {
+        let Some(layer) = self.layer_of_file(file_path) else {
+            return Vec::new();
+        };
+        let Some(imports) = imports::extract(Path::new(file_path), content) else {
+            return Vec::new();
+        };
+
+        let mut reported = HashSet::new();
+        let mut items = Vec::new();
+        for import in &imports {
+            let Some(target) = self.layer_of_import(import, file_path) else {
+                continue;
+            };
+            if target == layer {
+                continue;
+            }
+
+            for (index, rule) in self.layers.iter().enumerate() {
+                if rule.rule.name != layer {
+                    continue;
+                }
+                let Some(broken) = rule.broken_rule(target) else {
+                    continue;
+                };
+                if reported.insert((import.line, target, index)) {
+                    items.push(violation(
+                        file_path, layer, target, import, rule, &broken, index,
+                    ));
+                }
+            }
+        }
+        items
+    }
+
+    /// Layer of a file, the first whose paths match it
+    fn layer_of_file(&self, file_path: &str) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|layer| layer.contains_path(file_path))
+            .map(|layer| layer.rule.name.as_str())
+    }
+
+    /// Layer an import refers to, if any
+    fn layer_of_import(&self, import: &Import, file_path: &str) -> Option<&str> {
+        let resolved = import.resolve(file_path);
+        let extension = Path::new(file_path).extension().and_then(|e| e.to_str());
+        let segments = import.segments();
+
+        self.layers
+            .iter()
+            .filter(|layer| !layer.paths.is_empty())
+            .find(|layer| layer.contains_import(&segments, resolved.as_deref(), extension))
+            .map(|layer| layer.rule.name.as_str())
+    }
+}
+
+impl Layer {
+    /// Whether the layer restricts its imports
+    fn has_rules(&self) -> bool {
+        !self.rule.must_not_import.is_empty() || self.rule.may_only_import.is_some()
+    }
+
+    /// Whether a file belongs to the layer
+    fn contains_path(&self, file_path: &str) -> bool {
+        self.paths.iter().any(|p| p.matches(file_path))
+    }
+
+    /// Whether an import refers to the layer
+    ///
+    /// Module prefixes are checked first. A relative import refers to the
+    /// layer when the path it resolves to, or a module containing it, is in
+    /// the layer. Other imports refer to a layer without module prefixes
+    /// when one of their segments is the layer name.
+    fn contains_import(
+        &self,
+        segments: &[&str],
+        resolved: Option<&str>,
+        extension: Option<&str>,
+    ) -> bool {
+        let prefixed = self.modules.iter().any(|module| {
+            module.len() <= segments.len()
+                && module
+                    .iter()
+                    .zip(segments)
+                    .all(|(expected, segment)| expected == "*" || expected == segment)
+        });
+        if prefixed {
+            return true;
+        }
+
+        match resolved {
+            Some(path) => {
+                let parts: Vec<&str> = path.split('/').collect();
+                (1..=parts.len()).rev().any(|len| {
+                    let prefix = parts[..len].join("/");
+                    self.contains_path(&prefix)
+                        || extension
+                            .is_some_and(|ext| self.contains_path(&format!("{prefix}.{ext}")))
+                })
+            }
+            None => {
+                self.modules.is_empty()
+                    && segments
+                        .iter()
+                        .any(|segment| segment.eq_ignore_ascii_case(&self.rule.name))
+            }
+        }
+    }
+
+    /// The rule importing `target` breaks, if any
+    fn broken_rule(&self, target: &str) -> Option<String> {
+        let name = &self.rule.name;
+        if self.rule.must_not_import.iter().any(|l| l == target) {
+            return Some(format!("'{name}' must not import '{target}'"));
+        }
+        match self.rule.may_only_import {
+            Some(ref allowed) if !allowed.iter().any(|l| l == target) => {
+                if allowed.is_empty() {
+                    Some(format!("'{name}' must not import other layers"))
+                } else {
+                    let allowed: Vec<String> = allowed.iter().map(|l| format!("'{l}'")).collect();
+                    Some(format!("'{name}' may only import {}", allowed.join(", ")))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Drift item for an import that breaks a layering rule
+fn violation(
+    file_path: &str,
+    layer: &str,
+    target: &str,
+    import: &Import,
+    rule: &Layer,
+    broken: &str,
+    index: usize,
+) -> DriftItem {
+    let source = match rule.adr {
+        Some(ref title) => format!("ADR '{title}'"),
+        None => "the configuration".to_string(),
+    };
+
+    let mut item = DriftItem::new(
+        format!(
+            "layer_violation_{}_{}_{}_{}",
+            file_path.replace('/', "_"),
+            import.line,
+            target,
+            index
+        ),
+        DriftSeverity::High,
+        DriftCategory::PatternViolation,
+        format!("Layering violation: {layer} imports {target}"),
+        format!(
+            "{}:{} in layer '{}' imports '{}' from layer '{}', but {} according to {}",
+            file_path, import.line, layer, import.module, target, broken, source
+        ),
+        DriftLocation::new(PathBuf::from(file_path))
+            .with_line(import.line)
+            .with_column(import.column)
+            .with_snippet(import.statement.clone()),
+    )
+    .with_metadata("layer".to_string(), layer.to_string())
+    .with_metadata("imported_layer".to_string(), target.to_string())
+    .with_metadata("import".to_string(), import.module.clone())
+    .with_suggested_action(format!(
+        "Remove the dependency of {layer} on {target}, or change the layering rule in {source}"
+    ));
+    if let Some(ref title) = rule.adr {
+        item = item.with_related_adr(title.clone());
+    }
+    item
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(name: &str, paths: &[&str]) -> LayerRule {
+        LayerRule {
+            name: name.to_string(),
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            modules: Vec::new(),
+            must_not_import: Vec::new(),
+            may_only_import: None,
+        }
+    }
+
+    #[test]
+    fn test_layering_violations() {
+        let config = vec![
+            LayerRule {
+                must_not_import: vec!["infrastructure".to_string()],
+                ..layer(
+                    "domain",
+                    &["src/domain/**", "src/main/java/com/acme/domain/**"],
+                )
+            },
+            layer("infrastructure", &["src/infrastructure/**"]),
+            layer("ui", &["web/ui/**"]),
+            layer("api", &["web/api/**"]),
+            layer("store", &["web/store/**"]),
+        ];
+        let adr = vec![LayerRule {
+            may_only_import: Some(vec!["api".to_string()]),
+            ..layer("ui", &[])
+        }];
+        let analyzer = LayerAnalyzer::new()
+            .with_layers(&config, None)
+            .unwrap()
+            .with_layers(&adr, Some("Web Client Layers"))
+            .unwrap();
+        assert!(analyzer.has_rules());
+
+        let rust = "use std::sync::Arc;\nuse crate::domain::events;\nuse crate::infrastructure::{db::Pool, cache};\n";
+        let items = analyzer.check_file("src/domain/order.rs", rust);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].category, DriftCategory::PatternViolation);
+        assert_eq!(items[0].location.line_number, Some(3));
+        assert_eq!(items[0].related_adr, None);
+        assert!(items[0]
+            .description
+            .contains("'domain' must not import 'infrastructure' according to the configuration"));
+
+        let java = "package com.acme.domain;\n\nimport com.acme.infrastructure.JdbcOrders;\n";
+        let items = analyzer.check_file("src/main/java/com/acme/domain/Order.java", java);
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            items[0].metadata.get("import").unwrap(),
+            "com.acme.infrastructure.JdbcOrders"
+        );
+
+        let typescript = "import React from 'react';\nimport { get } from '../api/client';\nimport { cart } from '../store/cart';\n";
+        let items = analyzer.check_file("web/ui/Cart.tsx", typescript);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].location.line_number, Some(3));
+        assert_eq!(items[0].related_adr.as_deref(), Some("Web Client Layers"));
+        assert!(items[0].title.contains("ui imports store"));
+
+        // Files outside every layer are not checked
+        assert!(analyzer
+            .check_file("scripts/seed.py", "import infrastructure\n")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_layer_path() {
+        let result = LayerAnalyzer::new().with_layers(&[layer("domain", &["["])], None);
+        assert!(matches!(result, Err(AdrscanError::ConfigError(_))));
+    }
+}
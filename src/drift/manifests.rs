@@ -0,0 +1,1044 @@
+//! Dependency Manifest Parsing
+//!
+//! This module reads the dependencies declared in package manifests and
+//! lockfiles, so that technologies are detected from what a project
+//! actually depends on instead of from any line mentioning a name.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+
+use crate::drift::DriftResult;
+use crate::error::AdrscanError;
+
+/// How a project uses a dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    /// Needed when the project runs
+    Runtime,
+    /// Only needed by tests and development tooling
+    Dev,
+    /// Only needed to build the project
+    Build,
+    /// Listed in a lockfile or marked as indirect, so possibly only a
+    /// dependency of another dependency
+    Locked,
+}
+
+/// Where a declaration is in its manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// Byte offset of the start of the declaration
+    pub start: usize,
+    /// Byte offset just past the end of the declaration
+    pub end: usize,
+    /// 1-based line of the start
+    pub line: usize,
+    /// 1-based column of the start, in characters
+    pub column: usize,
+}
+
+/// A dependency declared in a manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// Package name; `group:artifact` for Maven and Gradle
+    pub name: String,
+    /// Version or version requirement as written, if there is one
+    pub version: Option<String>,
+    pub kind: DependencyKind,
+    /// The whole declaration, e.g. `axum = "0.7"`
+    pub span: SourceSpan,
+}
+
+/// Manifest formats, recognized by their file names
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Manifest {
+    CargoToml,
+    CargoLock,
+    PackageJson,
+    PackageLock,
+    YarnLock,
+    Requirements,
+    Pyproject,
+    GoMod,
+    Pom,
+    Gradle,
+}
+
+impl Manifest {
+    fn detect(file_path: &Path) -> Option<Self> {
+        let name = file_path.file_name()?.to_str()?;
+        Some(match name {
+            "Cargo.toml" => Self::CargoToml,
+            "Cargo.lock" => Self::CargoLock,
+            "package.json" => Self::PackageJson,
+            "package-lock.json" | "npm-shrinkwrap.json" => Self::PackageLock,
+            "yarn.lock" => Self::YarnLock,
+            "pyproject.toml" => Self::Pyproject,
+            "go.mod" => Self::GoMod,
+            "pom.xml" => Self::Pom,
+            "build.gradle" | "build.gradle.kts" => Self::Gradle,
+            name if name.starts_with("requirements") && name.ends_with(".txt") => {
+                Self::Requirements
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// Whether `file_path` is a manifest that [`parse`] understands
+pub fn is_manifest(file_path: &Path) -> bool {
+    Manifest::detect(file_path).is_some()
+}
+
+/// The dependencies declared in `content`, or `None` if `file_path` is not
+/// a manifest this module understands
+pub fn parse(file_path: &Path, content: &str) -> Option<DriftResult<Vec<Dependency>>> {
+    let manifest = Manifest::detect(file_path)?;
+    let dependencies = match manifest {
+        Manifest::CargoToml => cargo_toml(content),
+        Manifest::CargoLock => cargo_lock(content),
+        Manifest::PackageJson => package_json(content),
+        Manifest::PackageLock => package_lock(content),
+        Manifest::YarnLock => Ok(yarn_lock(content)),
+        Manifest::Requirements => {
+            let name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let kind = if name.contains("dev") || name.contains("test") {
+                DependencyKind::Dev
+            } else {
+                DependencyKind::Runtime
+            };
+            Ok(requirements(content, kind))
+        }
+        Manifest::Pyproject => pyproject(content),
+        Manifest::GoMod => Ok(go_mod(content)),
+        Manifest::Pom => Ok(pom(content)),
+        Manifest::Gradle => Ok(gradle(content)),
+    };
+    Some(dependencies.map_err(|message| {
+        AdrscanError::DriftError(format!(
+            "Cannot parse manifest {}: {message}",
+            file_path.display()
+        ))
+    }))
+}
+
+fn span(content: &str, start: usize, end: usize) -> SourceSpan {
+    let before = &content[..start];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    SourceSpan {
+        start,
+        end,
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+// Cargo
+
+/// Dependency tables of a Cargo manifest, in the root and in
+/// `[target.'cfg(...)']` tables
+const CARGO_SECTIONS: &[(&str, DependencyKind)] = &[
+    ("dependencies", DependencyKind::Runtime),
+    ("dev-dependencies", DependencyKind::Dev),
+    ("dev_dependencies", DependencyKind::Dev),
+    ("build-dependencies", DependencyKind::Build),
+    ("build_dependencies", DependencyKind::Build),
+];
+
+fn cargo_toml(content: &str) -> Result<Vec<Dependency>, String> {
+    let root = parse_toml(content)?;
+    let root = root.get_ref();
+    let mut dependencies = Vec::new();
+
+    let mut tables = vec![root];
+    if let Some(targets) = toml_get(root, "target").and_then(|t| t.get_ref().as_table()) {
+        tables.extend(targets.values().filter_map(|t| t.get_ref().as_table()));
+    }
+    for table in tables {
+        for (section, kind) in CARGO_SECTIONS {
+            if let Some(section) = toml_get(table, section).and_then(|s| s.get_ref().as_table()) {
+                cargo_dependencies(content, section, *kind, &mut dependencies);
+            }
+        }
+    }
+    if let Some(section) = toml_get(root, "workspace")
+        .and_then(|w| w.get_ref().as_table())
+        .and_then(|w| toml_get(w, "dependencies"))
+        .and_then(|s| s.get_ref().as_table())
+    {
+        cargo_dependencies(content, section, DependencyKind::Runtime, &mut dependencies);
+    }
+    // Tables are sorted by key
+    dependencies.sort_by_key(|dependency| dependency.span.start);
+    Ok(dependencies)
+}
+
+fn cargo_dependencies(
+    content: &str,
+    section: &DeTable<'_>,
+    kind: DependencyKind,
+    dependencies: &mut Vec<Dependency>,
+) {
+    for (key, value) in section {
+        let (name, version) = match value.get_ref() {
+            DeValue::String(version) => (key.get_ref().to_string(), Some(version.to_string())),
+            DeValue::Table(table) => (
+                // `package` renames a dependency
+                toml_str(table, "package")
+                    .unwrap_or(key.get_ref())
+                    .to_string(),
+                toml_str(table, "version").map(String::from),
+            ),
+            _ => continue,
+        };
+        dependencies.push(Dependency {
+            name,
+            version,
+            kind,
+            span: toml_span(content, key, value),
+        });
+    }
+}
+
+fn cargo_lock(content: &str) -> Result<Vec<Dependency>, String> {
+    let root = parse_toml(content)?;
+    let Some(packages) = toml_get(root.get_ref(), "package").and_then(|p| p.get_ref().as_array())
+    else {
+        return Ok(Vec::new());
+    };
+    Ok(packages
+        .iter()
+        .filter_map(|package| {
+            let package = package.get_ref().as_table()?;
+            let (key, value) = package.iter().find(|(key, _)| key.get_ref() == "name")?;
+            Some(Dependency {
+                name: value.get_ref().as_str()?.to_string(),
+                version: toml_str(package, "version").map(String::from),
+                kind: DependencyKind::Locked,
+                span: toml_span(content, key, value),
+            })
+        })
+        .collect())
+}
+
+// Python
+
+fn requirements(content: &str, kind: DependencyKind) -> Vec<Dependency> {
+    let mut dependencies = Vec::new();
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let start = offset + (line.len() - line.trim_start().len());
+        offset += line.len();
+        // Options such as `-r other.txt` and `--index-url` declare nothing
+        let requirement = line.split(" #").next().unwrap_or("").trim();
+        if requirement.is_empty() || requirement.starts_with(['#', '-']) {
+            continue;
+        }
+        if let Some((name, version)) = pep508(requirement) {
+            dependencies.push(Dependency {
+                name,
+                version,
+                kind,
+                span: span(content, start, start + requirement.len()),
+            });
+        }
+    }
+    dependencies
+}
+
+/// Name and version specifier of a PEP 508 requirement such as
+/// `django[bcrypt]>=4.2,<5; python_version >= "3.10"`
+fn pep508(requirement: &str) -> Option<(String, Option<String>)> {
+    let requirement = Regex::new(r"^\s*([A-Za-z0-9][A-Za-z0-9._-]*)\s*(?:\[[^\]]*\])?\s*([^;]*)")
+        .unwrap()
+        .captures(requirement)?;
+    let version = requirement[2].trim();
+    // `name @ https://...` points at a URL instead of a version
+    let version = (!version.is_empty() && !version.starts_with('@')).then(|| version.to_string());
+    Some((requirement[1].to_string(), version))
+}
+
+fn pyproject(content: &str) -> Result<Vec<Dependency>, String> {
+    let root = parse_toml(content)?;
+    let root = root.get_ref();
+    let mut dependencies = Vec::new();
+
+    if let Some(project) = toml_table(root, "project") {
+        if let Some(list) = toml_get(project, "dependencies") {
+            pep508_list(content, list, DependencyKind::Runtime, &mut dependencies);
+        }
+        if let Some(groups) = toml_table(project, "optional-dependencies") {
+            for (group, list) in groups {
+                let kind = if is_dev_group(group.get_ref()) {
+                    DependencyKind::Dev
+                } else {
+                    DependencyKind::Runtime
+                };
+                pep508_list(content, list, kind, &mut dependencies);
+            }
+        }
+    }
+    // PEP 735 groups are never installed with the project
+    if let Some(groups) = toml_table(root, "dependency-groups") {
+        for list in groups.values() {
+            pep508_list(content, list, DependencyKind::Dev, &mut dependencies);
+        }
+    }
+    if let Some(list) = toml_table(root, "build-system").and_then(|b| toml_get(b, "requires")) {
+        pep508_list(content, list, DependencyKind::Build, &mut dependencies);
+    }
+
+    if let Some(poetry) = toml_table(root, "tool").and_then(|t| toml_table(t, "poetry")) {
+        if let Some(section) = toml_table(poetry, "dependencies") {
+            poetry_dependencies(content, section, DependencyKind::Runtime, &mut dependencies);
+        }
+        if let Some(section) = toml_table(poetry, "dev-dependencies") {
+            poetry_dependencies(content, section, DependencyKind::Dev, &mut dependencies);
+        }
+        if let Some(groups) = toml_table(poetry, "group") {
+            for group in groups.values() {
+                let section = group
+                    .get_ref()
+                    .as_table()
+                    .and_then(|g| toml_table(g, "dependencies"));
+                if let Some(section) = section {
+                    poetry_dependencies(content, section, DependencyKind::Dev, &mut dependencies);
+                }
+            }
+        }
+    }
+    dependencies.sort_by_key(|dependency| dependency.span.start);
+    Ok(dependencies)
+}
+
+fn is_dev_group(name: &str) -> bool {
+    ["dev", "test", "lint", "doc"]
+        .iter()
+        .any(|dev| name.contains(dev))
+}
+
+fn pep508_list(
+    content: &str,
+    list: &Spanned<DeValue<'_>>,
+    kind: DependencyKind,
+    dependencies: &mut Vec<Dependency>,
+) {
+    let Some(items) = list.get_ref().as_array() else {
+        return;
+    };
+    for item in items.iter() {
+        let Some((name, version)) = item.get_ref().as_str().and_then(pep508) else {
+            continue;
+        };
+        let item_span = item.span();
+        dependencies.push(Dependency {
+            name,
+            version,
+            kind,
+            span: span(content, item_span.start, item_span.end),
+        });
+    }
+}
+
+fn poetry_dependencies(
+    content: &str,
+    section: &DeTable<'_>,
+    kind: DependencyKind,
+    dependencies: &mut Vec<Dependency>,
+) {
+    for (key, value) in section {
+        // The Python version the project supports
+        if key.get_ref() == "python" {
+            continue;
+        }
+        let version = match value.get_ref() {
+            DeValue::String(version) => Some(version.to_string()),
+            DeValue::Table(table) => toml_str(table, "version").map(String::from),
+            _ => None,
+        };
+        dependencies.push(Dependency {
+            name: key.get_ref().to_string(),
+            version,
+            kind,
+            span: toml_span(content, key, value),
+        });
+    }
+}
+
+// TOML helpers
+
+fn parse_toml(content: &str) -> Result<Spanned<DeTable<'_>>, String> {
+    DeTable::parse(content).map_err(|e| e.message().to_string())
+}
+
+fn toml_get<'a, 'i>(table: &'a DeTable<'i>, key: &str) -> Option<&'a Spanned<DeValue<'i>>> {
+    table
+        .iter()
+        .find(|(name, _)| name.get_ref() == key)
+        .map(|(_, value)| value)
+}
+
+fn toml_table<'a, 'i>(table: &'a DeTable<'i>, key: &str) -> Option<&'a DeTable<'i>> {
+    toml_get(table, key).and_then(|value| value.get_ref().as_table())
+}
+
+fn toml_str<'a>(table: &'a DeTable<'_>, key: &str) -> Option<&'a str> {
+    toml_get(table, key).and_then(|value| value.get_ref().as_str())
+}
+
+/// From the key to the end of the value; `[dependencies.name]` tables only
+/// cover their header
+fn toml_span(
+    content: &str,
+    key: &Spanned<std::borrow::Cow<'_, str>>,
+    value: &Spanned<DeValue<'_>>,
+) -> SourceSpan {
+    let key_span = key.span();
+    let value_span = value.span();
+    let end = if value_span.start > key_span.start {
+        value_span.end
+    } else {
+        key_span.end
+    };
+    span(content, key_span.start, end)
+}
+
+// JavaScript
+
+/// Dependency objects of package.json
+const PACKAGE_JSON_SECTIONS: &[(&str, DependencyKind)] = &[
+    ("dependencies", DependencyKind::Runtime),
+    ("peerDependencies", DependencyKind::Runtime),
+    ("optionalDependencies", DependencyKind::Runtime),
+    ("devDependencies", DependencyKind::Dev),
+];
+
+fn package_json(content: &str) -> Result<Vec<Dependency>, String> {
+    let root = JsonNode::parse(content)?;
+    let mut dependencies = Vec::new();
+    for (section, kind) in PACKAGE_JSON_SECTIONS {
+        let Some(section) = root.get(section) else {
+            continue;
+        };
+        for (name, start, value) in section.members() {
+            dependencies.push(Dependency {
+                name: name.clone(),
+                version: value.as_str().map(String::from),
+                kind: *kind,
+                span: span(content, *start, value.end),
+            });
+        }
+    }
+    Ok(dependencies)
+}
+
+fn package_lock(content: &str) -> Result<Vec<Dependency>, String> {
+    let root = JsonNode::parse(content)?;
+    let mut dependencies = Vec::new();
+    // Lockfile version 2 and later list every installed path, version 1
+    // a tree of dependencies
+    let (packages, prefix) = match root.get("packages") {
+        Some(packages) => (packages, Some("node_modules/")),
+        None => match root.get("dependencies") {
+            Some(packages) => (packages, None),
+            None => return Ok(dependencies),
+        },
+    };
+    for (path, start, package) in packages.members() {
+        let name = match prefix {
+            Some(prefix) => match path.rfind(prefix) {
+                Some(index) => &path[index + prefix.len()..],
+                // The project itself, or a workspace member
+                None => continue,
+            },
+            None => path.as_str(),
+        };
+        let dev = package
+            .get("dev")
+            .is_some_and(|dev| matches!(dev.value, JsonValue::True));
+        dependencies.push(Dependency {
+            name: name.to_string(),
+            version: package
+                .get("version")
+                .and_then(JsonNode::as_str)
+                .map(String::from),
+            kind: if dev {
+                DependencyKind::Dev
+            } else {
+                DependencyKind::Locked
+            },
+            span: span(content, *start, package.end),
+        });
+    }
+    Ok(dependencies)
+}
+
+fn yarn_lock(content: &str) -> Vec<Dependency> {
+    let mut dependencies: Vec<Dependency> = Vec::new();
+    let version = Regex::new(r#"^\s+version:?\s+"?([^"\s]+)"?"#).unwrap();
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            // `"@scope/name@^1.0.0", "@scope/name@^1.1.0":` or
+            // `"name@npm:^1.0.0":`
+            let descriptor = line.split(',').next().unwrap_or("");
+            let descriptor = descriptor.trim_end_matches(':').trim_matches('"');
+            let name = match descriptor[1.min(descriptor.len())..].find('@') {
+                Some(index) => &descriptor[..index + 1],
+                None => descriptor,
+            };
+            if name.is_empty() || name == "__metadata" {
+                continue;
+            }
+            dependencies.push(Dependency {
+                name: name.to_string(),
+                version: None,
+                kind: DependencyKind::Locked,
+                span: span(content, start, start + line.len()),
+            });
+        } else if let Some(captures) = version.captures(line) {
+            if let Some(dependency) = dependencies.last_mut().filter(|d| d.version.is_none()) {
+                dependency.version = Some(captures[1].to_string());
+            }
+        }
+    }
+    dependencies
+}
+
+/// A JSON value with the offset it ends at, as serde_json does not report
+/// positions; members of objects also record where their key starts
+struct JsonNode {
+    end: usize,
+    value: JsonValue,
+}
+
+enum JsonValue {
+    /// Members with the offset of their key
+    Object(Vec<(String, usize, JsonNode)>),
+    Array,
+    String(String),
+    True,
+    Other,
+}
+
+impl JsonNode {
+    fn parse(content: &str) -> Result<Self, String> {
+        // serde_json reports syntax errors, so the reader can assume valid JSON
+        serde_json::from_str::<serde_json::Value>(content).map_err(|e| e.to_string())?;
+        let mut reader = JsonReader { content, pos: 0 };
+        Ok(reader.value())
+    }
+
+    fn get(&self, key: &str) -> Option<&JsonNode> {
+        self.members()
+            .iter()
+            .find(|(name, _, _)| name == key)
+            .map(|(_, _, value)| value)
+    }
+
+    fn members(&self) -> &[(String, usize, JsonNode)] {
+        match self.value {
+            JsonValue::Object(ref members) => members,
+            _ => &[],
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self.value {
+            JsonValue::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+struct JsonReader<'a> {
+    content: &'a str,
+    pos: usize,
+}
+
+impl JsonReader<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.content[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> u8 {
+        self.content.as_bytes().get(self.pos).copied().unwrap_or(0)
+    }
+
+    fn value(&mut self) -> JsonNode {
+        self.skip_whitespace();
+        let value = match self.peek() {
+            b'{' => {
+                self.pos += 1;
+                let mut members = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        b'"' => {
+                            let key_start = self.pos;
+                            let key = self.string();
+                            self.skip_whitespace();
+                            self.pos += 1; // ':'
+                            members.push((key, key_start, self.value()));
+                        }
+                        b',' => self.pos += 1,
+                        _ => {
+                            self.pos += 1; // '}'
+                            break;
+                        }
+                    }
+                }
+                JsonValue::Object(members)
+            }
+            b'[' => {
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        b']' | 0 => {
+                            self.pos += 1;
+                            break;
+                        }
+                        b',' => self.pos += 1,
+                        _ => {
+                            self.value();
+                        }
+                    }
+                }
+                JsonValue::Array
+            }
+            b'"' => JsonValue::String(self.string()),
+            _ => {
+                let rest = &self.content[self.pos..];
+                let len = rest
+                    .find(|c: char| c == ',' || c == '}' || c == ']' || c.is_whitespace())
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                if &rest[..len] == "true" {
+                    JsonValue::True
+                } else {
+                    JsonValue::Other
+                }
+            }
+        };
+        JsonNode {
+            end: self.pos,
+            value,
+        }
+    }
+
+    fn string(&mut self) -> String {
+        let start = self.pos;
+        let bytes = self.content.as_bytes();
+        self.pos += 1;
+        while self.pos < bytes.len() && bytes[self.pos] != b'"' {
+            if bytes[self.pos] == b'\\' {
+                self.pos += 1;
+            }
+            self.pos += 1;
+        }
+        self.pos += 1;
+        serde_json::from_str(&self.content[start..self.pos.min(bytes.len())]).unwrap_or_default()
+    }
+}
+
+// Go
+
+fn go_mod(content: &str) -> Vec<Dependency> {
+    let requirement = Regex::new(r"^(?:require\s+)?([^\s()]+)\s+(v\S+)").unwrap();
+    let mut dependencies = Vec::new();
+    let mut in_require = false;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let start = offset + (line.len() - line.trim_start().len());
+        offset += line.len();
+        let (code, comment) = match line.find("//") {
+            Some(index) => (&line[..index], &line[index..]),
+            None => (line, ""),
+        };
+        let code = code.trim();
+        if code.starts_with("require") && code.ends_with('(') {
+            in_require = true;
+            continue;
+        }
+        if in_require && code == ")" {
+            in_require = false;
+            continue;
+        }
+        if !in_require && !code.starts_with("require ") {
+            continue;
+        }
+        if let Some(captures) = requirement.captures(code) {
+            let kind = if comment.contains("indirect") {
+                DependencyKind::Locked
+            } else {
+                DependencyKind::Runtime
+            };
+            dependencies.push(Dependency {
+                name: captures[1].to_string(),
+                version: Some(captures[2].to_string()),
+                kind,
+                span: span(content, start, start + line.trim().len()),
+            });
+        }
+    }
+    dependencies
+}
+
+// JVM
+
+fn pom(content: &str) -> Vec<Dependency> {
+    // Blank out comments so that commented dependencies are not read, and
+    // `<dependencyManagement>`, which only pins versions of dependencies
+    // declared elsewhere, keeping offsets intact
+    let ignored =
+        Regex::new(r"(?s)<!--.*?-->|<dependencyManagement>.*?</dependencyManagement>").unwrap();
+    let stripped = ignored.replace_all(content, |captures: &regex::Captures| {
+        captures[0].replace(|c: char| c != '\n', " ")
+    });
+
+    let dependency = Regex::new(r"(?s)<dependency>(.*?)</dependency>").unwrap();
+    let element = |body: &str, name: &str| {
+        Regex::new(&format!(r"<{name}>\s*([^<]*?)\s*</{name}>"))
+            .unwrap()
+            .captures(body)
+            .map(|captures| captures[1].to_string())
+    };
+    dependency
+        .captures_iter(&stripped)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            let body = &captures[1];
+            let artifact = element(body, "artifactId")?;
+            let name = match element(body, "groupId") {
+                Some(group) => format!("{group}:{artifact}"),
+                None => artifact,
+            };
+            let kind = match element(body, "scope").as_deref() {
+                Some("test") => DependencyKind::Dev,
+                Some("provided") => DependencyKind::Build,
+                _ => DependencyKind::Runtime,
+            };
+            Some(Dependency {
+                name,
+                version: element(body, "version"),
+                kind,
+                span: span(content, whole.start(), whole.end()),
+            })
+        })
+        .collect()
+}
+
+fn gradle(content: &str) -> Vec<Dependency> {
+    // `implementation 'group:name:version'`, `implementation("group:name")`
+    let coordinates = Regex::new(
+        r#"(?m)^[ \t]*(\w+)[ \t]*\(?[ \t]*["']([^"':\s]+):([^"':\s]+)(?::([^"'@\s]+))?[^"']*["']"#,
+    )
+    .unwrap();
+    // `implementation group: 'group', name: 'name', version: 'version'`
+    let map = Regex::new(
+        r#"(?m)^[ \t]*(\w+)[ \t]*\(?[ \t]*group\s*[:=]\s*["']([^"']+)["']\s*,\s*name\s*[:=]\s*["']([^"']+)["'](?:\s*,\s*version\s*[:=]\s*["']([^"']+)["'])?"#,
+    )
+    .unwrap();
+
+    let mut dependencies: Vec<Dependency> = coordinates
+        .captures_iter(content)
+        .chain(map.captures_iter(content))
+        .filter_map(|captures| {
+            let kind = gradle_kind(&captures[1])?;
+            let whole = captures.get(0)?;
+            let start = whole.start() + (whole.len() - whole.as_str().trim_start().len());
+            Some(Dependency {
+                name: format!("{}:{}", &captures[2], &captures[3]),
+                version: captures.get(4).map(|v| v.as_str().to_string()),
+                kind,
+                span: span(content, start, whole.end()),
+            })
+        })
+        .collect();
+    dependencies.sort_by_key(|dependency| dependency.span.start);
+    dependencies
+}
+
+/// Kind of dependency a Gradle configuration declares, if it is one
+fn gradle_kind(configuration: &str) -> Option<DependencyKind> {
+    Some(match configuration {
+        "implementation" | "api" | "compile" | "runtime" | "runtimeOnly" => DependencyKind::Runtime,
+        "compileOnly" | "annotationProcessor" | "kapt" | "ksp" | "classpath" => {
+            DependencyKind::Build
+        }
+        configuration if configuration.starts_with("test") => DependencyKind::Dev,
+        "developmentOnly" => DependencyKind::Dev,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(file_name: &str, content: &str) -> Vec<Dependency> {
+        parse(Path::new(file_name), content).unwrap().unwrap()
+    }
+
+    fn summary(dependencies: &[Dependency]) -> Vec<(&str, Option<&str>, DependencyKind)> {
+        dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_cargo_manifests() {
+        let content = r#"[package]
+name = "app"
+# axum = "0.6" is not used any more
+
+[dependencies]
+tokio = { version = "1.0", features = ["postgres"] }
+db = { package = "tokio-postgres", version = "0.7" }
+
+[dev-dependencies]
+mockall = "0.12"
+
+[target.'cfg(unix)'.build-dependencies]
+cc = "1"
+
+[dependencies.serde]
+version = "1.0"
+"#;
+        let dependencies = parse_str("Cargo.toml", content);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                ("tokio", Some("1.0"), DependencyKind::Runtime),
+                ("tokio-postgres", Some("0.7"), DependencyKind::Runtime),
+                ("mockall", Some("0.12"), DependencyKind::Dev),
+                ("cc", Some("1"), DependencyKind::Build),
+                ("serde", Some("1.0"), DependencyKind::Runtime),
+            ]
+        );
+        let tokio = dependencies[0].span;
+        assert_eq!((tokio.line, tokio.column), (6, 1));
+        assert_eq!(
+            &content[tokio.start..tokio.end],
+            r#"tokio = { version = "1.0", features = ["postgres"] }"#
+        );
+
+        let lock = "[[package]]\nname = \"axum\"\nversion = \"0.7.5\"\n";
+        let dependencies = parse_str("Cargo.lock", lock);
+        assert_eq!(
+            summary(&dependencies),
+            [("axum", Some("0.7.5"), DependencyKind::Locked)]
+        );
+        assert_eq!(dependencies[0].span.line, 2);
+
+        assert!(parse(Path::new("Cargo.toml"), "[dependencies")
+            .unwrap()
+            .is_err());
+        assert!(parse(Path::new("src/main.rs"), "").is_none());
+    }
+
+    #[test]
+    fn test_javascript_manifests() {
+        let content = r#"{
+  "name": "web",
+  "description": "uses \"react\" a lot",
+  "dependencies": {
+    "react": "^18.2.0",
+    "@tanstack/query": "5.0.0"
+  },
+  "devDependencies": { "jest": "29" }
+}"#;
+        let dependencies = parse_str("package.json", content);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                ("react", Some("^18.2.0"), DependencyKind::Runtime),
+                ("@tanstack/query", Some("5.0.0"), DependencyKind::Runtime),
+                ("jest", Some("29"), DependencyKind::Dev),
+            ]
+        );
+        let react = dependencies[0].span;
+        assert_eq!((react.line, react.column), (5, 5));
+        assert_eq!(&content[react.start..react.end], r#""react": "^18.2.0""#);
+
+        let lock = r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "web" },
+    "node_modules/react": { "version": "18.2.0" },
+    "node_modules/a/node_modules/jest": { "version": "29.7.0", "dev": true }
+  }
+}"#;
+        assert_eq!(
+            summary(&parse_str("package-lock.json", lock)),
+            [
+                ("react", Some("18.2.0"), DependencyKind::Locked),
+                ("jest", Some("29.7.0"), DependencyKind::Dev),
+            ]
+        );
+
+        let yarn = r#"# yarn lockfile v1
+
+"@babel/core@^7.0.0", "@babel/core@^7.1.0":
+  version "7.24.0"
+  resolved "https://registry.yarnpkg.com/@babel/core/-/core-7.24.0.tgz"
+
+react@^18.2.0:
+  version "18.2.0"
+"#;
+        let dependencies = parse_str("yarn.lock", yarn);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                ("@babel/core", Some("7.24.0"), DependencyKind::Locked),
+                ("react", Some("18.2.0"), DependencyKind::Locked),
+            ]
+        );
+        assert_eq!(dependencies[1].span.line, 7);
+    }
+
+    #[test]
+    fn test_python_manifests() {
+        let requirements = "# web\nDjango[bcrypt]>=4.2,<5 ; python_version >= \"3.10\"\n-r base.txt\n  psycopg2-binary  # driver\nmylib @ https://example.com/mylib.zip\n";
+        let dependencies = parse_str("requirements.txt", requirements);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                ("Django", Some(">=4.2,<5"), DependencyKind::Runtime),
+                ("psycopg2-binary", None, DependencyKind::Runtime),
+                ("mylib", None, DependencyKind::Runtime),
+            ]
+        );
+        let psycopg = dependencies[1].span;
+        assert_eq!((psycopg.line, psycopg.column), (4, 3));
+        assert_eq!(&requirements[psycopg.start..psycopg.end], "psycopg2-binary");
+        assert_eq!(
+            parse_str("requirements-dev.txt", "pytest==8.0\n")[0].kind,
+            DependencyKind::Dev
+        );
+
+        let pyproject = r#"[build-system]
+requires = ["hatchling"]
+
+[project]
+dependencies = ["fastapi>=0.110", "sqlalchemy"]
+
+[project.optional-dependencies]
+test = ["pytest"]
+
+[tool.poetry.dependencies]
+python = "^3.11"
+redis = { version = "^5.0" }
+"#;
+        let dependencies = parse_str("pyproject.toml", pyproject);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                ("hatchling", None, DependencyKind::Build),
+                ("fastapi", Some(">=0.110"), DependencyKind::Runtime),
+                ("sqlalchemy", None, DependencyKind::Runtime),
+                ("pytest", None, DependencyKind::Dev),
+                ("redis", Some("^5.0"), DependencyKind::Runtime),
+            ]
+        );
+        assert_eq!(dependencies[1].span.line, 5);
+    }
+
+    #[test]
+    fn test_go_and_jvm_manifests() {
+        let go = "module example.com/app\n\ngo 1.22\n\nrequire github.com/gin-gonic/gin v1.9.1\n\nrequire (\n\tgithub.com/lib/pq v1.10.9\n\tgolang.org/x/net v0.20.0 // indirect\n)\n";
+        assert_eq!(
+            summary(&parse_str("go.mod", go)),
+            [
+                (
+                    "github.com/gin-gonic/gin",
+                    Some("v1.9.1"),
+                    DependencyKind::Runtime
+                ),
+                (
+                    "github.com/lib/pq",
+                    Some("v1.10.9"),
+                    DependencyKind::Runtime
+                ),
+                ("golang.org/x/net", Some("v0.20.0"), DependencyKind::Locked),
+            ]
+        );
+
+        let pom = r#"<project>
+  <dependencies>
+    <!-- <dependency><groupId>mysql</groupId><artifactId>mysql-connector-java</artifactId></dependency> -->
+    <dependency>
+      <groupId>org.springframework.boot</groupId>
+      <artifactId>spring-boot-starter-web</artifactId>
+      <version>3.2.0</version>
+    </dependency>
+    <dependency>
+      <groupId>org.junit.jupiter</groupId>
+      <artifactId>junit-jupiter</artifactId>
+      <scope>test</scope>
+    </dependency>
+  </dependencies>
+  <dependencyManagement>
+    <dependencies>
+      <dependency>
+        <groupId>org.postgresql</groupId>
+        <artifactId>postgresql</artifactId>
+        <version>42.7.1</version>
+      </dependency>
+    </dependencies>
+  </dependencyManagement>
+</project>"#;
+        let dependencies = parse_str("pom.xml", pom);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                (
+                    "org.springframework.boot:spring-boot-starter-web",
+                    Some("3.2.0"),
+                    DependencyKind::Runtime
+                ),
+                ("org.junit.jupiter:junit-jupiter", None, DependencyKind::Dev),
+            ]
+        );
+        assert_eq!(dependencies[0].span.line, 4);
+
+        let gradle = r#"dependencies {
+    implementation("org.postgresql:postgresql:42.7.1")
+    // implementation 'mysql:mysql-connector-java:8.0.33'
+    testImplementation 'org.junit.jupiter:junit-jupiter'
+    compileOnly group: 'org.projectlombok', name: 'lombok', version: '1.18.30'
+}"#;
+        let dependencies = parse_str("build.gradle.kts", gradle);
+        assert_eq!(
+            summary(&dependencies),
+            [
+                (
+                    "org.postgresql:postgresql",
+                    Some("42.7.1"),
+                    DependencyKind::Runtime
+                ),
+                ("org.junit.jupiter:junit-jupiter", None, DependencyKind::Dev),
+                (
+                    "org.projectlombok:lombok",
+                    Some("1.18.30"),
+                    DependencyKind::Build
+                ),
+            ]
+        );
+        let postgres = dependencies[0].span;
+        assert_eq!((postgres.line, postgres.column), (2, 5));
+    }
+}
@@ -4,10 +4,17 @@
 //! between Architecture Decision Records (ADRs) and the actual codebase state.
 
 pub mod detector;
+pub mod imports;
+pub mod layering;
+pub mod manifests;
 pub mod patterns;
 pub mod report;
+pub mod rules;
 pub mod scanner;
 pub mod snapshot;
+#[cfg(feature = "ast")]
+pub mod structure;
+pub mod versions;
 
 pub use detector::DriftDetector;
 pub use patterns::{PatternMatcher, TechnologyMatch};
@@ -17,9 +24,10 @@ pub use report::{
 pub use scanner::CodebaseScanner;
 pub use snapshot::{Snapshot, SnapshotEntryType};
 
-use crate::config::DetectionPattern;
+use crate::config::{CacheConfig, DetectionPattern, LayerRule};
 use crate::error::AdrscanError;
-use std::path::Path;
+use layering::LayerAnalyzer;
+use std::path::{Path, PathBuf};
 
 /// Result type for drift detection operations
 pub type DriftResult<T> = std::result::Result<T, AdrscanError>;
@@ -28,6 +36,8 @@ pub type DriftResult<T> = std::result::Result<T, AdrscanError>;
 pub struct DriftEngine {
     detector: DriftDetector,
     scanner: CodebaseScanner,
+    incremental: bool,
+    layers: Vec<LayerRule>,
 }
 
 impl DriftEngine {
@@ -36,37 +46,101 @@ impl DriftEngine {
         Self {
             detector: DriftDetector::new(),
             scanner: CodebaseScanner::new(),
+            incremental: false,
+            layers: Vec::new(),
         }
     }
 
-    /// Perform comprehensive drift detection
+    /// Read and update the persistent ADR cache when parsing ADRs
+    pub fn with_adr_cache(mut self, cache: &CacheConfig) -> Self {
+        self.detector = self.detector.with_adr_cache(cache);
+        self
+    }
+
+    /// Only scan files matching the config's include and exclude patterns
+    pub fn with_scan_patterns(
+        mut self,
+        include: &[String],
+        exclude: &[String],
+    ) -> DriftResult<Self> {
+        self.scanner = self.scanner.with_patterns(include, exclude)?;
+        Ok(self)
+    }
+
+    /// Only rescan files changed since the commit of the baseline snapshot
+    pub fn with_incremental(mut self, enabled: bool) -> Self {
+        self.incremental = enabled;
+        self
+    }
+
+    /// Check imports against the config's layering rules, in addition to
+    /// those declared by accepted ADRs
+    pub fn with_layers(mut self, layers: &[LayerRule]) -> Self {
+        self.layers = layers.to_vec();
+        self
+    }
+
+    /// Perform comprehensive drift detection against the ADRs of several
+    /// directories
     pub async fn detect_drift(
         &self,
-        adr_dir: &Path,
+        adr_dirs: &[PathBuf],
         codebase_dir: &Path,
         baseline_snapshot: Option<&Path>,
         detection_patterns: &[DetectionPattern],
     ) -> DriftResult<DriftReport> {
-        log::info!("Starting drift detection...");
-
-        // 1. Scan current codebase state
-        let current_snapshot = self
-            .scanner
-            .scan_codebase(codebase_dir, detection_patterns)
+        let (report, _) = self
+            .detect_drift_with_snapshot(
+                adr_dirs,
+                codebase_dir,
+                baseline_snapshot,
+                detection_patterns,
+            )
             .await?;
+        Ok(report)
+    }
 
-        // 2. Load baseline snapshot if provided
+    /// Perform drift detection against the ADRs of several directories,
+    /// also returning the snapshot of the current codebase state so that it
+    /// can be saved as a later baseline
+    pub async fn detect_drift_with_snapshot(
+        &self,
+        adr_dirs: &[PathBuf],
+        codebase_dir: &Path,
+        baseline_snapshot: Option<&Path>,
+        detection_patterns: &[DetectionPattern],
+    ) -> DriftResult<(DriftReport, Snapshot)> {
+        log::info!("Starting drift detection...");
+
+        // 1. Load baseline snapshot if provided
         let baseline = if let Some(baseline_path) = baseline_snapshot {
             Some(Snapshot::load(baseline_path)?)
         } else {
             None
         };
 
+        // 2. Scan current codebase state
+        let current_snapshot = match baseline {
+            Some(ref baseline) if self.incremental => {
+                self.scanner
+                    .scan_incremental(codebase_dir, detection_patterns, baseline)
+                    .await?
+            }
+            _ => {
+                self.scanner
+                    .scan_codebase(codebase_dir, detection_patterns)
+                    .await?
+            }
+        };
+
         // 3. Parse ADRs for architectural decisions
-        let adr_decisions = self.detector.parse_adr_decisions(adr_dir).await?;
+        let mut adr_decisions = Vec::new();
+        for adr_dir in adr_dirs {
+            adr_decisions.extend(self.detector.parse_adr_decisions(adr_dir).await?);
+        }
 
         // 4. Detect drift between current state, baseline, and ADRs
-        let drift_report = self
+        let mut drift_report = self
             .detector
             .detect_drift(
                 &current_snapshot,
@@ -76,11 +150,26 @@ impl DriftEngine {
             )
             .await?;
 
+        // 5. Check imports against layering rules, reading the codebase
+        // once for the layers of the config and of every ADR root
+        let mut analyzer = LayerAnalyzer::new().with_layers(&self.layers, None)?;
+        for decision in adr_decisions.iter().filter(|d| d.is_accepted()) {
+            if let Some(ref rules) = decision.rules {
+                analyzer = analyzer.with_layers(&rules.layers, Some(&decision.title))?;
+            }
+        }
+        if analyzer.has_rules() {
+            let files = self.scanner.collect_files(codebase_dir);
+            analyzer.analyze(codebase_dir, &files, &mut drift_report);
+        }
+
+        drift_report.dedup_items();
+
         log::info!(
             "Drift detection completed. Found {} drift items",
             drift_report.items.len()
         );
-        Ok(drift_report)
+        Ok((drift_report, current_snapshot))
     }
 }
 
@@ -3,6 +3,9 @@
 //! This module defines patterns for detecting various technologies,
 //! frameworks, and architectural elements in codebases.
 
+use crate::drift::manifests::{self, Dependency, DependencyKind};
+#[cfg(feature = "ast")]
+use crate::drift::structure::StructuralMatcher;
 use crate::drift::DriftResult;
 use crate::error::AdrscanError;
 use regex::Regex;
@@ -32,6 +35,10 @@ pub struct TechnologyMatch {
 
     /// Confidence level of the match (0.0 to 1.0)
     pub confidence: f64,
+
+    /// The dependency that matched, for matches in dependency manifests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<Dependency>,
 }
 
 /// Built-in technology detection patterns
@@ -39,7 +46,8 @@ pub struct TechnologyMatch {
 pub struct BuiltinPatterns;
 
 impl BuiltinPatterns {
-    /// Get default patterns for common technologies
+    /// Get default patterns for common technologies. Patterns for
+    /// dependency manifests such as Cargo.toml match package names.
     #[allow(dead_code)] // Planned for default pattern loading
     pub fn default_patterns() -> Vec<DetectionPattern> {
         vec![
@@ -47,57 +55,67 @@ impl BuiltinPatterns {
             DetectionPattern {
                 name: "PostgreSQL Database".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"(postgres|tokio-postgres|diesel.*postgres)"#.to_string(),
+                content_pattern: r#"^(postgres|tokio-postgres|deadpool-postgres|sqlx-postgres)$"#
+                    .to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "MySQL Database".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"(mysql|tokio-mysql|diesel.*mysql)"#.to_string(),
+                content_pattern: r#"^(mysql|mysql_async|sqlx-mysql)$"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "SQLite Database".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"(sqlite|rusqlite|diesel.*sqlite)"#.to_string(),
+                content_pattern: r#"^(rusqlite|libsqlite3-sys|sqlx-sqlite)$"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "MongoDB Database".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"(mongodb|bson)"#.to_string(),
+                content_pattern: r#"^(mongodb|bson)$"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Redis Cache".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"(redis|darkredis)"#.to_string(),
+                content_pattern: r#"^(redis|darkredis|deadpool-redis)$"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
             // Web Frameworks (Rust)
             DetectionPattern {
                 name: "Axum Web Framework".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"axum\s*="#.to_string(),
+                content_pattern: r#"^axum$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Actix Web Framework".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"actix-web\s*="#.to_string(),
+                content_pattern: r#"^actix-web$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Warp Web Framework".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"warp\s*="#.to_string(),
+                content_pattern: r#"^warp$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Rocket Web Framework".to_string(),
                 file_pattern: "**/Cargo.toml".to_string(),
-                content_pattern: r#"rocket\s*="#.to_string(),
+                content_pattern: r#"^rocket$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             // Cloud Providers (Infrastructure as Code)
             DetectionPattern {
@@ -105,49 +123,57 @@ impl BuiltinPatterns {
                 file_pattern: "**/*.tf".to_string(),
                 content_pattern: r#"provider\s+"aws""#.to_string(),
                 category: "cloud".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Azure Provider".to_string(),
                 file_pattern: "**/*.tf".to_string(),
                 content_pattern: r#"provider\s+"azurerm""#.to_string(),
                 category: "cloud".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Google Cloud Provider".to_string(),
                 file_pattern: "**/*.tf".to_string(),
                 content_pattern: r#"provider\s+"google""#.to_string(),
                 category: "cloud".to_string(),
+                structure: None,
             },
             // JavaScript/TypeScript Frameworks
             DetectionPattern {
                 name: "React Framework".to_string(),
                 file_pattern: "**/package.json".to_string(),
-                content_pattern: r#""react"\s*:"#.to_string(),
+                content_pattern: r#"^react$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Vue.js Framework".to_string(),
                 file_pattern: "**/package.json".to_string(),
-                content_pattern: r#""vue"\s*:"#.to_string(),
+                content_pattern: r#"^vue$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Angular Framework".to_string(),
                 file_pattern: "**/package.json".to_string(),
-                content_pattern: r#""@angular/core"\s*:"#.to_string(),
+                content_pattern: r#"^@angular/core$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Express.js Framework".to_string(),
                 file_pattern: "**/package.json".to_string(),
-                content_pattern: r#""express"\s*:"#.to_string(),
+                content_pattern: r#"^express$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Next.js Framework".to_string(),
                 file_pattern: "**/package.json".to_string(),
-                content_pattern: r#""next"\s*:"#.to_string(),
+                content_pattern: r#"^next$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             // Authentication Libraries
             DetectionPattern {
@@ -155,12 +181,14 @@ impl BuiltinPatterns {
                 file_pattern: "**/*.{rs,js,ts,py}".to_string(),
                 content_pattern: r#"(jsonwebtoken|jwt|JWT)"#.to_string(),
                 category: "authentication".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "OAuth Implementation".to_string(),
                 file_pattern: "**/*.{rs,js,ts,py}".to_string(),
                 content_pattern: r#"(oauth|OAuth|passport)"#.to_string(),
                 category: "authentication".to_string(),
+                structure: None,
             },
             // Container Technology
             DetectionPattern {
@@ -168,12 +196,14 @@ impl BuiltinPatterns {
                 file_pattern: "**/Dockerfile".to_string(),
                 content_pattern: r#"FROM\s+"#.to_string(),
                 category: "infrastructure".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Kubernetes Deployment".to_string(),
                 file_pattern: "**/*.{yaml,yml}".to_string(),
                 content_pattern: r#"apiVersion:\s*(apps/v1|v1)"#.to_string(),
                 category: "infrastructure".to_string(),
+                structure: None,
             },
             // Message Queues
             DetectionPattern {
@@ -181,12 +211,14 @@ impl BuiltinPatterns {
                 file_pattern: "**/*.{rs,js,ts,py,java}".to_string(),
                 content_pattern: r#"(rabbitmq|amqp)"#.to_string(),
                 category: "messaging".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Apache Kafka".to_string(),
                 file_pattern: "**/*.{rs,js,ts,py,java}".to_string(),
                 content_pattern: r#"(kafka|rdkafka)"#.to_string(),
                 category: "messaging".to_string(),
+                structure: None,
             },
             // Monitoring and Observability
             DetectionPattern {
@@ -194,12 +226,14 @@ impl BuiltinPatterns {
                 file_pattern: "**/*.{rs,js,ts,py}".to_string(),
                 content_pattern: r#"(prometheus|metrics)"#.to_string(),
                 category: "monitoring".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "OpenTelemetry".to_string(),
                 file_pattern: "**/*.{rs,js,ts,py}".to_string(),
                 content_pattern: r#"(opentelemetry|tracing)"#.to_string(),
                 category: "monitoring".to_string(),
+                structure: None,
             },
         ]
     }
@@ -211,26 +245,30 @@ impl BuiltinPatterns {
             DetectionPattern {
                 name: "Django Framework".to_string(),
                 file_pattern: "**/requirements.txt".to_string(),
-                content_pattern: r#"[Dd]jango"#.to_string(),
+                content_pattern: r#"(?i)^django$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Flask Framework".to_string(),
                 file_pattern: "**/requirements.txt".to_string(),
-                content_pattern: r#"[Ff]lask"#.to_string(),
+                content_pattern: r#"(?i)^flask$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "FastAPI Framework".to_string(),
                 file_pattern: "**/requirements.txt".to_string(),
-                content_pattern: r#"fastapi"#.to_string(),
+                content_pattern: r#"(?i)^fastapi$"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "SQLAlchemy ORM".to_string(),
                 file_pattern: "**/requirements.txt".to_string(),
-                content_pattern: r#"[Ss][Qq][Ll][Aa]lchemy"#.to_string(),
+                content_pattern: r#"(?i)^sqlalchemy$"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
         ]
     }
@@ -242,20 +280,23 @@ impl BuiltinPatterns {
             DetectionPattern {
                 name: "Spring Framework".to_string(),
                 file_pattern: "**/pom.xml".to_string(),
-                content_pattern: r#"<groupId>org\.springframework"#.to_string(),
+                content_pattern: r#"^org\.springframework"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Spring Boot".to_string(),
                 file_pattern: "**/pom.xml".to_string(),
-                content_pattern: r#"spring-boot-starter"#.to_string(),
+                content_pattern: r#":spring-boot-starter"#.to_string(),
                 category: "framework".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Hibernate ORM".to_string(),
                 file_pattern: "**/pom.xml".to_string(),
-                content_pattern: r#"<groupId>org\.hibernate"#.to_string(),
+                content_pattern: r#"^org\.hibernate"#.to_string(),
                 category: "database".to_string(),
+                structure: None,
             },
         ]
     }
@@ -269,8 +310,14 @@ pub struct PatternMatcher {
 /// A compiled detection pattern with cached regex
 struct CompiledPattern {
     pattern: DetectionPattern,
-    regex: Regex,
+    /// `None` for an empty content pattern, which matches nothing
+    regex: Option<Regex>,
+    /// Whether the content pattern is written against manifest lines, like
+    /// `axum\s*=`, rather than package names
+    matches_lines: bool,
     file_matcher: glob::Pattern,
+    #[cfg(feature = "ast")]
+    structure: Option<StructuralMatcher>,
 }
 
 impl PatternMatcher {
@@ -279,12 +326,16 @@ impl PatternMatcher {
         let mut compiled_patterns = Vec::new();
 
         for pattern in patterns {
-            let regex = Regex::new(&pattern.content_pattern).map_err(|e| {
-                AdrscanError::DriftError(format!(
-                    "Invalid regex pattern '{}': {}",
-                    pattern.content_pattern, e
-                ))
-            })?;
+            let regex = if pattern.content_pattern.is_empty() {
+                None
+            } else {
+                Some(Regex::new(&pattern.content_pattern).map_err(|e| {
+                    AdrscanError::DriftError(format!(
+                        "Invalid regex pattern '{}': {}",
+                        pattern.content_pattern, e
+                    ))
+                })?)
+            };
 
             let file_matcher = glob::Pattern::new(&pattern.file_pattern).map_err(|e| {
                 AdrscanError::DriftError(format!(
@@ -293,10 +344,32 @@ impl PatternMatcher {
                 ))
             })?;
 
+            #[cfg(not(feature = "ast"))]
+            if pattern.structure.is_some() {
+                log::warn!(
+                    "Pattern '{}' has a structure query, which needs adrscan built with the `ast` feature; matching content_pattern only",
+                    pattern.name
+                );
+            }
+
+            #[cfg(feature = "ast")]
+            let structure = pattern
+                .structure
+                .as_ref()
+                .map(|query| {
+                    StructuralMatcher::new(query).map_err(|e| {
+                        AdrscanError::DriftError(format!("Pattern '{}': {}", pattern.name, e))
+                    })
+                })
+                .transpose()?;
+
             compiled_patterns.push(CompiledPattern {
                 pattern: pattern.clone(),
                 regex,
+                matches_lines: is_line_pattern(&pattern.content_pattern),
                 file_matcher,
+                #[cfg(feature = "ast")]
+                structure,
             });
         }
 
@@ -314,39 +387,141 @@ impl PatternMatcher {
             .collect()
     }
 
-    /// Find all technology matches in a file
+    /// Find all technology matches in a file. In dependency manifests the
+    /// content patterns are matched against the names of the dependencies;
+    /// manifests that cannot be parsed are matched line by line. Patterns
+    /// with a structural query are matched against the syntax tree of
+    /// source files in supported languages, and line by line otherwise.
     pub fn find_matches(
         &self,
         file_path: &Path,
         content: &str,
     ) -> DriftResult<Vec<TechnologyMatch>> {
+        match manifests::parse(file_path, content) {
+            Some(Ok(dependencies)) => {
+                return Ok(self.find_dependency_matches(file_path, content, &dependencies))
+            }
+            Some(Err(e)) => log::debug!("{e}, matching lines instead"),
+            None => {}
+        }
+
         let mut matches = Vec::new();
         let path_str = file_path.to_string_lossy().to_string();
+        #[cfg(feature = "ast")]
+        let lines: Vec<&str> = content.lines().collect();
 
         for compiled_pattern in &self.compiled_patterns {
             if !compiled_pattern.file_matcher.matches(&path_str) {
                 continue;
             }
 
-            for (line_number, line) in content.lines().enumerate() {
-                if let Some(captures) = compiled_pattern.regex.captures(line) {
-                    let matched_text = captures.get(0).unwrap().as_str().to_string();
-
+            #[cfg(feature = "ast")]
+            if let Some(found) = compiled_pattern
+                .structure
+                .as_ref()
+                .and_then(|structure| structure.find(file_path, content))
+            {
+                for structural_match in found {
                     matches.push(TechnologyMatch {
                         pattern: compiled_pattern.pattern.clone(),
                         file_path: path_str.clone(),
-                        line_number: line_number + 1,
-                        matched_text,
-                        context: line.to_string(),
-                        confidence: self.calculate_confidence(&compiled_pattern.pattern, line),
+                        line_number: structural_match.line,
+                        matched_text: structural_match.text,
+                        context: lines
+                            .get(structural_match.line - 1)
+                            .unwrap_or(&"")
+                            .to_string(),
+                        // Syntax tree matches are never in comments or strings
+                        confidence: 1.0,
+                        dependency: None,
                     });
                 }
+                continue;
             }
+
+            self.find_line_matches(compiled_pattern, &path_str, content, &mut matches);
         }
 
         Ok(matches)
     }
 
+    /// Match the content pattern of `compiled_pattern` line by line
+    fn find_line_matches(
+        &self,
+        compiled_pattern: &CompiledPattern,
+        path_str: &str,
+        content: &str,
+        matches: &mut Vec<TechnologyMatch>,
+    ) {
+        let Some(ref regex) = compiled_pattern.regex else {
+            return;
+        };
+        for (line_number, line) in content.lines().enumerate() {
+            if let Some(captures) = regex.captures(line) {
+                let matched_text = captures.get(0).unwrap().as_str().to_string();
+
+                matches.push(TechnologyMatch {
+                    pattern: compiled_pattern.pattern.clone(),
+                    file_path: path_str.to_string(),
+                    line_number: line_number + 1,
+                    matched_text,
+                    context: line.to_string(),
+                    confidence: self.calculate_confidence(&compiled_pattern.pattern, line),
+                    dependency: None,
+                });
+            }
+        }
+    }
+
+    /// Match the dependencies declared in a manifest; patterns written
+    /// against manifest lines are matched line by line
+    fn find_dependency_matches(
+        &self,
+        file_path: &Path,
+        content: &str,
+        dependencies: &[Dependency],
+    ) -> Vec<TechnologyMatch> {
+        let path_str = file_path.to_string_lossy().to_string();
+        let lines: Vec<&str> = content.lines().collect();
+
+        let mut matches = Vec::new();
+        for compiled_pattern in &self.compiled_patterns {
+            if !compiled_pattern.file_matcher.matches(&path_str) {
+                continue;
+            }
+            if compiled_pattern.matches_lines {
+                self.find_line_matches(compiled_pattern, &path_str, content, &mut matches);
+                continue;
+            }
+            let Some(ref regex) = compiled_pattern.regex else {
+                continue;
+            };
+            for dependency in dependencies {
+                if !regex.is_match(&dependency.name) {
+                    continue;
+                }
+                matches.push(TechnologyMatch {
+                    pattern: compiled_pattern.pattern.clone(),
+                    file_path: path_str.clone(),
+                    line_number: dependency.span.line,
+                    matched_text: dependency.name.clone(),
+                    context: lines
+                        .get(dependency.span.line - 1)
+                        .unwrap_or(&"")
+                        .to_string(),
+                    confidence: match dependency.kind {
+                        DependencyKind::Runtime => 1.0,
+                        // Less likely to be an architectural choice
+                        DependencyKind::Dev | DependencyKind::Build => 0.8,
+                        DependencyKind::Locked => 0.7,
+                    },
+                    dependency: Some(dependency.clone()),
+                });
+            }
+        }
+        matches
+    }
+
     /// Calculate confidence score for a match
     fn calculate_confidence(&self, pattern: &DetectionPattern, line: &str) -> f64 {
         let mut confidence = 0.8; // Base confidence
@@ -374,6 +549,14 @@ impl PatternMatcher {
     }
 }
 
+/// Whether a content pattern can only match manifest lines and never a
+/// package name: package names have no whitespace, quotes, `=` or tags
+fn is_line_pattern(content_pattern: &str) -> bool {
+    // Named groups are the only regex syntax with `<`
+    let pattern = content_pattern.replace("(?P<", "(").replace("(?<", "(");
+    pattern.contains(['=', '"', '\'', '<', ' ', '\t']) || pattern.contains(r"\s")
+}
+
 impl TechnologyMatch {
     /// Check if this match is likely in a comment
     #[allow(dead_code)] // Planned for enhanced match filtering
@@ -446,6 +629,7 @@ mod tests {
             file_pattern: "**/*.rs".to_string(),
             content_pattern: r"use\s+test".to_string(),
             category: "test".to_string(),
+            structure: None,
         }];
 
         let matcher = PatternMatcher::new(&patterns);
@@ -459,6 +643,7 @@ mod tests {
             file_pattern: "**/*.rs".to_string(),
             content_pattern: "[invalid_regex".to_string(), // Invalid regex
             category: "test".to_string(),
+            structure: None,
         }];
 
         let matcher = PatternMatcher::new(&patterns);
@@ -472,6 +657,7 @@ mod tests {
             file_pattern: "[".to_string(), // Invalid glob
             content_pattern: "test".to_string(),
             category: "test".to_string(),
+            structure: None,
         }];
 
         let matcher = PatternMatcher::new(&patterns);
@@ -486,12 +672,14 @@ mod tests {
                 file_pattern: "**/*.rs".to_string(),
                 content_pattern: "fn".to_string(),
                 category: "rust".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "JSON Pattern".to_string(),
                 file_pattern: "**/*.json".to_string(),
                 content_pattern: r#""name""#.to_string(),
                 category: "config".to_string(),
+                structure: None,
             },
         ];
 
@@ -522,12 +710,14 @@ mod tests {
                 file_pattern: "**/*.rs".to_string(),
                 content_pattern: r"fn\s+\w+".to_string(),
                 category: "rust".to_string(),
+                structure: None,
             },
             DetectionPattern {
                 name: "Use Statement".to_string(),
                 file_pattern: "**/*.rs".to_string(),
                 content_pattern: r"use\s+\w+".to_string(),
                 category: "rust".to_string(),
+                structure: None,
             },
         ];
 
@@ -582,6 +772,7 @@ fn helper_function() -> i32 {
             file_pattern: "**/*.rs".to_string(),
             content_pattern: "test".to_string(),
             category: "test".to_string(),
+            structure: None,
         }];
 
         let matcher = PatternMatcher::new(&patterns).unwrap();
@@ -615,6 +806,50 @@ fn helper_function() -> i32 {
         assert!(dep_confidence >= normal_confidence);
     }
 
+    #[cfg(feature = "ast")]
+    #[test]
+    fn test_structural_matching() {
+        let patterns = vec![DetectionPattern {
+            name: "Reqwest Client".to_string(),
+            file_pattern: "**/*".to_string(),
+            content_pattern: r"reqwest::Client::new".to_string(),
+            category: "http".to_string(),
+            structure: Some(crate::config::StructuralQuery {
+                call: Some("reqwest::Client::new".to_string()),
+                annotation: None,
+                query: None,
+            }),
+        }];
+        let matcher = PatternMatcher::new(&patterns).unwrap();
+
+        let rust_content = r#"// reqwest::Client::new() is created once
+fn client() -> reqwest::Client {
+    reqwest::Client
+        ::new()
+}
+"#;
+        let matches = matcher
+            .find_matches(Path::new("src/http.rs"), rust_content)
+            .unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].line_number, 3);
+        assert_eq!(matches[0].confidence, 1.0);
+
+        // Files without a grammar fall back to the content pattern
+        let matches = matcher
+            .find_matches(Path::new("docs/http.md"), "Call reqwest::Client::new()")
+            .unwrap();
+        assert_eq!(matches.len(), 1);
+
+        let mut invalid = patterns[0].clone();
+        invalid.structure = Some(crate::config::StructuralQuery {
+            call: None,
+            annotation: None,
+            query: Some("(no_such_node) @match".to_string()),
+        });
+        assert!(PatternMatcher::new(&[invalid]).is_err());
+    }
+
     #[test]
     fn test_technology_match_properties() {
         let tech_match = TechnologyMatch {
@@ -623,12 +858,14 @@ fn helper_function() -> i32 {
                 file_pattern: "**/*.rs".to_string(),
                 content_pattern: "test".to_string(),
                 category: "test".to_string(),
+                structure: None,
             },
             file_path: "src/test.rs".to_string(),
             line_number: 10,
             matched_text: "test".to_string(),
             context: "let test = 42;".to_string(),
             confidence: 0.85,
+            dependency: None,
         };
 
         assert!(!tech_match.is_likely_comment());
@@ -647,6 +884,7 @@ fn helper_function() -> i32 {
             matched_text: "test".to_string(),
             context: "// This is a test comment".to_string(),
             confidence: 0.6,
+            dependency: None,
         };
 
         assert!(comment_match.is_likely_comment());
@@ -667,7 +905,11 @@ tokio = { version = "1.0", features = ["full"] }
 axum = "0.6"
 serde = { version = "1.0", features = ["derive"] }
 serde_json = "1.0"
-diesel = { version = "2.0", features = ["postgres"] }
+tokio-postgres = "0.7"
+# rocket = "0.5" was replaced by axum
+diesel = { version = "2.0", features = ["mysql"] }
+
+[dev-dependencies]
 redis = "0.23"
 "#;
 
@@ -681,6 +923,36 @@ redis = "0.23"
         assert!(tech_names.iter().any(|name| name.contains("Axum")));
         assert!(tech_names.iter().any(|name| name.contains("PostgreSQL")));
         assert!(tech_names.iter().any(|name| name.contains("Redis")));
+
+        // Comments and feature lists are not dependencies
+        assert!(!tech_names.iter().any(|name| name.contains("Rocket")));
+        assert!(!tech_names.iter().any(|name| name.contains("MySQL")));
+
+        let redis = matches
+            .iter()
+            .find(|m| m.pattern.name.contains("Redis"))
+            .unwrap();
+        let dependency = redis.dependency.as_ref().unwrap();
+        assert_eq!(dependency.version.as_deref(), Some("0.23"));
+        assert_eq!(dependency.kind, DependencyKind::Dev);
+        assert_eq!(redis.line_number, 16);
+        assert_eq!(redis.context, r#"redis = "0.23""#);
+
+        // Patterns written against manifest lines are still matched by line
+        let line_pattern = DetectionPattern {
+            name: "Serde".to_string(),
+            file_pattern: "**/Cargo.toml".to_string(),
+            content_pattern: r#"^serde\s*="#.to_string(),
+            category: "library".to_string(),
+            structure: None,
+        };
+        let matcher = PatternMatcher::new(&[line_pattern]).unwrap();
+        let matches = matcher.find_matches(cargo_file, cargo_content).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].line_number, 9);
+        assert!(matches[0].dependency.is_none());
+        assert!(!is_line_pattern(r#"^(?P<name>tokio|axum)$"#));
+        assert!(is_line_pattern(r#""react"\s*:"#));
     }
 
     #[test]
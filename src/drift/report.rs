@@ -71,7 +71,7 @@ pub struct DriftItem {
 }
 
 /// Severity levels for drift items
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 pub enum DriftSeverity {
     /// Critical drift that likely violates architectural decisions
     Critical,
@@ -110,6 +110,8 @@ pub enum DriftCategory {
     Framework,
     /// Configuration drift
     Configuration,
+    /// Dependency version outside an ADR constraint or a major version change
+    VersionDrift,
     /// Other/uncategorized drift
     Other,
 }
@@ -181,6 +183,20 @@ impl DriftReport {
         self.total_items = self.items.len();
     }
 
+    /// Remove items with the same id as an earlier item, such as the same
+    /// technology matched by the patterns of two ADR roots
+    pub fn dedup_items(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        let items = std::mem::take(&mut self.items);
+        self.category_summary.clear();
+        self.severity_summary.clear();
+        for item in items {
+            if seen.insert(item.id.clone()) {
+                self.add_item(item);
+            }
+        }
+    }
+
     /// Get items by severity level
     pub fn items_by_severity(&self, severity: &DriftSeverity) -> Vec<&DriftItem> {
         self.items
@@ -318,6 +334,23 @@ impl DriftLocation {
     }
 }
 
+impl DriftSeverity {
+    /// Whether this severity is `threshold` or more severe
+    pub fn is_at_least(&self, threshold: &DriftSeverity) -> bool {
+        self.rank() >= threshold.rank()
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            DriftSeverity::Critical => 4,
+            DriftSeverity::High => 3,
+            DriftSeverity::Medium => 2,
+            DriftSeverity::Low => 1,
+            DriftSeverity::Info => 0,
+        }
+    }
+}
+
 impl std::fmt::Display for DriftSeverity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -344,6 +377,7 @@ impl std::fmt::Display for DriftCategory {
             DriftCategory::Infrastructure => write!(f, "Infrastructure"),
             DriftCategory::Framework => write!(f, "Framework"),
             DriftCategory::Configuration => write!(f, "Configuration"),
+            DriftCategory::VersionDrift => write!(f, "Version Drift"),
             DriftCategory::Other => write!(f, "Other"),
         }
     }
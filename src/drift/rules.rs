@@ -0,0 +1,217 @@
+//! Declarative Architecture Rules
+//!
+//! An ADR can state which technologies it allows and forbids in an
+//! `enforces` frontmatter entry, optionally limited to part of the tree, and
+//! declare layering rules in the same form as the `drift.layers` config:
+//!
+//! ```yaml
+//! enforces:
+//!   allow: [postgres]
+//!   forbid: [mysql, mongodb]
+//!   scope: "services/**"
+//!   layers:
+//!     - name: domain
+//!       paths: ["services/*/src/domain/**"]
+//!       must_not_import: [infrastructure]
+//! ```
+//!
+//! Unlike the wording of an ADR's body, these rules are authoritative: the
+//! detector reports every forbidden technology found in scope and does not
+//! guess whether the ADR accepts or rejects what it mentions.
+
+use serde::Deserialize;
+
+use crate::config::LayerRule;
+use crate::drift::DriftResult;
+use crate::error::AdrscanError;
+use crate::parser::AdrDocument;
+
+/// Frontmatter field holding an ADR's rules
+pub const ENFORCES_FIELD: &str = "enforces";
+
+/// Technologies an ADR allows and forbids, and the layering it enforces
+#[derive(Debug, Clone, Default)]
+pub struct AdrRules {
+    /// Technologies the ADR accepts
+    pub allow: Vec<String>,
+
+    /// Technologies that must not be used
+    pub forbid: Vec<String>,
+
+    /// Globs the rules apply to, relative to the scanned directory; empty
+    /// means everywhere
+    pub scope: Vec<String>,
+
+    /// Layering rules, checked by [`LayerAnalyzer`](super::layering::LayerAnalyzer)
+    pub layers: Vec<LayerRule>,
+
+    /// Compiled `scope` globs
+    patterns: Vec<glob::Pattern>,
+}
+
+/// How `enforces` is written in frontmatter
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesRepr {
+    #[serde(default)]
+    allow: Vec<String>,
+    #[serde(default)]
+    forbid: Vec<String>,
+    #[serde(default)]
+    scope: Option<ScopeRepr>,
+    #[serde(default)]
+    layers: Vec<LayerRule>,
+}
+
+/// A single glob or a list of globs
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScopeRepr {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl AdrRules {
+    /// Read the `enforces` entry of an ADR, if it has one
+    pub fn from_document(document: &AdrDocument) -> DriftResult<Option<Self>> {
+        let Some(value) = document.metadata.custom_fields.get(ENFORCES_FIELD) else {
+            return Ok(None);
+        };
+        let invalid = |message: String| {
+            AdrscanError::ParseError(format!(
+                "Invalid '{ENFORCES_FIELD}' in {}: {message}",
+                document.path.display()
+            ))
+        };
+
+        let repr: RulesRepr =
+            serde_yaml::from_value(value.clone()).map_err(|e| invalid(e.to_string()))?;
+        let scope = match repr.scope {
+            None => Vec::new(),
+            Some(ScopeRepr::One(glob)) => vec![glob],
+            Some(ScopeRepr::Many(globs)) => globs,
+        };
+        let patterns = scope
+            .iter()
+            .map(|glob| {
+                glob::Pattern::new(glob).map_err(|e| invalid(format!("scope '{glob}': {e}")))
+            })
+            .collect::<DriftResult<Vec<_>>>()?;
+        for layer in &repr.layers {
+            for glob in &layer.paths {
+                glob::Pattern::new(glob).map_err(|e| {
+                    invalid(format!("path '{glob}' of layer '{}': {e}", layer.name))
+                })?;
+            }
+        }
+
+        Ok(Some(Self {
+            allow: repr.allow,
+            forbid: repr.forbid,
+            scope,
+            layers: repr.layers,
+            patterns,
+        }))
+    }
+
+    /// Whether the rules apply to a path relative to the scanned directory
+    pub fn in_scope(&self, file_path: &str) -> bool {
+        self.patterns.is_empty() || self.patterns.iter().any(|p| p.matches(file_path))
+    }
+
+    /// Whether the technology is on the allow list
+    pub fn allows(&self, technology: &str, package: Option<&str>) -> bool {
+        self.allow
+            .iter()
+            .any(|name| names_technology(name, technology, package))
+    }
+
+    /// The forbid list entry naming the technology, if any
+    pub fn forbidding(&self, technology: &str, package: Option<&str>) -> Option<&str> {
+        self.forbid
+            .iter()
+            .find(|name| names_technology(name, technology, package))
+            .map(String::as_str)
+    }
+
+    /// Technologies the rules name, allowed or forbidden
+    pub fn technologies(&self) -> impl Iterator<Item = &String> {
+        self.allow.iter().chain(&self.forbid)
+    }
+}
+
+/// Whether a name used in an ADR refers to a detected technology
+///
+/// The name matches when its words appear in sequence in the technology's
+/// name or in the package it was detected from, ignoring case and
+/// punctuation. So `postgres` matches the `tokio-postgres` package and
+/// `mongodb` matches "MongoDB Database", but `go` does not match "Google
+/// Cloud Provider".
+pub fn names_technology(name: &str, technology: &str, package: Option<&str>) -> bool {
+    let name = words(name);
+    if name.is_empty() {
+        return false;
+    }
+    std::iter::once(technology)
+        .chain(package)
+        .any(|candidate| words(candidate).windows(name.len()).any(|w| w == name))
+}
+
+/// Lowercase alphanumeric words of a name
+fn words(name: &str) -> Vec<String> {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::AdrParser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rules_from_frontmatter() {
+        let content = r#"---
+title: Use PostgreSQL in services
+status: accepted
+enforces:
+  allow: [postgres]
+  forbid: [mysql, mongodb]
+  scope: "services/**"
+---
+
+# Use PostgreSQL in services
+"#;
+        let document = AdrParser::parse_content(content, PathBuf::from("0002.md")).unwrap();
+        let rules = AdrRules::from_document(&document).unwrap().unwrap();
+
+        assert_eq!(rules.scope, vec!["services/**"]);
+        assert!(rules.in_scope("services/billing/Cargo.toml"));
+        assert!(!rules.in_scope("tools/Cargo.toml"));
+        assert!(rules.allows("PostgreSQL Database", Some("tokio-postgres")));
+        assert_eq!(
+            rules.forbidding("MongoDB Database", Some("mongodb")),
+            Some("mongodb")
+        );
+        assert_eq!(rules.forbidding("Redis Cache", Some("redis")), None);
+
+        let typo = content.replace("forbid:", "forbidden:");
+        let document = AdrParser::parse_content(&typo, PathBuf::from("0002.md")).unwrap();
+        assert!(AdrRules::from_document(&document).is_err());
+    }
+
+    #[test]
+    fn test_names_technology() {
+        assert!(names_technology("Spring Boot", "Spring Boot", None));
+        assert!(names_technology(
+            "spring",
+            "Spring Framework",
+            Some("org.springframework:spring-core")
+        ));
+        assert!(names_technology("vue.js", "Vue.js Framework", None));
+        assert!(!names_technology("go", "Google Cloud Provider", None));
+        assert!(!names_technology("", "Redis Cache", None));
+    }
+}
@@ -6,22 +6,65 @@
 use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
-use walkdir::WalkDir;
 
 use crate::config::DetectionPattern;
-use crate::drift::{DriftResult, PatternMatcher, Snapshot, SnapshotEntryType};
+use crate::drift::{manifests, DriftResult, PatternMatcher, Snapshot, SnapshotEntryType};
 use crate::error::AdrscanError;
 
+/// Ignore file for paths that are tracked but should not be scanned, with
+/// the same syntax as `.gitignore`
+pub const IGNORE_FILE_NAME: &str = ".adrscanignore";
+
+/// Snapshot metadata key for the fingerprint of the scan settings; entries
+/// of a baseline are only reused by a scan with the same fingerprint
+const FINGERPRINT_KEY: &str = "scan_fingerprint";
+
+/// Snapshot metadata key for the commit an incremental scan started from
+const INCREMENTAL_BASE_KEY: &str = "incremental_base";
+
+/// Snapshot metadata key set when tracked files had uncommitted changes at
+/// scan time, so that the entries do not describe the recorded commit
+const DIRTY_KEY: &str = "dirty_worktree";
+
+/// Version control directories, never scanned
+const VCS_DIRS: &[&str] = &[".git", ".hg", ".svn"];
+
+/// Directories of build output, dependencies and tooling, skipped only when
+/// the scanned directory is neither in a git repository nor has an ignore
+/// file to say what to leave out
+const FALLBACK_EXCLUDE_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "build",
+    "dist",
+    ".cargo",
+    ".rustup",
+    "__pycache__",
+    ".pytest_cache",
+    ".venv",
+    "venv",
+    ".idea",
+    ".vscode",
+    "tmp",
+    "temp",
+    ".next",
+    ".nuxt",
+];
+
 /// Scanner for analyzing codebases and detecting technologies
 pub struct CodebaseScanner {
     /// File extensions to include in scanning
     include_extensions: HashSet<String>,
 
-    /// Directories to exclude from scanning
-    exclude_dirs: HashSet<String>,
+    /// Glob patterns of files to scan, relative to the scanned directory;
+    /// every file is scanned if empty
+    include_patterns: Vec<glob::Pattern>,
+
+    /// Glob patterns of files to leave out, relative to the scanned directory
+    exclude_patterns: Vec<glob::Pattern>,
 
     /// Maximum file size to scan (in bytes)
     max_file_size: u64,
@@ -76,46 +119,30 @@ impl CodebaseScanner {
             .iter()
             .map(|s| s.to_string())
             .collect(),
-            exclude_dirs: [
-                "target",
-                "node_modules",
-                ".git",
-                "build",
-                "dist",
-                ".cargo",
-                ".rustup",
-                "__pycache__",
-                ".pytest_cache",
-                ".venv",
-                "venv",
-                ".idea",
-                ".vscode",
-                "tmp",
-                "temp",
-                ".next",
-                ".nuxt",
-            ]
-            .iter()
-            .map(|s| s.to_string())
-            .collect(),
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
             max_file_size: 10 * 1024 * 1024, // 10MB
             parallel_processing: true,
             max_threads: 4,
         }
     }
 
-    /// Configure the scanner with custom settings
-    #[allow(dead_code)] // Planned for scanner configuration
-    pub fn with_config(
-        mut self,
-        include_extensions: Vec<String>,
-        exclude_dirs: Vec<String>,
-        max_file_size: u64,
-    ) -> Self {
-        self.include_extensions = include_extensions.into_iter().collect();
-        self.exclude_dirs = exclude_dirs.into_iter().collect();
-        self.max_file_size = max_file_size;
-        self
+    /// Only scan files matching one of `include` and none of `exclude`, like
+    /// the config's `include_patterns` and `exclude_patterns`
+    pub fn with_patterns(mut self, include: &[String], exclude: &[String]) -> DriftResult<Self> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|pattern| {
+                    glob::Pattern::new(pattern).map_err(|e| {
+                        AdrscanError::ConfigError(format!("Invalid scan pattern '{pattern}': {e}"))
+                    })
+                })
+                .collect::<DriftResult<Vec<_>>>()
+        };
+        self.include_patterns = compile(include)?;
+        self.exclude_patterns = compile(exclude)?;
+        Ok(self)
     }
 
     /// Enable or disable parallel processing
@@ -132,46 +159,85 @@ impl CodebaseScanner {
         &self,
         root_path: &Path,
         detection_patterns: &[DetectionPattern],
+    ) -> DriftResult<Snapshot> {
+        self.scan(root_path, detection_patterns, None).await
+    }
+
+    /// Scan only the files git reports as changed or added since the commit
+    /// of `baseline`, and take the entries of all other files from it.
+    /// Falls back to a full scan if the baseline has no commit, was made with
+    /// different settings or uncommitted changes, or git cannot compare
+    /// against it.
+    pub async fn scan_incremental(
+        &self,
+        root_path: &Path,
+        detection_patterns: &[DetectionPattern],
+        baseline: &Snapshot,
+    ) -> DriftResult<Snapshot> {
+        self.scan(root_path, detection_patterns, Some(baseline))
+            .await
+    }
+
+    async fn scan(
+        &self,
+        root_path: &Path,
+        detection_patterns: &[DetectionPattern],
+        baseline: Option<&Snapshot>,
     ) -> DriftResult<Snapshot> {
         let start_time = Instant::now();
         log::info!("Starting codebase scan of: {}", root_path.display());
 
-        let mut snapshot = Snapshot::new(root_path.to_path_buf());
+        let fingerprint = self.fingerprint(detection_patterns);
+        let mut snapshot = Snapshot::new(root_path.to_path_buf())
+            .with_metadata(FINGERPRINT_KEY.to_string(), fingerprint.clone());
 
         // Add git information if available
         if let Ok((commit, branch)) = self.get_git_info(root_path).await {
             snapshot = snapshot.with_git_info(commit, branch);
         }
+        if snapshot.git_commit.is_some() && !self.is_clean(root_path).await {
+            snapshot
+                .metadata
+                .insert(DIRTY_KEY.to_string(), "true".to_string());
+        }
 
         // Create pattern matcher
         let pattern_matcher = PatternMatcher::new(detection_patterns)?;
 
         // Collect all valid files first for parallel processing
-        let files_to_process: Vec<_> = WalkDir::new(root_path)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-            .filter(|entry| {
-                let file_path = entry.path();
-                // Skip if in excluded directory
-                if self.is_excluded_path(file_path, root_path) {
-                    return false;
-                }
-                // Skip if file is too large
-                if let Ok(metadata) = entry.metadata() {
-                    if metadata.len() > self.max_file_size {
-                        log::debug!(
-                            "Skipping large file: {} ({} bytes)",
-                            file_path.display(),
-                            metadata.len()
-                        );
-                        return false;
+        let mut files_to_process = self.collect_files(root_path);
+
+        // Files whose entries are taken from the baseline
+        let mut unchanged = HashSet::new();
+        if let Some(baseline) = baseline {
+            if let Some((commit, changed)) = self
+                .changed_since_baseline(root_path, baseline, &fingerprint)
+                .await
+            {
+                let known: HashSet<&str> = baseline
+                    .entries
+                    .iter()
+                    .map(|entry| entry.file_path.as_str())
+                    .collect();
+                files_to_process.retain(|file_path| {
+                    let relative_path = relative_path(file_path, root_path);
+                    if changed.contains(&relative_path) || !known.contains(relative_path.as_str()) {
+                        return true;
                     }
-                }
-                true
-            })
-            .collect();
+                    unchanged.insert(relative_path);
+                    false
+                });
+                log::info!(
+                    "Incremental scan since {}: {} files changed, {} unchanged",
+                    commit,
+                    files_to_process.len(),
+                    unchanged.len()
+                );
+                snapshot
+                    .metadata
+                    .insert(INCREMENTAL_BASE_KEY.to_string(), commit.to_string());
+            }
+        }
 
         let total_files = files_to_process.len();
         log::info!("Found {} files to process", total_files);
@@ -181,9 +247,7 @@ impl CodebaseScanner {
             rayon::ThreadPoolBuilder::new()
                 .num_threads(self.max_threads)
                 .build_global()
-                .map_err(|e| {
-                    AdrscanError::DriftError(format!("Failed to setup thread pool: {}", e))
-                })?;
+                .unwrap_or_else(|e| log::debug!("Keeping the existing thread pool: {}", e));
         }
 
         // Process files in parallel or sequentially
@@ -195,14 +259,25 @@ impl CodebaseScanner {
         // Simplified sequential processing for now
         let file_results: Result<Vec<_>, AdrscanError> = files_to_process
             .iter()
-            .map(|entry| {
-                // Basic file processing without advanced parallel features
-                Ok(())
+            .map(|file_path| {
+                self.process_file_entry(
+                    file_path,
+                    root_path,
+                    &pattern_matcher,
+                    &snapshot_mutex,
+                    &files_processed,
+                    &lines_analyzed,
+                )
             })
             .collect();
 
         // Check for any processing errors
         file_results?;
+        drop(snapshot_mutex);
+
+        if let Some(baseline) = baseline.filter(|_| !unchanged.is_empty()) {
+            snapshot.merge_unchanged(baseline, &unchanged);
+        }
 
         let final_files_processed = *files_processed.lock().unwrap();
         let final_lines_analyzed = *lines_analyzed.lock().unwrap();
@@ -222,24 +297,77 @@ impl CodebaseScanner {
         Ok(snapshot)
     }
 
+    /// Files to scan below `root_path`, leaving out what `.gitignore` files,
+    /// the global git excludes, `.git/info/exclude` and `.adrscanignore`
+    /// files ignore, and what the include and exclude patterns filter out
+    pub(crate) fn collect_files(&self, root_path: &Path) -> Vec<PathBuf> {
+        let mut exclude_dirs: HashSet<&str> = VCS_DIRS.iter().copied().collect();
+        if !has_ignore_rules(root_path) {
+            exclude_dirs.extend(FALLBACK_EXCLUDE_DIRS);
+        }
+        ignore::WalkBuilder::new(root_path)
+            .follow_links(false)
+            // Dotfiles such as CI workflows are part of the codebase
+            .hidden(false)
+            // Ignore files apply even outside of a git repository
+            .require_git(false)
+            .add_custom_ignore_filename(IGNORE_FILE_NAME)
+            .filter_entry(move |entry| {
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                !(is_dir
+                    && entry
+                        .file_name()
+                        .to_str()
+                        .is_some_and(|name| exclude_dirs.contains(name)))
+            })
+            .build()
+            .filter_map(|entry| match entry {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    log::warn!("Skipping unreadable path while scanning: {}", e);
+                    None
+                }
+            })
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .filter(|entry| {
+                let file_path = entry.path();
+                // Skip if excluded by the patterns
+                if self.is_excluded_path(file_path, root_path) {
+                    return false;
+                }
+                // Skip if file is too large
+                if let Ok(metadata) = entry.metadata() {
+                    if metadata.len() > self.max_file_size {
+                        log::debug!(
+                            "Skipping large file: {} ({} bytes)",
+                            file_path.display(),
+                            metadata.len()
+                        );
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+
     /// Process a single file entry for parallel or sequential processing
+    #[allow(clippy::too_many_arguments)]
     fn process_file_entry(
         &self,
-        entry: &walkdir::DirEntry,
+        file_path: &Path,
         root_path: &Path,
         pattern_matcher: &Arc<PatternMatcher>,
         snapshot_mutex: &Arc<Mutex<&mut Snapshot>>,
         files_processed: &Arc<Mutex<usize>>,
         lines_analyzed: &Arc<Mutex<usize>>,
     ) -> Result<(), AdrscanError> {
-        let file_path = entry.path();
-
         // Get relative path
-        let relative_path = file_path
-            .strip_prefix(root_path)
-            .map_err(|_| AdrscanError::DriftError("Invalid file path".to_string()))?
-            .to_string_lossy()
-            .to_string();
+        if !file_path.starts_with(root_path) {
+            return Err(AdrscanError::DriftError("Invalid file path".to_string()));
+        }
+        let relative_path = relative_path(file_path, root_path);
 
         // Determine file type
         let entry_type = self.classify_file(file_path);
@@ -251,7 +379,10 @@ impl CodebaseScanner {
                     let line_count = content.lines().count();
 
                     // Find technology matches
-                    let tech_matches = pattern_matcher.find_matches(file_path, &content)?;
+                    // Matches are recorded relative to the root, so that
+                    // snapshots from other checkouts can be compared
+                    let tech_matches =
+                        pattern_matcher.find_matches(Path::new(&relative_path), &content)?;
 
                     // Lock snapshot and update it
                     {
@@ -328,21 +459,29 @@ impl CodebaseScanner {
             Err(_) => return true,
         };
 
-        for component in relative_path.components() {
-            if let std::path::Component::Normal(name) = component {
-                if let Some(name_str) = name.to_str() {
-                    if self.exclude_dirs.contains(name_str) {
-                        return true;
-                    }
-                }
-            }
+        let relative_path = relative_path.to_string_lossy().replace('\\', "/");
+
+        if self
+            .exclude_patterns
+            .iter()
+            .any(|pattern| pattern.matches(&relative_path))
+        {
+            return true;
         }
 
-        false
+        !self.include_patterns.is_empty()
+            && !self
+                .include_patterns
+                .iter()
+                .any(|pattern| pattern.matches(&relative_path))
     }
 
     /// Check if a file should be analyzed for content
     fn should_analyze_file(&self, file_path: &Path) -> bool {
+        if manifests::is_manifest(file_path) {
+            return true;
+        }
+
         if let Some(extension) = file_path.extension() {
             if let Some(ext_str) = extension.to_str() {
                 return self.include_extensions.contains(&ext_str.to_lowercase());
@@ -397,7 +536,8 @@ impl CodebaseScanner {
             "makefile" | "rakefile" | "build.gradle" | "pom.xml" => {
                 return SnapshotEntryType::Build
             }
-            "cargo.toml" | "package.json" | "requirements.txt" | "composer.json" => {
+            "cargo.toml" | "cargo.lock" | "package.json" | "package-lock.json" | "yarn.lock"
+            | "requirements.txt" | "pyproject.toml" | "go.mod" | "composer.json" => {
                 return SnapshotEntryType::Configuration
             }
             name if name.ends_with(".md") || name.ends_with(".rst") || name.ends_with(".txt") => {
@@ -447,6 +587,98 @@ impl CodebaseScanner {
             .map(|duration| DateTime::<Utc>::from_timestamp(duration.as_secs() as i64, 0).unwrap())
     }
 
+    /// Hash of everything that decides which entries a file produces,
+    /// including the adrscan version and whether structural analysis is built
+    /// in, since both change what the analyzers extract
+    fn fingerprint(&self, detection_patterns: &[DetectionPattern]) -> String {
+        let mut extensions: Vec<&String> = self.include_extensions.iter().collect();
+        extensions.sort();
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(if cfg!(feature = "ast") { "ast" } else { "text" });
+        hasher.update(serde_json::to_string(detection_patterns).unwrap_or_default());
+        for pattern in self.include_patterns.iter().chain(&self.exclude_patterns) {
+            hasher.update(pattern.as_str());
+        }
+        hasher.update(format!("{:?}{}", extensions, self.max_file_size));
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// The baseline commit and the files changed or added since it, relative
+    /// to `root_path`, if the baseline can be used for an incremental scan
+    async fn changed_since_baseline<'a>(
+        &self,
+        root_path: &Path,
+        baseline: &'a Snapshot,
+        fingerprint: &str,
+    ) -> Option<(&'a str, HashSet<String>)> {
+        let Some(ref commit) = baseline.git_commit else {
+            log::info!("Baseline snapshot has no git commit, scanning all files");
+            return None;
+        };
+        if baseline.metadata.get(FINGERPRINT_KEY).map(String::as_str) != Some(fingerprint) {
+            log::info!("Baseline snapshot was made with other scan settings, scanning all files");
+            return None;
+        }
+        if baseline.metadata.contains_key(DIRTY_KEY) {
+            log::info!(
+                "Baseline snapshot was made with uncommitted changes to {}, scanning all files",
+                commit
+            );
+            return None;
+        }
+
+        // Working tree against the commit, covering staged and unstaged
+        // changes, plus untracked files that are not ignored
+        let changed = git_output(
+            root_path,
+            &[
+                "diff",
+                "--name-only",
+                "-z",
+                "--no-renames",
+                "--relative",
+                commit.as_str(),
+                "--",
+            ],
+        )
+        .await;
+        let untracked = git_output(
+            root_path,
+            &["ls-files", "--others", "--exclude-standard", "-z"],
+        )
+        .await;
+        match (changed, untracked) {
+            (Some(changed), Some(untracked)) => Some((
+                commit.as_str(),
+                changed
+                    .split('\0')
+                    .chain(untracked.split('\0'))
+                    .filter(|path| !path.is_empty())
+                    .map(String::from)
+                    .collect(),
+            )),
+            _ => {
+                log::warn!(
+                    "Cannot compare with baseline commit {} using git, scanning all files",
+                    commit
+                );
+                None
+            }
+        }
+    }
+
+    /// Whether no tracked file below `root_path` differs from `HEAD`;
+    /// untracked files are rescanned anyway. False if git cannot tell.
+    async fn is_clean(&self, root_path: &Path) -> bool {
+        git_output(
+            root_path,
+            &["status", "--porcelain", "--untracked-files=no", "--", "."],
+        )
+        .await
+        .is_some_and(|status| status.trim().is_empty())
+    }
+
     /// Get git information for the repository
     async fn get_git_info(
         &self,
@@ -504,8 +736,213 @@ impl CodebaseScanner {
     }
 }
 
+/// Whether `root_path` is in a git repository or has an ignore file, in
+/// itself or a parent directory
+fn has_ignore_rules(root_path: &Path) -> bool {
+    root_path.ancestors().any(|dir| {
+        dir.join(".git").exists()
+            || dir.join(".gitignore").is_file()
+            || dir.join(IGNORE_FILE_NAME).is_file()
+    })
+}
+
+/// `file_path` relative to `root_path`, with `/` separators
+fn relative_path(file_path: &Path, root_path: &Path) -> String {
+    file_path
+        .strip_prefix(root_path)
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Standard output of a successful git command run in `dir`
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+async fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = tokio::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        log::debug!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(any(not(feature = "tokio"), target_arch = "wasm32"))]
+async fn git_output(_dir: &Path, _args: &[&str]) -> Option<String> {
+    None
+}
+
 impl Default for CodebaseScanner {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_files_respects_ignore_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let files = [
+            ".gitignore",
+            ".github/workflows/ci.yml",
+            ".git/config.yml",
+            ".venv/lib/site.py",
+            "node_modules/react/index.js",
+            "src/main.rs",
+            "src/local.rs",
+            "src/.gitignore",
+            "src/gen/api.rs",
+            "server.log",
+            "fixtures/sample.rs",
+            "vendor/lib.rs",
+            "README.md",
+            ".adrscanignore",
+        ];
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        fs::write(root.join(".gitignore"), "*.log\ngen/\n").unwrap();
+        fs::write(root.join("src/.gitignore"), "local.rs\n").unwrap();
+        fs::write(root.join(".adrscanignore"), "fixtures/\n").unwrap();
+
+        let scanned = |scanner: &CodebaseScanner| {
+            let mut files: Vec<String> = scanner
+                .collect_files(root)
+                .iter()
+                .map(|path| {
+                    let relative = path.strip_prefix(root).unwrap();
+                    relative.to_string_lossy().replace('\\', "/")
+                })
+                .collect();
+            files.sort();
+            files
+        };
+
+        // The ignore files decide; only version control directories are
+        // always left out
+        let scanner = CodebaseScanner::new();
+        assert_eq!(
+            scanned(&scanner),
+            [
+                ".adrscanignore",
+                ".github/workflows/ci.yml",
+                ".gitignore",
+                ".venv/lib/site.py",
+                "README.md",
+                "node_modules/react/index.js",
+                "src/.gitignore",
+                "src/main.rs",
+                "vendor/lib.rs",
+            ]
+        );
+
+        let scanner = CodebaseScanner::new()
+            .with_patterns(&["**/*.rs".to_string()], &["vendor/**".to_string()])
+            .unwrap();
+        assert_eq!(scanned(&scanner), ["src/main.rs"]);
+
+        assert!(CodebaseScanner::new()
+            .with_patterns(&["[".to_string()], &[])
+            .is_err());
+
+        // Outside of a repository and without ignore files, build output
+        // and dependencies are skipped
+        for file in [".gitignore", "src/.gitignore", ".adrscanignore"] {
+            fs::remove_file(root.join(file)).unwrap();
+        }
+        fs::remove_dir_all(root.join(".git")).unwrap();
+        assert!(!scanned(&CodebaseScanner::new())
+            .iter()
+            .any(|file| file.starts_with(".venv/") || file.starts_with("node_modules/")));
+    }
+
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    #[tokio::test]
+    async fn test_scan_incremental() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(root)
+                .output()
+                .is_ok_and(|output| output.status.success())
+        };
+        if !git(&["init", "-q"]) {
+            // git is not installed
+            return;
+        }
+        fs::write(root.join("db.rs"), "use postgres::Client;\n").unwrap();
+        fs::write(root.join("old.rs"), "fn old() {}\n").unwrap();
+        fs::write(root.join("main.rs"), "fn main() {}\n").unwrap();
+        assert!(git(&["add", "-A"]));
+        assert!(git(&["commit", "-q", "-m", "baseline"]));
+
+        let patterns = [DetectionPattern {
+            name: "PostgreSQL".to_string(),
+            file_pattern: "**/*.rs".to_string(),
+            content_pattern: "postgres".to_string(),
+            category: "database".to_string(),
+            structure: None,
+        }];
+        let scanner = CodebaseScanner::new().with_parallel(false, None);
+        let baseline = scanner.scan_codebase(root, &patterns).await.unwrap();
+        assert!(baseline.git_commit.is_some());
+        assert!(!baseline.metadata.contains_key(DIRTY_KEY));
+
+        fs::remove_file(root.join("old.rs")).unwrap();
+        fs::write(root.join("main.rs"), "fn main() { redis(); }\n").unwrap();
+        fs::write(root.join("new.rs"), "use postgres::Row;\n").unwrap();
+
+        let current = scanner
+            .scan_incremental(root, &patterns, &baseline)
+            .await
+            .unwrap();
+        assert_eq!(
+            current.metadata.get(INCREMENTAL_BASE_KEY),
+            baseline.git_commit.as_ref()
+        );
+        let mut technologies: Vec<&str> = current
+            .technology_entries()
+            .iter()
+            .map(|entry| entry.file_path.as_str())
+            .collect();
+        technologies.sort();
+        assert_eq!(technologies, ["db.rs", "new.rs"]);
+        assert_eq!(current.statistics.files_scanned, 3);
+        assert!(!current.entries.iter().any(|e| e.file_path == "old.rs"));
+
+        // A baseline of uncommitted changes does not describe its commit:
+        // reverting main.rs would leave its entries stale
+        assert!(current.metadata.contains_key(DIRTY_KEY));
+        let full = scanner
+            .scan_incremental(root, &patterns, &current)
+            .await
+            .unwrap();
+        assert!(!full.metadata.contains_key(INCREMENTAL_BASE_KEY));
+
+        // Different patterns cannot reuse the baseline
+        let full = scanner
+            .scan_incremental(root, &patterns[..0], &baseline)
+            .await
+            .unwrap();
+        assert!(!full.metadata.contains_key(INCREMENTAL_BASE_KEY));
+    }
+}
@@ -7,7 +7,7 @@ use crate::drift::{DriftResult, TechnologyMatch};
 use crate::error::AdrscanError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 /// A snapshot of codebase state at a specific point in time
@@ -62,6 +62,10 @@ pub struct SnapshotEntry {
     /// The actual content that was matched
     pub matched_content: Option<String>,
 
+    /// Declared version of the dependency this entry was detected from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
     /// File hash for change detection
     pub file_hash: Option<String>,
 
@@ -141,7 +145,7 @@ impl Snapshot {
             tech_match.line_number
         );
 
-        let entry = SnapshotEntry {
+        let mut entry = SnapshotEntry {
             id,
             entry_type: SnapshotEntryType::Technology,
             file_path: tech_match.file_path.clone(),
@@ -149,11 +153,24 @@ impl Snapshot {
             category: tech_match.pattern.category.clone(),
             line_number: Some(tech_match.line_number),
             matched_content: Some(tech_match.matched_text.clone()),
+            version: tech_match
+                .dependency
+                .as_ref()
+                .and_then(|d| d.version.clone()),
             file_hash: None,
             file_size: None,
             modified_time: None,
             metadata: HashMap::new(),
         };
+        if let Some(ref dependency) = tech_match.dependency {
+            entry
+                .metadata
+                .insert("package".to_string(), dependency.name.clone());
+            entry.metadata.insert(
+                "dependency_kind".to_string(),
+                format!("{:?}", dependency.kind).to_lowercase(),
+            );
+        }
 
         self.entries.push(entry);
         self.update_statistics();
@@ -178,6 +195,7 @@ impl Snapshot {
             category: "file".to_string(),
             line_number: None,
             matched_content: None,
+            version: None,
             file_hash,
             file_size,
             modified_time,
@@ -221,6 +239,18 @@ impl Snapshot {
         self.statistics.technology_categories = tech_categories;
     }
 
+    /// Copy the entries of the files in `file_paths` from `baseline`
+    pub fn merge_unchanged(&mut self, baseline: &Snapshot, file_paths: &HashSet<String>) {
+        self.entries.extend(
+            baseline
+                .entries
+                .iter()
+                .filter(|entry| file_paths.contains(&entry.file_path))
+                .cloned(),
+        );
+        self.update_statistics();
+    }
+
     /// Set git information
     pub fn with_git_info(mut self, commit: Option<String>, branch: Option<String>) -> Self {
         self.git_commit = commit;
@@ -229,14 +259,12 @@ impl Snapshot {
     }
 
     /// Add metadata
-    #[allow(dead_code)] // Planned for snapshot metadata tracking
     pub fn with_metadata(mut self, key: String, value: String) -> Self {
         self.metadata.insert(key, value);
         self
     }
 
     /// Save snapshot to file
-    #[allow(dead_code)] // Planned for snapshot persistence
     pub fn save(&self, path: &Path) -> DriftResult<()> {
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
@@ -0,0 +1,381 @@
+//! Structural Pattern Matching
+//!
+//! This module matches detection patterns against tree-sitter syntax trees
+//! instead of lines of text, so that matches can span lines and never come
+//! from comments or string literals. Patterns select calls or annotations
+//! by path, or give a tree-sitter query for anything else.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, StreamingIterator};
+
+use crate::config::StructuralQuery;
+use crate::drift::{imports, DriftResult};
+use crate::error::AdrscanError;
+
+/// Grammars that structural queries are matched with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grammar {
+    Rust,
+    Java,
+    Python,
+    TypeScript,
+    Tsx,
+}
+
+impl Grammar {
+    const ALL: [Grammar; 5] = [
+        Grammar::Rust,
+        Grammar::Java,
+        Grammar::Python,
+        Grammar::TypeScript,
+        Grammar::Tsx,
+    ];
+
+    /// Grammar of a source file, by extension. JavaScript is parsed with
+    /// the TSX grammar, which is a superset of it.
+    fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Grammar::Rust),
+            "java" => Some(Grammar::Java),
+            "py" | "pyi" => Some(Grammar::Python),
+            "ts" | "mts" | "cts" => Some(Grammar::TypeScript),
+            "tsx" | "js" | "jsx" | "mjs" | "cjs" => Some(Grammar::Tsx),
+            _ => None,
+        }
+    }
+
+    fn language(self) -> Language {
+        match self {
+            Grammar::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Grammar::Java => tree_sitter_java::LANGUAGE.into(),
+            Grammar::Python => tree_sitter_python::LANGUAGE.into(),
+            Grammar::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Grammar::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+        }
+    }
+
+    /// Query selecting the nodes of calls and constructor invocations
+    fn calls(self) -> &'static str {
+        match self {
+            Grammar::Rust => "(call_expression) @match",
+            Grammar::Java => "[(method_invocation) (object_creation_expression)] @match",
+            Grammar::Python => "(call) @match",
+            Grammar::TypeScript | Grammar::Tsx => "[(call_expression) (new_expression)] @match",
+        }
+    }
+
+    /// Query selecting the nodes of annotations, decorators and attributes
+    fn annotations(self) -> &'static str {
+        match self {
+            Grammar::Rust => "[(attribute_item) (inner_attribute_item)] @match",
+            Grammar::Java => "[(marker_annotation) (annotation)] @match",
+            Grammar::Python | Grammar::TypeScript | Grammar::Tsx => "(decorator) @match",
+        }
+    }
+}
+
+/// How the nodes selected by a query are filtered
+#[derive(Debug, Clone)]
+enum Filter {
+    /// Calls whose callee path matches
+    Call(Vec<String>),
+    /// Annotations whose name matches
+    Annotation(Vec<String>),
+    /// Every node the query selects
+    All,
+}
+
+/// A structural query compiled for each grammar it is valid for
+pub struct StructuralMatcher {
+    queries: Vec<(Grammar, Query)>,
+    filter: Filter,
+}
+
+/// A node matched by a structural query
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuralMatch {
+    /// 1-based line where the node starts
+    pub line: usize,
+
+    /// First line of the node's text
+    pub text: String,
+}
+
+impl StructuralMatcher {
+    /// Compile a structural query
+    pub fn new(query: &StructuralQuery) -> DriftResult<Self> {
+        let invalid = |message: String| {
+            AdrscanError::DriftError(format!("Invalid structural query: {message}"))
+        };
+
+        let (filter, source): (Filter, Option<&str>) =
+            match (&query.call, &query.annotation, &query.query) {
+                (Some(call), None, None) => (Filter::Call(path_segments(call)), None),
+                (None, Some(annotation), None) => {
+                    let name = annotation.trim_start_matches(['@', '#']);
+                    (Filter::Annotation(path_segments(name)), None)
+                }
+                (None, None, Some(source)) => (Filter::All, Some(source.as_str())),
+                _ => {
+                    return Err(invalid(
+                        "set exactly one of 'call', 'annotation' and 'query'".to_string(),
+                    ))
+                }
+            };
+        if matches!(&filter, Filter::Call(p) | Filter::Annotation(p) if p.is_empty()) {
+            return Err(invalid("the path is empty".to_string()));
+        }
+
+        // A raw query is written for one language, so keep the grammars it
+        // compiles for and let the others fall back to the regex
+        let mut queries = Vec::new();
+        let mut last_error = None;
+        for grammar in Grammar::ALL {
+            let source = match filter {
+                Filter::Call(_) => grammar.calls(),
+                Filter::Annotation(_) => grammar.annotations(),
+                Filter::All => source.unwrap_or_default(),
+            };
+            match Query::new(&grammar.language(), source) {
+                Ok(query) => queries.push((grammar, query)),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        if queries.is_empty() {
+            let message = last_error.map(|e| e.to_string()).unwrap_or_default();
+            return Err(invalid(message));
+        }
+
+        Ok(Self { queries, filter })
+    }
+
+    /// Matches in a file, or `None` if its language has no compiled query
+    /// or it cannot be parsed
+    pub fn find(&self, path: &Path, content: &str) -> Option<Vec<StructuralMatch>> {
+        let grammar = Grammar::of(path)?;
+        let (_, query) = self.queries.iter().find(|(g, _)| *g == grammar)?;
+
+        let mut parser = Parser::new();
+        parser.set_language(&grammar.language()).ok()?;
+        let tree = parser.parse(content, None)?;
+
+        // Paths shortened by an import are resolved through the file's imports
+        let imported = match self.filter {
+            Filter::All => Vec::new(),
+            Filter::Call(_) | Filter::Annotation(_) => imported_names(path, content),
+        };
+
+        let match_index = query.capture_index_for_name("match");
+        let mut cursor = QueryCursor::new();
+        let mut query_matches = cursor.matches(query, tree.root_node(), content.as_bytes());
+
+        let mut seen = HashSet::new();
+        let mut found = Vec::new();
+        while let Some(query_match) = query_matches.next() {
+            let capture = query_match
+                .captures
+                .iter()
+                .find(|c| Some(c.index) == match_index)
+                .or_else(|| query_match.captures.first());
+            let Some(node) = capture.map(|c| c.node) else {
+                continue;
+            };
+            if !seen.insert(node.id()) || !self.accepts(node, content, &imported) {
+                continue;
+            }
+
+            let text = &content[node.byte_range()];
+            found.push(StructuralMatch {
+                line: node.start_position().row + 1,
+                text: text.lines().next().unwrap_or_default().trim().to_string(),
+            });
+        }
+        Some(found)
+    }
+
+    /// Whether a selected node passes the call or annotation filter
+    fn accepts(&self, node: Node, content: &str, imported: &[ImportedName]) -> bool {
+        match self.filter {
+            Filter::Call(ref wanted) => {
+                // The callee is everything before the argument list
+                let end = node
+                    .child_by_field_name("arguments")
+                    .map_or(node.end_byte(), |arguments| arguments.start_byte());
+                let callee = content[node.start_byte()..end].trim();
+                let mut found = match callee.strip_prefix("new") {
+                    Some(rest) if rest.starts_with(char::is_whitespace) => {
+                        let mut segments = path_segments(rest);
+                        segments.push("new".to_string());
+                        segments
+                    }
+                    _ => path_segments(callee),
+                };
+                found.retain(|s| !s.starts_with('<'));
+                path_matches(wanted, &found, imported)
+            }
+            Filter::Annotation(ref wanted) => {
+                let text = &content[node.byte_range()];
+                let name = text
+                    .trim_start_matches(['#', '!', '[', '@'])
+                    .split(['(', '=', ']'])
+                    .next()
+                    .unwrap_or_default();
+                path_matches(wanted, &path_segments(name), imported)
+            }
+            Filter::All => true,
+        }
+    }
+}
+
+/// A name bound by an import, with the path segments it stands for
+type ImportedName = (String, Vec<String>);
+
+/// Names bound by the imports of a file
+fn imported_names(path: &Path, content: &str) -> Vec<ImportedName> {
+    imports::extract(path, content)
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|import| import.bindings())
+        .map(|(name, path)| (name, path_segments(&path)))
+        .collect()
+}
+
+/// Whether a path found in the code refers to the wanted path
+///
+/// The found path may be longer (`crate::http::reqwest::Client::new`), or
+/// start with a name the file imports: `Client::new` after
+/// `use reqwest::Client` stands for `reqwest::Client::new`.
+fn path_matches(wanted: &[String], found: &[String], imported: &[ImportedName]) -> bool {
+    let ends_with = |path: &[String]| {
+        path.len() >= wanted.len() && path[path.len() - wanted.len()..] == *wanted
+    };
+    if ends_with(found) {
+        return true;
+    }
+
+    let Some((first, rest)) = found.split_first() else {
+        return false;
+    };
+    imported
+        .iter()
+        .filter(|(name, _)| name == first)
+        .any(|(_, path)| ends_with(&[path.as_slice(), rest].concat()))
+}
+
+/// Segments of a path written with `::`, `.` or `->` separators
+fn path_segments(path: &str) -> Vec<String> {
+    path.split(|c: char| c == ':' || c == '.' || c == '-' || c == '>' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(call: Option<&str>, annotation: Option<&str>, raw: Option<&str>) -> StructuralQuery {
+        StructuralQuery {
+            call: call.map(str::to_string),
+            annotation: annotation.map(str::to_string),
+            query: raw.map(str::to_string),
+        }
+    }
+
+    fn lines(matcher: &StructuralMatcher, path: &str, content: &str) -> Vec<usize> {
+        matcher
+            .find(Path::new(path), content)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.line)
+            .collect()
+    }
+
+    #[test]
+    fn test_call_queries() {
+        let matcher =
+            StructuralMatcher::new(&query(Some("reqwest::Client::new"), None, None)).unwrap();
+        let rust = r#"use reqwest::Client;
+// reqwest::Client::new() in a comment
+fn client() -> Client {
+    let message = "reqwest::Client::new()";
+    Client::new()
+}
+fn other() {
+    let c = reqwest::Client
+        ::new();
+    let v = Vec::<u8>::new();
+}
+"#;
+        assert_eq!(lines(&matcher, "src/http.rs", rust), vec![5, 8]);
+        // A shortened path only matches when an import resolves it
+        assert!(lines(
+            &matcher,
+            "src/db.rs",
+            "use sqlx::Client;\nfn f() { Client::new(); }\n"
+        )
+        .is_empty());
+        assert_eq!(
+            lines(
+                &matcher,
+                "src/http.rs",
+                "use reqwest::Client as Http;\nfn f() { Http::new(); }\n"
+            ),
+            vec![2]
+        );
+
+        let matcher =
+            StructuralMatcher::new(&query(Some("RestTemplate::new"), None, None)).unwrap();
+        let java =
+            "class A {\n  void f() {\n    var t = new RestTemplate();\n    t.exchange();\n  }\n}\n";
+        assert_eq!(lines(&matcher, "A.java", java), vec![3]);
+
+        let matcher = StructuralMatcher::new(&query(Some("requests.get"), None, None)).unwrap();
+        let python =
+            "import requests\n\n# requests.get(url)\nresponse = requests.get(\n    url,\n)\n";
+        assert_eq!(lines(&matcher, "app.py", python), vec![4]);
+        assert_eq!(
+            lines(&matcher, "app.py", "from requests import get\n\nget(url)\n"),
+            vec![3]
+        );
+        assert!(lines(&matcher, "app.py", "from httpx import get\n\nget(url)\n").is_empty());
+        assert!(matcher
+            .find(Path::new("README.md"), "requests.get()")
+            .is_none());
+    }
+
+    #[test]
+    fn test_annotation_and_raw_queries() {
+        let matcher = StructuralMatcher::new(&query(None, Some("@Entity"), None)).unwrap();
+        let java = "@javax.persistence.Entity\n@Table(name = \"orders\")\npublic class Order {\n  /* @Entity */\n}\n";
+        let found = matcher.find(Path::new("Order.java"), java).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].text, "@javax.persistence.Entity");
+
+        let matcher = StructuralMatcher::new(&query(None, Some("tokio::main"), None)).unwrap();
+        assert_eq!(
+            lines(
+                &matcher,
+                "src/main.rs",
+                "#[tokio::main]\nasync fn main() {}\n"
+            ),
+            vec![1]
+        );
+
+        let matcher = StructuralMatcher::new(&query(None, Some("Component"), None)).unwrap();
+        let typescript = "@Component({ selector: 'app' })\nexport class AppComponent {}\n";
+        assert_eq!(lines(&matcher, "app.component.ts", typescript), vec![1]);
+
+        // A Java query does not compile for the other grammars
+        let raw = r#"(class_declaration name: (identifier) @match (#match? @match "Repository$"))"#;
+        let matcher = StructuralMatcher::new(&query(None, None, Some(raw))).unwrap();
+        let java = "class OrderRepository {}\nclass Order {}\n";
+        assert_eq!(lines(&matcher, "Repo.java", java), vec![1]);
+        assert!(matcher.find(Path::new("lib.rs"), "struct A;").is_none());
+
+        assert!(StructuralMatcher::new(&query(None, None, Some("(no_such_node)"))).is_err());
+        assert!(StructuralMatcher::new(&query(Some("a"), Some("b"), None)).is_err());
+    }
+}
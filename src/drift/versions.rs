@@ -0,0 +1,262 @@
+//! ADR Version Constraints
+//!
+//! An ADR can pin the versions a decision was made for, either in a
+//! `versions` frontmatter map or in a "Versions" section with one
+//! technology per list item:
+//!
+//! ```markdown
+//! ---
+//! versions:
+//!   PostgreSQL: 15
+//!   react: ">=18, <19"
+//! ---
+//!
+//! ## Versions
+//!
+//! - tokio 1
+//! - Django: ^4.2
+//! ```
+//!
+//! Requirements use Cargo's semver syntax; a bare version such as `15`
+//! allows any compatible release (`^15`). They are checked against the
+//! versions declared in dependency manifests, for the package whose name
+//! matches the pinned name ignoring case, or the artifact of a Maven
+//! `group:artifact`. A pin on a technology rather than a package, such as
+//! the PostgreSQL server version above, is not checked: client libraries
+//! like `tokio-postgres` have versions of their own.
+
+use regex::Regex;
+use semver::{Version, VersionReq};
+use serde_yaml::Value;
+use std::sync::LazyLock;
+
+use crate::parser::{AdrDocument, SectionKind};
+
+/// Frontmatter field holding version constraints
+pub const VERSIONS_FIELD: &str = "versions";
+
+/// A `v` prefix on a version, as in `>=v1.2`
+static V_PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(^|[\s=<>^~,])v(\d)").unwrap());
+
+/// The first dotted number in a version spec
+static DOTTED_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\d+)(?:\.(\d+))?(?:\.(\d+))?").unwrap());
+
+/// A version requirement an ADR places on one technology
+#[derive(Debug, Clone)]
+pub struct VersionConstraint {
+    /// Name the requirement is written for; only checked against a
+    /// dependency package of that name
+    pub technology: String,
+
+    /// Requirement as written in the ADR
+    pub requirement: String,
+
+    /// Parsed requirement
+    req: VersionReq,
+}
+
+impl VersionConstraint {
+    /// Parse a requirement, returning `None` if it is not valid semver
+    pub fn new(technology: &str, requirement: &str) -> Option<Self> {
+        let normalized = V_PREFIX
+            .replace_all(&requirement.replace("==", "="), "$1$2")
+            .into_owned();
+        match VersionReq::parse(normalized.trim()) {
+            Ok(req) => Some(Self {
+                technology: technology.trim().to_string(),
+                requirement: requirement.trim().to_string(),
+                req,
+            }),
+            Err(e) => {
+                log::warn!("Ignoring version constraint '{technology} {requirement}': {e}");
+                None
+            }
+        }
+    }
+
+    /// Whether the constraint pins the given dependency package
+    pub fn applies_to(&self, package: &str) -> bool {
+        let artifact = package.rsplit(':').next().unwrap_or(package);
+        [package, artifact]
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(&self.technology))
+    }
+
+    /// Whether the version satisfies the requirement
+    pub fn allows(&self, version: &Version) -> bool {
+        self.req.matches(version)
+    }
+}
+
+/// Version constraints declared by an ADR, frontmatter first
+pub fn constraints(document: &AdrDocument) -> Vec<VersionConstraint> {
+    let mut constraints = Vec::new();
+
+    match document.metadata.custom_fields.get(VERSIONS_FIELD) {
+        Some(Value::Mapping(mapping)) => {
+            for (technology, requirement) in mapping {
+                if let (Some(technology), Some(requirement)) =
+                    (scalar(technology), scalar(requirement))
+                {
+                    constraints.extend(VersionConstraint::new(&technology, &requirement));
+                }
+            }
+        }
+        Some(Value::Sequence(items)) => {
+            for item in items.iter().filter_map(scalar) {
+                constraints.extend(parse_line(&item));
+            }
+        }
+        Some(_) => log::warn!(
+            "Ignoring '{VERSIONS_FIELD}' in {}: expected a map of technology to version",
+            document.path.display()
+        ),
+        None => {}
+    }
+
+    for section in document.sections.find_all(SectionKind::Versions) {
+        for line in section.text.lines() {
+            let line = line.trim_start();
+            if let Some(item) = ["- ", "* ", "+ "]
+                .iter()
+                .find_map(|marker| line.strip_prefix(marker))
+            {
+                constraints.extend(parse_line(item));
+            }
+        }
+    }
+
+    constraints
+}
+
+/// Lowest version a dependency version spec allows
+///
+/// Manifests write versions in many dialects (`^1.2`, `~> 2.0`, `==4.2.1`,
+/// `>=2,<3`, `3.1.+`), so this takes the first dotted number in the spec.
+/// Returns `None` for specs without one, such as `*` or `workspace = true`.
+pub fn parse_version(spec: &str) -> Option<Version> {
+    let captures = DOTTED_NUMBER.captures(spec)?;
+    let part = |index: usize| {
+        captures
+            .get(index)
+            .and_then(|m| m.as_str().parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    Some(Version::new(part(1), part(2), part(3)))
+}
+
+/// Whether going from `old` to `new` is a semver-incompatible upgrade
+///
+/// Below 1.0 the minor version carries breaking changes, as in Cargo.
+pub fn is_major_bump(old: &Version, new: &Version) -> bool {
+    if new.major != old.major {
+        new.major > old.major
+    } else {
+        new.major == 0 && new.minor > old.minor
+    }
+}
+
+/// Parse a "Technology requirement" list item
+fn parse_line(line: &str) -> Option<VersionConstraint> {
+    let line = line.replace(['`', '*'], "");
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let split = tokens.iter().position(|token| {
+        let token = token.strip_prefix('v').unwrap_or(token);
+        token.starts_with(|c: char| c.is_ascii_digit() || "<>=^~".contains(c))
+    })?;
+
+    let technology = tokens[..split].join(" ");
+    let technology = technology.trim_end_matches(':').trim();
+    if technology.is_empty() {
+        return None;
+    }
+    VersionConstraint::new(technology, &tokens[split..].join(" "))
+}
+
+/// String form of a YAML scalar
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::AdrParser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_constraints_from_frontmatter_and_section() {
+        let content = r#"---
+title: Use PostgreSQL
+status: accepted
+versions:
+  PostgreSQL: 15
+  react: ">=18, <19"
+  broken: "not a version"
+---
+
+# Use PostgreSQL
+
+## Versions
+
+- `tokio` 1
+- Django: ==4.2
+- Spring Boot v3.1
+
+Notes that are not list items are ignored.
+"#;
+        let document = AdrParser::parse_content(content, PathBuf::from("0001.md")).unwrap();
+        let constraints = constraints(&document);
+        let pinned: Vec<(&str, &str)> = constraints
+            .iter()
+            .map(|c| (c.technology.as_str(), c.requirement.as_str()))
+            .collect();
+
+        assert!(pinned.contains(&("PostgreSQL", "15")));
+        assert!(pinned.contains(&("react", ">=18, <19")));
+        assert!(pinned.contains(&("tokio", "1")));
+        assert!(pinned.contains(&("Django", "==4.2")));
+        assert!(pinned.contains(&("Spring Boot", "v3.1")));
+        assert_eq!(constraints.len(), 5);
+
+        let django = constraints
+            .iter()
+            .find(|c| c.technology == "Django")
+            .unwrap();
+        assert!(django.allows(&Version::new(4, 2, 0)));
+        assert!(!django.allows(&Version::new(5, 0, 0)));
+        assert!(django.applies_to("django"));
+        assert!(!django.applies_to("django-cors-headers"));
+
+        let postgres = constraints
+            .iter()
+            .find(|c| c.technology == "PostgreSQL")
+            .unwrap();
+        assert!(postgres.allows(&Version::new(15, 4, 0)));
+        assert!(!postgres.allows(&Version::new(16, 0, 0)));
+        assert!(!postgres.applies_to("tokio-postgres"));
+
+        let spring = VersionConstraint::new("spring-boot-starter", "3").unwrap();
+        assert!(spring.applies_to("org.springframework.boot:spring-boot-starter"));
+    }
+
+    #[test]
+    fn test_parse_version_and_major_bumps() {
+        assert_eq!(parse_version("^1.2"), Some(Version::new(1, 2, 0)));
+        assert_eq!(parse_version(">=2,<3"), Some(Version::new(2, 0, 0)));
+        assert_eq!(parse_version("v4.2.1"), Some(Version::new(4, 2, 1)));
+        assert_eq!(parse_version("3.1.+"), Some(Version::new(3, 1, 0)));
+        assert_eq!(parse_version("*"), None);
+
+        let v = |s: &str| parse_version(s).unwrap();
+        assert!(is_major_bump(&v("17.0.2"), &v("18.2.0")));
+        assert!(is_major_bump(&v("0.7"), &v("0.8")));
+        assert!(!is_major_bump(&v("1.2"), &v("1.9")));
+        assert!(!is_major_bump(&v("2.0"), &v("1.0")));
+    }
+}
@@ -35,6 +35,9 @@ pub enum AdrscanError {
     #[allow(dead_code)] // Planned for permission checking
     PermissionDenied(String),
 
+    #[error("Git error: {0}")]
+    GitError(String),
+
     #[error("Serialization error: {0}")]
     SerializationError(String),
 
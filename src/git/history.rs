@@ -0,0 +1,227 @@
+//! ADR History
+//!
+//! Reconstructs how a decision evolved by replaying the committed versions
+//! of its file. Only commits that change the status, the title or the
+//! decision section are kept; edits elsewhere in the document are noise for
+//! this purpose.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::{GitRepository, Result};
+use crate::parser::{AdrDocument, AdrParser, SectionKind};
+
+/// A decision-relevant change made in one commit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HistoryChange {
+    /// First commit containing the ADR
+    Created {
+        status: String,
+        title: String,
+    },
+    StatusChanged {
+        from: String,
+        to: String,
+    },
+    TitleChanged {
+        from: String,
+        to: String,
+    },
+    DecisionChanged,
+}
+
+/// A commit that changed an ADR
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub commit: String,
+    pub author: String,
+    pub email: String,
+    pub date: DateTime<FixedOffset>,
+    pub summary: String,
+    /// Repository-relative path of the ADR in this commit
+    pub path: String,
+    pub changes: Vec<HistoryChange>,
+}
+
+/// Decision history of a single ADR, oldest change first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdrHistory {
+    pub path: PathBuf,
+    pub entries: Vec<HistoryEntry>,
+}
+
+/// The parts of an ADR whose changes are tracked
+#[derive(Debug, PartialEq)]
+struct Tracked {
+    status: String,
+    title: String,
+    decision: Option<String>,
+}
+
+impl From<&AdrDocument> for Tracked {
+    fn from(document: &AdrDocument) -> Self {
+        Self {
+            status: document.metadata.status.clone(),
+            title: document.metadata.title.clone(),
+            decision: document
+                .sections
+                .text(SectionKind::DecisionOutcome)
+                .map(str::to_string),
+        }
+    }
+}
+
+impl AdrHistory {
+    /// Build the history of the ADR at `path` from its committed versions
+    ///
+    /// Uncommitted changes are ignored. Versions that fail to parse are
+    /// skipped, so a broken commit does not hide later changes.
+    pub fn load(repo: &GitRepository, path: &Path) -> Result<Self> {
+        let relative = repo.relative_path(path)?;
+        let mut log = repo.file_log(&relative)?;
+        log.reverse();
+
+        let mut entries = Vec::new();
+        let mut previous: Option<Tracked> = None;
+        for (commit, commit_path) in log {
+            let Some(content) = repo.show_file(&commit.hash, &commit_path) else {
+                // Deleted in this commit
+                continue;
+            };
+            let current = match AdrParser::parse_content(&content, PathBuf::from(&commit_path)) {
+                Ok(document) => Tracked::from(&document),
+                Err(e) => {
+                    log::debug!("Skipping unparsable {commit_path} at {}: {e}", commit.hash);
+                    continue;
+                }
+            };
+
+            let changes = diff(previous.as_ref(), &current);
+            if !changes.is_empty() {
+                entries.push(HistoryEntry {
+                    commit: commit.hash,
+                    author: commit.author,
+                    email: commit.email,
+                    date: commit.date,
+                    summary: commit.summary,
+                    path: commit_path,
+                    changes,
+                });
+            }
+            previous = Some(current);
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+}
+
+fn diff(previous: Option<&Tracked>, current: &Tracked) -> Vec<HistoryChange> {
+    let Some(previous) = previous else {
+        return vec![HistoryChange::Created {
+            status: current.status.clone(),
+            title: current.title.clone(),
+        }];
+    };
+
+    let mut changes = Vec::new();
+    if previous.status != current.status {
+        changes.push(HistoryChange::StatusChanged {
+            from: previous.status.clone(),
+            to: current.status.clone(),
+        });
+    }
+    if previous.title != current.title {
+        changes.push(HistoryChange::TitleChanged {
+            from: previous.title.clone(),
+            to: current.title.clone(),
+        });
+    }
+    if previous.decision != current.decision {
+        changes.push(HistoryChange::DecisionChanged);
+    }
+    changes
+}
+
+impl std::fmt::Display for HistoryChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryChange::Created { status, title } => {
+                write!(f, "created \"{title}\" as {status}")
+            }
+            HistoryChange::StatusChanged { from, to } => write!(f, "status {from} → {to}"),
+            HistoryChange::TitleChanged { from, to } => {
+                write!(f, "title \"{from}\" → \"{to}\"")
+            }
+            HistoryChange::DecisionChanged => write!(f, "decision section edited"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_support::*;
+    use super::*;
+    use tempfile::TempDir;
+
+    fn adr(status: &str, title: &str, decision: &str) -> String {
+        format!(
+            "---\ntitle: {title}\nstatus: {status}\n---\n# {title}\n\n## Context\n\nWhy.\n\n## Decision\n\n{decision}\n"
+        )
+    }
+
+    #[test]
+    fn test_history_tracks_decision_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_repo(root);
+        std::fs::create_dir(root.join("adr")).unwrap();
+        let path = root.join("adr/0001-db.md");
+
+        std::fs::write(&path, adr("proposed", "Use MySQL", "MySQL.")).unwrap();
+        commit_all(root, "Alice", "Propose database");
+        std::fs::write(
+            &path,
+            adr("proposed", "Use MySQL", "MySQL.").replace("Why.", "Because."),
+        )
+        .unwrap();
+        commit_all(root, "Bob", "Reword context");
+        std::fs::write(&path, adr("accepted", "Use PostgreSQL", "PostgreSQL.")).unwrap();
+        commit_all(root, "Carol", "Accept database");
+        // Uncommitted edits are not part of the history
+        std::fs::write(&path, adr("deprecated", "Use PostgreSQL", "PostgreSQL.")).unwrap();
+
+        let repo = GitRepository::discover(root).unwrap();
+        let history = AdrHistory::load(&repo, &path).unwrap();
+
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.entries[0].author, "Alice");
+        assert_eq!(
+            history.entries[0].changes,
+            vec![HistoryChange::Created {
+                status: "proposed".to_string(),
+                title: "Use MySQL".to_string(),
+            }]
+        );
+        assert_eq!(history.entries[1].author, "Carol");
+        assert_eq!(history.entries[1].path, "adr/0001-db.md");
+        assert_eq!(
+            history.entries[1].changes,
+            vec![
+                HistoryChange::StatusChanged {
+                    from: "proposed".to_string(),
+                    to: "accepted".to_string(),
+                },
+                HistoryChange::TitleChanged {
+                    from: "Use MySQL".to_string(),
+                    to: "Use PostgreSQL".to_string(),
+                },
+                HistoryChange::DecisionChanged,
+            ]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Git Integration
+//!
+//! Thin wrapper around the local `git` executable. Commands run in the
+//! repository's work tree and their output is parsed from machine-readable
+//! formats, so no libgit2 dependency is needed.
+
+pub mod history;
+
+pub use history::{AdrHistory, HistoryEntry};
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::error::AdrscanError;
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Separates fields within a formatted `git log` record
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// Starts each formatted `git log` record
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// A git work tree
+#[derive(Debug, Clone)]
+pub struct GitRepository {
+    root: PathBuf,
+}
+
+/// Commit metadata as reported by `git log`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommitInfo {
+    /// Full commit hash
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    /// Author date
+    pub date: DateTime<FixedOffset>,
+    /// First line of the commit message
+    pub summary: String,
+}
+
+impl GitRepository {
+    /// Find the repository containing `path`, if git is installed and the
+    /// path is inside a work tree
+    pub fn discover(path: &Path) -> Option<Self> {
+        let dir = if path.is_dir() { path } else { path.parent()? };
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+
+        let output = Command::new("git")
+            .args(["rev-parse", "--show-toplevel"])
+            .current_dir(dir)
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        let root = String::from_utf8(output.stdout).ok()?;
+        Some(Self {
+            root: PathBuf::from(root.trim()),
+        })
+    }
+
+    /// Path of `path` relative to the work tree root, using `/` separators
+    pub fn relative_path(&self, path: &Path) -> Result<String> {
+        let absolute = std::fs::canonicalize(path)?;
+        let root = std::fs::canonicalize(&self.root)?;
+        let relative = absolute.strip_prefix(&root).map_err(|_| {
+            AdrscanError::GitError(format!(
+                "{} is outside the repository at {}",
+                path.display(),
+                self.root.display()
+            ))
+        })?;
+
+        Ok(relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"))
+    }
+
+    /// Run a git command in the work tree and return its standard output
+    pub fn run(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&self.root)
+            .output()
+            .map_err(|e| AdrscanError::GitError(format!("Failed to run git: {e}")))?;
+
+        if !output.status.success() {
+            return Err(AdrscanError::GitError(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Commits touching a file, newest first, following renames
+    ///
+    /// Each commit is paired with the file's repository-relative path in that
+    /// commit.
+    pub fn file_log(&self, relative_path: &str) -> Result<Vec<(CommitInfo, String)>> {
+        let format = format!("--format={RECORD_SEPARATOR}%H{FIELD_SEPARATOR}%an{FIELD_SEPARATOR}%ae{FIELD_SEPARATOR}%aI{FIELD_SEPARATOR}%s");
+        let output = self.run(&[
+            "log",
+            "--follow",
+            "--name-only",
+            &format,
+            "--",
+            relative_path,
+        ])?;
+
+        output
+            .split(RECORD_SEPARATOR)
+            .filter(|record| !record.trim().is_empty())
+            .map(|record| {
+                let mut lines = record.lines();
+                let header = lines.next().unwrap_or_default();
+                let commit = parse_commit(header)?;
+                let path = lines
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or(relative_path)
+                    .to_string();
+                Ok((commit, path))
+            })
+            .collect()
+    }
+
+    /// Content of a file at a commit, or `None` if it does not exist there
+    pub fn show_file(&self, commit: &str, relative_path: &str) -> Option<String> {
+        self.run(&["show", &format!("{commit}:{relative_path}")])
+            .ok()
+    }
+}
+
+/// Parse a header line produced by the `file_log` format
+fn parse_commit(header: &str) -> Result<CommitInfo> {
+    let fields: Vec<&str> = header.split(FIELD_SEPARATOR).collect();
+    let [hash, author, email, date, summary] = fields[..] else {
+        return Err(AdrscanError::GitError(format!(
+            "Unexpected git log output: {header}"
+        )));
+    };
+
+    let date = DateTime::parse_from_rfc3339(date)
+        .map_err(|e| AdrscanError::GitError(format!("Invalid commit date '{date}': {e}")))?;
+
+    Ok(CommitInfo {
+        hash: hash.to_string(),
+        author: author.to_string(),
+        email: email.to_string(),
+        date,
+        summary: summary.to_string(),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::path::Path;
+    use std::process::Command;
+
+    /// Initialize a repository with a fixed identity
+    pub fn init_repo(dir: &Path) {
+        git(dir, &["init", "--quiet"]);
+        git(dir, &["config", "user.name", "Alice Example"]);
+        git(dir, &["config", "user.email", "alice@example.com"]);
+        git(dir, &["config", "commit.gpgsign", "false"]);
+    }
+
+    /// Stage everything and commit as `author`
+    pub fn commit_all(dir: &Path, author: &str, message: &str) {
+        git(dir, &["add", "-A"]);
+        git(
+            dir,
+            &[
+                "commit",
+                "--quiet",
+                "--author",
+                &format!("{author} <{}@example.com>", author.to_lowercase()),
+                "-m",
+                message,
+            ],
+        );
+    }
+
+    pub fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::*;
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_file_log_follows_renames() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        init_repo(root);
+
+        std::fs::write(root.join("old.md"), "# Old\n").unwrap();
+        commit_all(root, "Alice", "Add ADR");
+        git(root, &["mv", "old.md", "new.md"]);
+        commit_all(root, "Bob", "Rename ADR");
+
+        let repo = GitRepository::discover(root).unwrap();
+        assert_eq!(repo.relative_path(&root.join("new.md")).unwrap(), "new.md");
+
+        let log = repo.file_log("new.md").unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].0.author, "Bob");
+        assert_eq!(log[0].1, "new.md");
+        assert_eq!(log[1].0.summary, "Add ADR");
+        assert_eq!(log[1].1, "old.md");
+
+        assert_eq!(
+            repo.show_file(&log[1].0.hash, "old.md").as_deref(),
+            Some("# Old\n")
+        );
+        assert!(repo.show_file(&log[1].0.hash, "new.md").is_none());
+    }
+}
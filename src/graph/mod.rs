@@ -0,0 +1,628 @@
+//! ADR Relationship Graph
+//!
+//! This module resolves the `supersedes` and `relates_to` references in ADR
+//! metadata into a graph of decisions and reports structural problems:
+//!
+//! - supersession cycles (A supersedes B supersedes A)
+//! - references that match no ADR
+//! - ADRs still "accepted" although another ADR supersedes them
+//!
+//! References may name an ADR by id (`ADR-0003`, `3`), by file name
+//! (`0003-use-postgres.md`) or by relative link (`./0003-use-postgres.md`).
+//! ADRs from namespaced roots are referenced across namespaces as
+//! `payments/0007`, and ADRs without a namespace as `/0007`. An unqualified
+//! reference resolves within the referring ADR's namespace first and then
+//! among ADRs without a namespace, matching file names before numbers.
+
+pub mod render;
+
+pub use render::RenderOptions;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::parser::AdrDocument;
+use crate::status;
+
+/// Prefix of references to an ADR without a namespace, written by ADRs of
+/// namespaced roots: `/0003` or `/0003-use-postgres.md`
+pub const GLOBAL_PREFIX: &str = "/";
+
+/// An ADR in the graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdrNode {
+    /// ADR file
+    pub path: PathBuf,
+
+    /// Id from the metadata, if any
+    pub id: Option<String>,
+
+    pub title: String,
+
+    pub status: String,
+
+    pub tags: Vec<String>,
+
+    /// References as written in `supersedes`
+    pub supersedes: Vec<String>,
+
+    /// References as written in `relates_to`
+    pub relates_to: Vec<String>,
+
+    /// Namespace of the ADR's root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl AdrNode {
+    /// Short label: the id as written, or the file stem, prefixed with the
+    /// namespace if any
+    pub fn label(&self) -> String {
+        let label = self.id.clone().unwrap_or_else(|| {
+            self.path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.path.display().to_string())
+        });
+        match self.namespace {
+            Some(ref namespace) => format!("{namespace}/{label}"),
+            None => label,
+        }
+    }
+
+    /// Normalized number from the id or the file name prefix
+    pub fn number(&self) -> Option<String> {
+        self.id.as_deref().map(normalize_reference).or_else(|| {
+            let stem = self.path.file_stem()?.to_str()?;
+            stem.starts_with(|c: char| c.is_ascii_digit())
+                .then(|| normalize_reference(stem))
+        })
+    }
+}
+
+impl From<&AdrDocument> for AdrNode {
+    fn from(document: &AdrDocument) -> Self {
+        Self {
+            path: document.path.clone(),
+            id: document.metadata.id.clone(),
+            title: document.metadata.title.clone(),
+            status: document.metadata.status.clone(),
+            tags: document.metadata.tags.clone(),
+            supersedes: document.metadata.supersedes.clone(),
+            relates_to: document.metadata.relates_to.clone(),
+            namespace: document.namespace.clone(),
+        }
+    }
+}
+
+/// Kind of relationship between two ADRs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    Supersedes,
+    RelatesTo,
+}
+
+impl EdgeKind {
+    /// Metadata field the edge comes from
+    pub fn field(&self) -> &'static str {
+        match self {
+            EdgeKind::Supersedes => "supersedes",
+            EdgeKind::RelatesTo => "relates_to",
+        }
+    }
+}
+
+/// A resolved reference from one node to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AdrEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+}
+
+/// A reference that matched no ADR
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DanglingReference {
+    pub from: usize,
+    pub kind: EdgeKind,
+    pub reference: String,
+}
+
+/// Structural problem in the graph, described by ADR labels
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GraphIssue {
+    /// ADRs that supersede each other in a loop
+    SupersessionCycle { adrs: Vec<String> },
+
+    /// A reference that matches no ADR
+    DanglingReference {
+        adr: String,
+        field: String,
+        reference: String,
+    },
+
+    /// An accepted ADR that another ADR supersedes
+    AcceptedButSuperseded {
+        adr: String,
+        superseded_by: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for GraphIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphIssue::SupersessionCycle { adrs } => {
+                write!(
+                    f,
+                    "Supersession cycle: {} -> {}",
+                    adrs.join(" -> "),
+                    adrs[0]
+                )
+            }
+            GraphIssue::DanglingReference {
+                adr,
+                field,
+                reference,
+            } => write!(f, "{adr}: {field} reference '{reference}' matches no ADR"),
+            GraphIssue::AcceptedButSuperseded { adr, superseded_by } => write!(
+                f,
+                "{adr} is still accepted but superseded by {}",
+                superseded_by.join(", ")
+            ),
+        }
+    }
+}
+
+/// Decision graph of an ADR corpus
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdrGraph {
+    pub nodes: Vec<AdrNode>,
+    pub edges: Vec<AdrEdge>,
+    pub dangling: Vec<DanglingReference>,
+
+    /// Reference keys of the nodes, built with the graph
+    #[serde(skip)]
+    keys: OnceLock<ReferenceKeys>,
+}
+
+impl AdrGraph {
+    /// Build the graph for parsed documents; node indices follow document order
+    pub fn from_documents(documents: &[AdrDocument]) -> Self {
+        Self::from_nodes(documents.iter().map(AdrNode::from).collect())
+    }
+
+    /// Build the graph by resolving every node's references
+    pub fn from_nodes(nodes: Vec<AdrNode>) -> Self {
+        let keys = ReferenceKeys::new(&nodes);
+        let mut graph = Self {
+            nodes,
+            edges: Vec::new(),
+            dangling: Vec::new(),
+            keys: OnceLock::new(),
+        };
+
+        for (from, node) in graph.nodes.iter().enumerate() {
+            let namespace = node.namespace.as_deref();
+            let references = node
+                .supersedes
+                .iter()
+                .map(|r| (EdgeKind::Supersedes, r))
+                .chain(node.relates_to.iter().map(|r| (EdgeKind::RelatesTo, r)));
+
+            for (kind, reference) in references {
+                match keys.lookup(namespace, reference) {
+                    Some(to) => graph.edges.push(AdrEdge { from, to, kind }),
+                    None => graph.dangling.push(DanglingReference {
+                        from,
+                        kind,
+                        reference: reference.clone(),
+                    }),
+                }
+            }
+        }
+
+        graph.keys = OnceLock::from(keys);
+        graph
+    }
+
+    /// Lookup table from namespaced, normalized keys to node indices
+    fn reference_keys(&self) -> &ReferenceKeys {
+        // Only a deserialized graph has to build them here
+        self.keys.get_or_init(|| ReferenceKeys::new(&self.nodes))
+    }
+
+    /// Resolve a reference to a node index
+    ///
+    /// Unqualified references prefer ADRs without a namespace, then an ADR
+    /// that is the only match across all namespaces.
+    pub fn resolve(&self, reference: &str) -> Option<usize> {
+        let keys = self.reference_keys();
+        keys.lookup(None, reference).or_else(|| {
+            let mut matches = keys
+                .namespaces
+                .iter()
+                .filter_map(|namespace| keys.lookup(Some(namespace), reference));
+            let first = matches.next()?;
+            matches.all(|other| other == first).then_some(first)
+        })
+    }
+
+    /// Node index of an ADR file
+    pub fn node_by_path(&self, path: &Path) -> Option<usize> {
+        self.nodes.iter().position(|node| node.path == path)
+    }
+
+    /// Targets of a node's edges of the given kind
+    pub fn outgoing(&self, node: usize, kind: EdgeKind) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|e| e.from == node && e.kind == kind)
+            .map(|e| e.to)
+            .collect()
+    }
+
+    /// Sources of edges of the given kind pointing at a node
+    pub fn incoming(&self, node: usize, kind: EdgeKind) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|e| e.to == node && e.kind == kind)
+            .map(|e| e.from)
+            .collect()
+    }
+
+    /// ADRs that supersede the given one
+    pub fn superseded_by(&self, node: usize) -> Vec<usize> {
+        self.incoming(node, EdgeKind::Supersedes)
+    }
+
+    /// ADRs related to the given one in either direction
+    pub fn related(&self, node: usize) -> Vec<usize> {
+        let related: BTreeSet<usize> = self
+            .outgoing(node, EdgeKind::RelatesTo)
+            .into_iter()
+            .chain(self.incoming(node, EdgeKind::RelatesTo))
+            .filter(|&other| other != node)
+            .collect();
+        related.into_iter().collect()
+    }
+
+    /// Nodes reachable from `root` along outgoing edges, root included, in graph order
+    pub fn subtree(&self, root: usize) -> Vec<usize> {
+        let mut seen = BTreeSet::from([root]);
+        let mut queue = vec![root];
+        while let Some(node) = queue.pop() {
+            for edge in self.edges.iter().filter(|e| e.from == node) {
+                if seen.insert(edge.to) {
+                    queue.push(edge.to);
+                }
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Cycles in the supersession chains, each listed once
+    pub fn supersession_cycles(&self) -> Vec<Vec<usize>> {
+        let mut cycles = BTreeSet::new();
+        let mut state = vec![VisitState::New; self.nodes.len()];
+        let mut stack = Vec::new();
+
+        for start in 0..self.nodes.len() {
+            if state[start] == VisitState::New {
+                self.find_cycles(start, &mut state, &mut stack, &mut cycles);
+            }
+        }
+
+        cycles.into_iter().collect()
+    }
+
+    fn find_cycles(
+        &self,
+        node: usize,
+        state: &mut [VisitState],
+        stack: &mut Vec<usize>,
+        cycles: &mut BTreeSet<Vec<usize>>,
+    ) {
+        state[node] = VisitState::OnStack;
+        stack.push(node);
+
+        for next in self.outgoing(node, EdgeKind::Supersedes) {
+            match state[next] {
+                VisitState::New => self.find_cycles(next, state, stack, cycles),
+                VisitState::OnStack => {
+                    let start = stack.iter().position(|&n| n == next).unwrap_or(0);
+                    let mut cycle = stack[start..].to_vec();
+                    // Rotate so the same cycle is always reported the same way
+                    let min = cycle
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, &n)| n)
+                        .map(|(i, _)| i);
+                    cycle.rotate_left(min.unwrap_or(0));
+                    cycles.insert(cycle);
+                }
+                VisitState::Done => {}
+            }
+        }
+
+        stack.pop();
+        state[node] = VisitState::Done;
+    }
+
+    /// Accepted ADRs that another ADR supersedes
+    pub fn accepted_while_superseded(&self) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&node| {
+                status::normalize(&self.nodes[node].status) == "accepted"
+                    && !self.superseded_by(node).is_empty()
+            })
+            .collect()
+    }
+
+    /// All structural problems, described by ADR labels
+    pub fn issues(&self) -> Vec<GraphIssue> {
+        let label = |node: usize| self.nodes[node].label();
+        let mut issues = Vec::new();
+
+        for cycle in self.supersession_cycles() {
+            issues.push(GraphIssue::SupersessionCycle {
+                adrs: cycle.into_iter().map(label).collect(),
+            });
+        }
+
+        for dangling in &self.dangling {
+            issues.push(GraphIssue::DanglingReference {
+                adr: label(dangling.from),
+                field: dangling.kind.field().to_string(),
+                reference: dangling.reference.clone(),
+            });
+        }
+
+        for node in self.accepted_while_superseded() {
+            issues.push(GraphIssue::AcceptedButSuperseded {
+                adr: label(node),
+                superseded_by: self.superseded_by(node).into_iter().map(label).collect(),
+            });
+        }
+
+        issues
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    New,
+    OnStack,
+    Done,
+}
+
+/// Reference keys per namespace
+#[derive(Debug, Clone, Default)]
+struct ReferenceKeys {
+    /// Lowercased file stems
+    stems: HashMap<(Option<String>, String), usize>,
+    /// Numbers, or normalized stems of files without one
+    numbers: HashMap<(Option<String>, String), usize>,
+    namespaces: BTreeSet<String>,
+}
+
+impl ReferenceKeys {
+    /// Keys of every node: its file stem, and its number if it has one
+    fn new(nodes: &[AdrNode]) -> Self {
+        let mut keys = Self::default();
+        for (index, node) in nodes.iter().enumerate() {
+            let namespace = node.namespace.clone();
+            if let Some(ref namespace) = namespace {
+                keys.namespaces.insert(namespace.clone());
+            }
+            if let Some(stem) = node.path.file_stem().and_then(|s| s.to_str()) {
+                keys.stems
+                    .entry((namespace.clone(), stem.to_lowercase()))
+                    .or_insert(index);
+                keys.numbers
+                    .entry((namespace.clone(), normalize_reference(stem)))
+                    .or_insert(index);
+            }
+            if let Some(number) = node.number() {
+                keys.numbers.entry((namespace, number)).or_insert(index);
+            }
+        }
+        keys
+    }
+
+    /// Resolve `reference` as written in an ADR of namespace `from`
+    ///
+    /// A file stem is matched before a number, so that `0001-use-mysql.md`
+    /// never resolves to another namespace's ADR 1.
+    fn lookup(&self, from: Option<&str>, reference: &str) -> Option<usize> {
+        let reference = reference.trim();
+        if let Some(rest) = reference.strip_prefix(GLOBAL_PREFIX) {
+            return self.lookup_in(&[None], rest);
+        }
+        if let Some((namespace, rest)) = reference.split_once('/') {
+            if self.namespaces.contains(namespace) {
+                return self.lookup_in(&[Some(namespace)], rest);
+            }
+        }
+
+        match from {
+            Some(namespace) => self.lookup_in(&[Some(namespace), None], reference),
+            None => self.lookup_in(&[None], reference),
+        }
+    }
+
+    /// Resolve `reference` in the first of `namespaces` with a matching
+    /// file stem, else the first with a matching number
+    fn lookup_in(&self, namespaces: &[Option<&str>], reference: &str) -> Option<usize> {
+        let get = |keys: &HashMap<(Option<String>, String), usize>, key: &str| {
+            namespaces.iter().find_map(|namespace| {
+                keys.get(&(namespace.map(str::to_string), key.to_string()))
+                    .copied()
+            })
+        };
+        let stem = reference_stem(reference);
+        get(&self.stems, &stem).or_else(|| get(&self.numbers, &normalize_reference(reference)))
+    }
+}
+
+/// Lowercased file stem a reference names, e.g. `0003-use-postgres` for
+/// `./0003-use-postgres.md#decision`
+fn reference_stem(reference: &str) -> String {
+    let name = reference
+        .trim()
+        .rsplit('/')
+        .next()
+        .unwrap_or(reference)
+        .split('#')
+        .next()
+        .unwrap_or_default();
+    name.strip_suffix(".md")
+        .or_else(|| name.strip_suffix(".markdown"))
+        .unwrap_or(name)
+        .to_lowercase()
+}
+
+/// Reduce a reference to a comparable key: the number if it has one
+pub fn normalize_reference(reference: &str) -> String {
+    let name = reference_stem(reference);
+    let name = name
+        .strip_prefix("adr-")
+        .or_else(|| name.strip_prefix("adr"))
+        .unwrap_or(&name)
+        .trim();
+
+    let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+    match digits.parse::<u64>() {
+        Ok(number) => number.to_string(),
+        Err(_) => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(file: &str, status: &str, supersedes: &[&str], relates_to: &[&str]) -> AdrNode {
+        AdrNode {
+            path: PathBuf::from(file),
+            id: None,
+            title: file.to_string(),
+            status: status.to_string(),
+            tags: Vec::new(),
+            supersedes: supersedes.iter().map(|s| s.to_string()).collect(),
+            relates_to: relates_to.iter().map(|s| s.to_string()).collect(),
+            namespace: None,
+        }
+    }
+
+    #[test]
+    fn test_resolves_ids_numbers_and_filenames() {
+        let mut first = node("docs/adr/0001-use-mysql.md", "superseded", &[], &[]);
+        first.id = Some("ADR-0001".to_string());
+        let graph = AdrGraph::from_nodes(vec![
+            first,
+            node(
+                "docs/adr/0002-use-postgres.md",
+                "accepted",
+                &["ADR-0001"],
+                &["./0003-caching.md", "9"],
+            ),
+            node(
+                "docs/adr/0003-caching.md",
+                "accepted",
+                &[],
+                &["0002-use-postgres"],
+            ),
+        ]);
+
+        assert_eq!(graph.resolve("1"), Some(0));
+        assert_eq!(graph.resolve("0001-use-mysql.md"), Some(0));
+        assert_eq!(graph.superseded_by(0), vec![1]);
+        assert_eq!(graph.related(1), vec![2]);
+        assert_eq!(graph.related(2), vec![1]);
+        assert_eq!(graph.dangling.len(), 1);
+        assert_eq!(graph.dangling[0].reference, "9");
+        assert!(graph.supersession_cycles().is_empty());
+        assert!(graph.accepted_while_superseded().is_empty());
+
+        // A deserialized graph rebuilds its reference keys
+        let json = serde_json::to_string(&graph).unwrap();
+        let loaded: AdrGraph = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.resolve("ADR-0003"), Some(2));
+    }
+
+    #[test]
+    fn test_issues() {
+        let graph = AdrGraph::from_nodes(vec![
+            node("0001-a.md", "accepted", &["0003"], &[]),
+            node("0002-b.md", "accepted", &["0001"], &[]),
+            node("0003-c.md", "superseded", &["0002"], &[]),
+            node("0004-d.md", "Accepted", &["0004-d.md", "0099"], &[]),
+        ]);
+
+        assert_eq!(graph.supersession_cycles(), vec![vec![0, 2, 1], vec![3]]);
+        assert_eq!(graph.accepted_while_superseded(), vec![0, 1, 3]);
+
+        let issues = graph.issues();
+        assert_eq!(
+            issues[0].to_string(),
+            "Supersession cycle: 0001-a -> 0003-c -> 0002-b -> 0001-a"
+        );
+        assert!(issues.contains(&GraphIssue::DanglingReference {
+            adr: "0004-d".to_string(),
+            field: "supersedes".to_string(),
+            reference: "0099".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_namespaced_references() {
+        let namespaced = |namespace: &str, mut node: AdrNode| {
+            node.namespace = Some(namespace.to_string());
+            node
+        };
+        let graph = AdrGraph::from_nodes(vec![
+            node("docs/adr/0001-platform.md", "accepted", &[], &[]),
+            namespaced(
+                "payments",
+                node(
+                    "payments/0001-ledger.md",
+                    "accepted",
+                    &["0001-platform.md"],
+                    &["search/2", "1", "/1"],
+                ),
+            ),
+            namespaced(
+                "search",
+                node("search/0002-index.md", "accepted", &[], &["3", "billing/9"]),
+            ),
+        ]);
+
+        assert_eq!(graph.nodes[1].label(), "payments/0001-ledger");
+        // Qualified references cross namespaces, unqualified ones stay local
+        // unless marked as global
+        assert_eq!(graph.outgoing(1, EdgeKind::RelatesTo), vec![2, 1, 0]);
+        // A file name is matched before the number it starts with
+        assert_eq!(graph.outgoing(1, EdgeKind::Supersedes), vec![0]);
+        assert_eq!(graph.accepted_while_superseded(), vec![0]);
+        // Neither the local namespace nor the global root has ADR 3, and an
+        // unknown namespace is read as a plain path
+        assert_eq!(graph.dangling.len(), 2);
+
+        assert_eq!(graph.resolve("1"), Some(0));
+        assert_eq!(graph.resolve("/0001-platform.md"), Some(0));
+        assert_eq!(graph.resolve("payments/0001"), Some(1));
+        // Unique across namespaces
+        assert_eq!(graph.resolve("2"), Some(2));
+    }
+
+    #[test]
+    fn test_normalize_reference() {
+        assert_eq!(normalize_reference("ADR-0007"), "7");
+        assert_eq!(normalize_reference("./0007-use-kafka.md#status"), "7");
+        assert_eq!(normalize_reference("use-kafka.md"), "use-kafka");
+    }
+}
@@ -0,0 +1,281 @@
+//! Graph Rendering
+//!
+//! Renders the decision graph as Graphviz DOT or Mermaid flowchart source.
+//! Nodes are colored by status (using the same palette as the index badges)
+//! and can be clustered by their first tag.
+
+use std::collections::BTreeMap;
+
+use super::{AdrGraph, EdgeKind};
+
+/// Options shared by all output formats
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Cluster nodes by their first tag
+    pub group_by_tag: bool,
+
+    /// Render only the ADRs reachable from this node
+    pub root: Option<usize>,
+}
+
+/// Fill colors per status
+const STATUS_COLORS: &[(&str, &str)] = &[
+    ("accepted", "#a3d977"),
+    ("proposed", "#f5d76e"),
+    ("rejected", "#f28b82"),
+    ("deprecated", "#f7b267"),
+    ("superseded", "#d3d3d3"),
+];
+
+const DEFAULT_COLOR: &str = "#aecbfa";
+
+fn status_color(status: &str) -> &'static str {
+    STATUS_COLORS
+        .iter()
+        .find(|(s, _)| s.eq_ignore_ascii_case(status))
+        .map(|(_, color)| *color)
+        .unwrap_or(DEFAULT_COLOR)
+}
+
+impl AdrGraph {
+    /// Render as Graphviz DOT
+    pub fn to_dot(&self, options: &RenderOptions) -> String {
+        let nodes = self.visible_nodes(options);
+        let mut out = String::new();
+
+        out.push_str("digraph adrs {\n");
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+
+        let node_line = |node: usize, indent: &str| {
+            let adr = &self.nodes[node];
+            format!(
+                "{indent}adr{node} [label=\"{}\\n{}\", fillcolor=\"{}\"];\n",
+                escape_dot(&adr.label()),
+                escape_dot(&adr.title),
+                status_color(&adr.status)
+            )
+        };
+
+        let (groups, ungrouped) = self.group_nodes(&nodes, options);
+        for (index, (tag, members)) in groups.iter().enumerate() {
+            out.push_str(&format!("  subgraph cluster_{index} {{\n"));
+            out.push_str(&format!("    label=\"{}\";\n", escape_dot(tag)));
+            out.push_str("    style=dashed;\n");
+            for &node in members {
+                out.push_str(&node_line(node, "    "));
+            }
+            out.push_str("  }\n");
+        }
+        for node in ungrouped {
+            out.push_str(&node_line(node, "  "));
+        }
+
+        for edge in self.visible_edges(&nodes) {
+            let attributes = match edge.kind {
+                EdgeKind::Supersedes => "label=\"supersedes\"",
+                EdgeKind::RelatesTo => "label=\"relates to\", style=dashed, dir=none",
+            };
+            out.push_str(&format!(
+                "  adr{} -> adr{} [{attributes}];\n",
+                edge.from, edge.to
+            ));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render as a Mermaid flowchart
+    pub fn to_mermaid(&self, options: &RenderOptions) -> String {
+        let nodes = self.visible_nodes(options);
+        let mut out = String::from("flowchart LR\n");
+
+        let node_line = |node: usize, indent: &str| {
+            let adr = &self.nodes[node];
+            format!(
+                "{indent}adr{node}[\"{}<br/>{}\"]\n",
+                escape_mermaid(&adr.label()),
+                escape_mermaid(&adr.title)
+            )
+        };
+
+        let (groups, ungrouped) = self.group_nodes(&nodes, options);
+        for (index, (tag, members)) in groups.iter().enumerate() {
+            out.push_str(&format!(
+                "  subgraph tag{index}[\"{}\"]\n",
+                escape_mermaid(tag)
+            ));
+            for &node in members {
+                out.push_str(&node_line(node, "    "));
+            }
+            out.push_str("  end\n");
+        }
+        for node in ungrouped {
+            out.push_str(&node_line(node, "  "));
+        }
+
+        for edge in self.visible_edges(&nodes) {
+            let arrow = match edge.kind {
+                EdgeKind::Supersedes => "-->|supersedes|",
+                EdgeKind::RelatesTo => "-.-|relates to|",
+            };
+            out.push_str(&format!("  adr{} {arrow} adr{}\n", edge.from, edge.to));
+        }
+
+        // One class per status that appears in the output
+        let mut by_status: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for &node in &nodes {
+            by_status
+                .entry(self.nodes[node].status.to_lowercase())
+                .or_default()
+                .push(node);
+        }
+        for (status, members) in by_status {
+            let class = class_name(&status);
+            out.push_str(&format!(
+                "  classDef {class} fill:{},stroke:#555\n",
+                status_color(&status)
+            ));
+            let ids: Vec<String> = members.iter().map(|n| format!("adr{n}")).collect();
+            out.push_str(&format!("  class {} {class}\n", ids.join(",")));
+        }
+
+        out
+    }
+
+    /// Nodes to render, in graph order
+    fn visible_nodes(&self, options: &RenderOptions) -> Vec<usize> {
+        match options.root {
+            Some(root) => self.subtree(root),
+            None => (0..self.nodes.len()).collect(),
+        }
+    }
+
+    /// Edges whose ends are both rendered
+    fn visible_edges<'a>(&'a self, nodes: &'a [usize]) -> impl Iterator<Item = &'a super::AdrEdge> {
+        self.edges
+            .iter()
+            .filter(move |e| nodes.contains(&e.from) && nodes.contains(&e.to))
+    }
+
+    /// Split nodes into tag clusters (first tag wins) and untagged nodes
+    fn group_nodes(
+        &self,
+        nodes: &[usize],
+        options: &RenderOptions,
+    ) -> (BTreeMap<String, Vec<usize>>, Vec<usize>) {
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        let mut ungrouped = Vec::new();
+
+        for &node in nodes {
+            match self.nodes[node].tags.first() {
+                Some(tag) if options.group_by_tag => {
+                    groups.entry(tag.clone()).or_default().push(node)
+                }
+                _ => ungrouped.push(node),
+            }
+        }
+
+        (groups, ungrouped)
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Mermaid class names must be plain identifiers
+fn class_name(status: &str) -> String {
+    let name: String = status
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("status_{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdrNode;
+    use std::path::PathBuf;
+
+    fn graph() -> AdrGraph {
+        let node =
+            |file: &str, title: &str, status: &str, tags: &[&str], supersedes: &[&str]| AdrNode {
+                path: PathBuf::from(file),
+                id: None,
+                title: title.to_string(),
+                status: status.to_string(),
+                tags: tags.iter().map(|s| s.to_string()).collect(),
+                supersedes: supersedes.iter().map(|s| s.to_string()).collect(),
+                relates_to: Vec::new(),
+                namespace: None,
+            };
+
+        let mut third = node("0003-cache.md", "Add \"Redis\" cache", "proposed", &[], &[]);
+        third.relates_to = vec!["2".to_string()];
+        AdrGraph::from_nodes(vec![
+            node(
+                "0001-mysql.md",
+                "Use MySQL",
+                "superseded",
+                &["database"],
+                &[],
+            ),
+            node(
+                "0002-postgres.md",
+                "Use PostgreSQL",
+                "accepted",
+                &["database"],
+                &["1"],
+            ),
+            third,
+        ])
+    }
+
+    #[test]
+    fn test_dot_output() {
+        let dot = graph().to_dot(&RenderOptions {
+            group_by_tag: true,
+            root: None,
+        });
+
+        assert!(dot.starts_with("digraph adrs {"));
+        assert!(dot.contains("subgraph cluster_0 {\n    label=\"database\";"));
+        assert!(
+            dot.contains("adr1 [label=\"0002-postgres\\nUse PostgreSQL\", fillcolor=\"#a3d977\"];")
+        );
+        assert!(dot.contains("Add \\\"Redis\\\" cache"));
+        assert!(dot.contains("adr1 -> adr0 [label=\"supersedes\"];"));
+        assert!(dot.contains("adr2 -> adr1 [label=\"relates to\", style=dashed, dir=none];"));
+    }
+
+    #[test]
+    fn test_mermaid_output() {
+        let mermaid = graph().to_mermaid(&RenderOptions::default());
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(!mermaid.contains("subgraph"));
+        assert!(mermaid.contains("adr2[\"0003-cache<br/>Add #quot;Redis#quot; cache\"]"));
+        assert!(mermaid.contains("adr1 -->|supersedes| adr0"));
+        assert!(mermaid.contains("classDef status_accepted fill:#a3d977,stroke:#555"));
+        assert!(mermaid.contains("class adr0 status_superseded"));
+    }
+
+    #[test]
+    fn test_subtree_rendering() {
+        let mermaid = graph().to_mermaid(&RenderOptions {
+            group_by_tag: false,
+            root: Some(1),
+        });
+
+        assert!(mermaid.contains("adr0["));
+        assert!(mermaid.contains("adr1["));
+        assert!(!mermaid.contains("adr2"));
+    }
+}
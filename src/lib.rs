@@ -7,10 +7,16 @@ pub mod commands;
 pub mod config;
 pub mod drift;
 pub mod error;
+pub mod git;
+pub mod graph;
+pub mod lint;
 #[cfg(feature = "lsp")]
 pub mod lsp;
 pub mod ml;
 pub mod parser;
+pub mod roots;
+pub mod search;
+pub mod status;
 
 // Plugin system (only available with plugins feature)
 #[cfg(feature = "plugins")]
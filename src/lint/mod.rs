@@ -0,0 +1,405 @@
+//! ADR Linting
+//!
+//! This module validates an ADR corpus against a registry of named rules.
+//! Every rule has a default severity that can be overridden, or turned off,
+//! per rule in the `lint.rules` section of `.adrscan.yml`:
+//!
+//! ```yaml
+//! lint:
+//!   rules:
+//!     future-date: error
+//!     missing-section: off
+//! ```
+
+pub mod report;
+pub mod rules;
+
+pub use report::{LintReport, RuleDescriptor};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::{LintConfig, LintLevel, StatusConfig};
+use crate::graph::{AdrGraph, DanglingReference};
+use crate::parser::{find_adr_files, AdrDocument, AdrParser};
+use crate::roots::AdrRoot;
+use crate::status::StatusModel;
+
+/// A problem found in an ADR file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintDiagnostic {
+    /// Name of the rule that produced the diagnostic
+    pub rule: String,
+
+    /// Configured severity of the rule
+    pub severity: LintLevel,
+
+    /// ADR file the diagnostic belongs to
+    pub path: PathBuf,
+
+    /// 1-based line in the file, if the problem has a location
+    pub line: Option<usize>,
+
+    /// Human-readable description
+    pub message: String,
+}
+
+/// A problem reported by a rule, before the file and severity are attached
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Finding {
+    /// Create a finding without a location
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attach a 1-based file line
+    pub fn at_line(mut self, line: Option<usize>) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+/// Corpus-wide information available to every rule
+pub struct LintContext<'a> {
+    /// Relationships between all ADRs that parsed successfully
+    pub graph: &'a AdrGraph,
+
+    /// Lint configuration
+    pub config: &'a LintConfig,
+
+    /// Configured status lifecycle
+    pub statuses: &'a StatusModel,
+
+    /// Reference date for date checks
+    pub today: NaiveDate,
+
+    /// Graph node of each ADR file
+    nodes: HashMap<&'a Path, usize>,
+
+    /// Nodes by namespace and normalized id or number
+    ids: HashMap<(Option<String>, String), Vec<usize>>,
+
+    /// Supersession cycles keyed by their first member
+    cycles: HashMap<usize, Vec<Vec<usize>>>,
+
+    /// Accepted ADRs that others supersede, with the superseding ADRs
+    stale_accepted: HashMap<usize, Vec<usize>>,
+
+    /// Unresolved references keyed by the node they start from
+    dangling: HashMap<usize, Vec<&'a DanglingReference>>,
+}
+
+impl<'a> LintContext<'a> {
+    /// Analyze the graph once for all rules and documents
+    pub fn new(
+        graph: &'a AdrGraph,
+        config: &'a LintConfig,
+        statuses: &'a StatusModel,
+        today: NaiveDate,
+    ) -> Self {
+        // Namespaced roots number their ADRs independently
+        let mut ids: HashMap<(Option<String>, String), Vec<usize>> = HashMap::new();
+        for (index, node) in graph.nodes.iter().enumerate() {
+            if let Some(number) = node.number() {
+                ids.entry((node.namespace.clone(), number))
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        let mut cycles: HashMap<usize, Vec<Vec<usize>>> = HashMap::new();
+        for cycle in graph.supersession_cycles() {
+            cycles.entry(cycle[0]).or_default().push(cycle);
+        }
+
+        let mut dangling: HashMap<usize, Vec<&DanglingReference>> = HashMap::new();
+        for reference in &graph.dangling {
+            dangling.entry(reference.from).or_default().push(reference);
+        }
+
+        Self {
+            graph,
+            config,
+            statuses,
+            today,
+            nodes: graph
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| (node.path.as_path(), index))
+                .collect(),
+            ids,
+            cycles,
+            stale_accepted: graph
+                .accepted_while_superseded()
+                .into_iter()
+                .map(|node| (node, graph.superseded_by(node)))
+                .collect(),
+            dangling,
+        }
+    }
+
+    /// Graph node of a document
+    pub fn node(&self, document: &AdrDocument) -> Option<usize> {
+        self.nodes.get(document.path.as_path()).copied()
+    }
+
+    /// Nodes of `namespace` whose id or number normalizes to `id`
+    pub fn nodes_with_id(&self, namespace: Option<&str>, id: &str) -> &[usize] {
+        self.ids
+            .get(&(namespace.map(str::to_string), id.to_string()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Supersession cycles starting at a node
+    pub fn cycles_from(&self, node: usize) -> &[Vec<usize>] {
+        self.cycles
+            .get(&node)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// ADRs superseding a node that is still accepted
+    pub fn stale_accepted(&self, node: usize) -> Option<&[usize]> {
+        self.stale_accepted.get(&node).map(Vec::as_slice)
+    }
+
+    /// References of a node that match no ADR
+    pub fn dangling_from(&self, node: usize) -> &[&'a DanglingReference] {
+        self.dangling
+            .get(&node)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// A named check run against each ADR
+pub trait LintRule: Send + Sync {
+    /// Stable rule name used in configuration and reports
+    fn name(&self) -> &'static str;
+
+    /// One-line description of what the rule checks
+    fn description(&self) -> &'static str;
+
+    /// Severity used when the configuration does not override it
+    fn default_level(&self) -> LintLevel;
+
+    /// Check one document
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding>;
+}
+
+/// Rule registry and runner
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    config: LintConfig,
+    statuses: StatusModel,
+}
+
+impl Linter {
+    /// Create a linter with the built-in rules
+    pub fn new(config: &LintConfig, statuses: &StatusConfig) -> Self {
+        Self {
+            rules: rules::builtin_rules(),
+            config: config.clone(),
+            statuses: StatusModel::new(statuses),
+        }
+    }
+
+    /// Registered rules with their effective severity
+    pub fn rule_descriptors(&self) -> Vec<RuleDescriptor> {
+        self.rules
+            .iter()
+            .map(|rule| RuleDescriptor {
+                name: rule.name().to_string(),
+                description: rule.description().to_string(),
+                level: self.level(rule.as_ref()),
+            })
+            .collect()
+    }
+
+    /// Rule names in the configuration that no registered rule answers to
+    pub fn unknown_rule_names(&self) -> Vec<&str> {
+        let mut unknown: Vec<&str> = self
+            .config
+            .rules
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| !self.rules.iter().any(|rule| rule.name() == *name))
+            .collect();
+        unknown.sort();
+        unknown
+    }
+
+    /// Effective severity of a rule
+    fn level(&self, rule: &dyn LintRule) -> LintLevel {
+        self.config
+            .rules
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.default_level())
+    }
+
+    /// Parse and lint the ADR files of every root together, so references
+    /// across namespaces resolve
+    pub fn lint_roots(&self, roots: &[AdrRoot], today: NaiveDate) -> LintReport {
+        let mut documents = Vec::new();
+        let mut diagnostics = Vec::new();
+        let mut files_checked = 0;
+        let parse_level = self
+            .config
+            .rules
+            .get(rules::PARSE_ERROR)
+            .copied()
+            .unwrap_or(LintLevel::Error);
+
+        for root in roots {
+            let paths = find_adr_files(&root.path);
+            files_checked += paths.len();
+            for path in paths {
+                match AdrParser::parse_file(&path) {
+                    Ok(mut document) => {
+                        document.namespace = root.namespace.clone();
+                        documents.push(document);
+                    }
+                    Err(e) if parse_level != LintLevel::Off => diagnostics.push(LintDiagnostic {
+                        rule: rules::PARSE_ERROR.to_string(),
+                        severity: parse_level,
+                        path,
+                        line: Some(1),
+                        message: e.to_string(),
+                    }),
+                    Err(_) => {}
+                }
+            }
+        }
+
+        diagnostics.extend(self.lint_documents(&documents, today));
+
+        LintReport {
+            files_checked,
+            diagnostics,
+            rules: self.rule_descriptors(),
+        }
+    }
+
+    /// Lint already-parsed documents
+    pub fn lint_documents(
+        &self,
+        documents: &[AdrDocument],
+        today: NaiveDate,
+    ) -> Vec<LintDiagnostic> {
+        let graph = AdrGraph::from_documents(documents);
+        let context = LintContext::new(&graph, &self.config, &self.statuses, today);
+
+        let mut diagnostics = Vec::new();
+        for document in documents {
+            for rule in &self.rules {
+                let level = self.level(rule.as_ref());
+                if level == LintLevel::Off {
+                    continue;
+                }
+
+                diagnostics.extend(rule.check(document, &context).into_iter().map(|finding| {
+                    LintDiagnostic {
+                        rule: rule.name().to_string(),
+                        severity: level,
+                        path: document.path.clone(),
+                        line: finding.line,
+                        message: finding.message,
+                    }
+                }));
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// File line of a 1-based line in [`AdrDocument::content`]
+pub fn body_line(document: &AdrDocument, line: usize) -> usize {
+    match document.frontmatter_format {
+        // Opening delimiter, frontmatter lines, closing delimiter
+        Some(_) => line + document.frontmatter.lines().count() + 2,
+        None => line,
+    }
+}
+
+/// File line of a top-level frontmatter key
+pub fn frontmatter_line(document: &AdrDocument, key: &str) -> Option<usize> {
+    document.frontmatter_format?;
+    document
+        .frontmatter
+        .lines()
+        .position(|line| {
+            line.strip_prefix(key)
+                .map(|rest| rest.trim_start().starts_with([':', '=']))
+                .unwrap_or(false)
+        })
+        .map(|index| index + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str, content: &str) -> AdrDocument {
+        AdrParser::parse_content(content, PathBuf::from(path)).unwrap()
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+    }
+
+    #[test]
+    fn test_rule_levels_follow_config() {
+        let mut config = LintConfig::default();
+        config
+            .rules
+            .insert("missing-section".to_string(), LintLevel::Off);
+        config
+            .rules
+            .insert("unknown-status".to_string(), LintLevel::Error);
+        config
+            .rules
+            .insert("no-such-rule".to_string(), LintLevel::Info);
+
+        let linter = Linter::new(&config, &StatusConfig::default());
+        let document = parse("0001-x.md", "---\nstatus: draft\n---\n# X\n");
+        let diagnostics = linter.lint_documents(&[document], today());
+
+        assert!(diagnostics.iter().all(|d| d.rule != "missing-section"));
+        let unknown = diagnostics
+            .iter()
+            .find(|d| d.rule == "unknown-status")
+            .unwrap();
+        assert_eq!(unknown.severity, LintLevel::Error);
+        assert_eq!(unknown.line, Some(2));
+        assert_eq!(linter.unknown_rule_names(), vec!["no-such-rule"]);
+    }
+
+    #[test]
+    fn test_line_mapping() {
+        let document = parse(
+            "0001-x.md",
+            "---\ntitle: X\nstatus: accepted\n---\n# X\n\n## Context\n",
+        );
+        assert_eq!(frontmatter_line(&document, "status"), Some(3));
+        assert_eq!(frontmatter_line(&document, "date"), None);
+        assert_eq!(body_line(&document, 3), 7);
+
+        let plain = parse("0002-y.md", "# Y\n");
+        assert_eq!(frontmatter_line(&plain, "status"), None);
+        assert_eq!(body_line(&plain, 1), 1);
+    }
+}
@@ -0,0 +1,216 @@
+//! Lint Report Output
+//!
+//! Console, JSON and SARIF 2.1.0 renderings of a lint run. SARIF output can
+//! be uploaded to code scanning services to annotate ADRs in pull requests.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::LintDiagnostic;
+use crate::config::LintLevel;
+
+/// A registered rule and its effective severity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleDescriptor {
+    pub name: String,
+    pub description: String,
+    pub level: LintLevel,
+}
+
+/// Result of linting an ADR corpus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintReport {
+    /// Number of ADR files examined
+    pub files_checked: usize,
+
+    /// Problems found, grouped by file in scan order
+    pub diagnostics: Vec<LintDiagnostic>,
+
+    /// Rules that were registered for the run
+    #[serde(skip)]
+    pub rules: Vec<RuleDescriptor>,
+}
+
+impl LintReport {
+    /// Number of diagnostics at the given severity
+    pub fn count(&self, level: LintLevel) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == level)
+            .count()
+    }
+
+    /// Whether the run should fail
+    pub fn is_failure(&self, deny_warnings: bool) -> bool {
+        self.count(LintLevel::Error) > 0 || (deny_warnings && self.count(LintLevel::Warning) > 0)
+    }
+
+    /// Export report to JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&json!({
+            "files_checked": self.files_checked,
+            "errors": self.count(LintLevel::Error),
+            "warnings": self.count(LintLevel::Warning),
+            "infos": self.count(LintLevel::Info),
+            "diagnostics": self.diagnostics,
+        }))
+    }
+
+    /// Export report as a SARIF 2.1.0 log
+    pub fn to_sarif(&self) -> serde_json::Result<String> {
+        let rules: Vec<_> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "id": rule.name,
+                    "shortDescription": { "text": rule.description },
+                    "defaultConfiguration": { "level": sarif_level(rule.level) },
+                })
+            })
+            .collect();
+
+        let results: Vec<_> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let mut location = json!({
+                    "artifactLocation": {
+                        "uri": diagnostic.path.to_string_lossy().replace('\\', "/"),
+                    },
+                });
+                if let Some(line) = diagnostic.line {
+                    location["region"] = json!({ "startLine": line });
+                }
+
+                json!({
+                    "ruleId": diagnostic.rule,
+                    "level": sarif_level(diagnostic.severity),
+                    "message": { "text": diagnostic.message },
+                    "locations": [{ "physicalLocation": location }],
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "adrscan",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        }))
+    }
+
+    /// Print a human-readable console report
+    pub fn print_console(&self) {
+        println!("🔎 ADR Lint Report");
+        println!("==================");
+        println!();
+
+        let mut current_file = None;
+        for diagnostic in &self.diagnostics {
+            if current_file != Some(&diagnostic.path) {
+                if current_file.is_some() {
+                    println!();
+                }
+                println!("📄 {}", diagnostic.path.display());
+                current_file = Some(&diagnostic.path);
+            }
+
+            let icon = match diagnostic.severity {
+                LintLevel::Error => "🔴",
+                LintLevel::Warning => "🟡",
+                _ => "🔵",
+            };
+            let line = diagnostic.line.map(|l| format!("{l}:")).unwrap_or_default();
+            println!(
+                "  {icon} {line} {} [{}]",
+                diagnostic.message, diagnostic.rule
+            );
+        }
+        if !self.diagnostics.is_empty() {
+            println!();
+        }
+
+        println!(
+            "📊 {} files checked: {} errors, {} warnings, {} infos",
+            self.files_checked,
+            self.count(LintLevel::Error),
+            self.count(LintLevel::Warning),
+            self.count(LintLevel::Info)
+        );
+        if self.diagnostics.is_empty() {
+            println!("✅ No problems found!");
+        }
+    }
+}
+
+fn sarif_level(level: LintLevel) -> &'static str {
+    match level {
+        LintLevel::Error => "error",
+        LintLevel::Warning => "warning",
+        LintLevel::Info => "note",
+        LintLevel::Off => "none",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn sample_report() -> LintReport {
+        LintReport {
+            files_checked: 2,
+            diagnostics: vec![LintDiagnostic {
+                rule: "missing-status".to_string(),
+                severity: LintLevel::Error,
+                path: PathBuf::from("docs/adr/0001-a.md"),
+                line: Some(1),
+                message: "ADR has no status".to_string(),
+            }],
+            rules: vec![RuleDescriptor {
+                name: "missing-status".to_string(),
+                description: "ADR declares no status".to_string(),
+                level: LintLevel::Error,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_failure_threshold() {
+        let mut report = sample_report();
+        assert!(report.is_failure(false));
+
+        report.diagnostics[0].severity = LintLevel::Warning;
+        assert!(!report.is_failure(false));
+        assert!(report.is_failure(true));
+    }
+
+    #[test]
+    fn test_sarif_output() {
+        let sarif: serde_json::Value =
+            serde_json::from_str(&sample_report().to_sarif().unwrap()).unwrap();
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "missing-status");
+        let result = &run["results"][0];
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "docs/adr/0001-a.md"
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+    }
+}
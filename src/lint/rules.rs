@@ -0,0 +1,536 @@
+//! Built-in Lint Rules
+
+use std::path::Path;
+
+use super::{body_line, frontmatter_line, Finding, LintContext, LintRule};
+use crate::config::LintLevel;
+use crate::parser::{AdrDocument, AdrParser, SectionKind};
+use crate::status::STATUS_HISTORY_FIELD;
+
+/// Name of the rule reported when a file cannot be parsed at all
+pub const PARSE_ERROR: &str = "parse-error";
+
+/// All built-in rules in reporting order
+pub fn builtin_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(ParseError),
+        Box::new(MissingStatus),
+        Box::new(UnknownStatus),
+        Box::new(InvalidTransition),
+        Box::new(MissingSection),
+        Box::new(EmptySection),
+        Box::new(BrokenReference),
+        Box::new(DuplicateId),
+        Box::new(SupersessionCycle),
+        Box::new(AcceptedButSuperseded),
+        Box::new(FutureDate),
+    ]
+}
+
+/// Invalid frontmatter; reported by the linter while parsing
+struct ParseError;
+
+impl LintRule for ParseError {
+    fn name(&self) -> &'static str {
+        PARSE_ERROR
+    }
+
+    fn description(&self) -> &'static str {
+        "ADR file cannot be parsed (for example, malformed frontmatter)"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, _document: &AdrDocument, _context: &LintContext) -> Vec<Finding> {
+        Vec::new()
+    }
+}
+
+/// No status in the frontmatter or the body
+struct MissingStatus;
+
+impl LintRule for MissingStatus {
+    fn name(&self) -> &'static str {
+        "missing-status"
+    }
+
+    fn description(&self) -> &'static str {
+        "ADR declares no status in its frontmatter or body"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, document: &AdrDocument, _context: &LintContext) -> Vec<Finding> {
+        if has_status(document) {
+            return Vec::new();
+        }
+        vec![Finding::new(
+            "ADR has no status; add a `status` frontmatter field or a `## Status` section",
+        )
+        .at_line(Some(1))]
+    }
+}
+
+/// Status outside the configured set
+struct UnknownStatus;
+
+impl LintRule for UnknownStatus {
+    fn name(&self) -> &'static str {
+        "unknown-status"
+    }
+
+    fn description(&self) -> &'static str {
+        "Status is not one of the states listed in `status.states`"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let status = &document.metadata.status;
+        if !has_status(document) || context.statuses.canonical(status).is_some() {
+            return Vec::new();
+        }
+
+        let line = frontmatter_line(document, "status").or_else(|| {
+            document
+                .sections
+                .find(SectionKind::Status)
+                .map(|s| body_line(document, s.line))
+        });
+        vec![Finding::new(format!(
+            "Unknown status '{}' (expected one of: {})",
+            status,
+            context.statuses.states().join(", ")
+        ))
+        .at_line(line)]
+    }
+}
+
+/// Recorded status history breaks the configured lifecycle
+struct InvalidTransition;
+
+impl LintRule for InvalidTransition {
+    fn name(&self) -> &'static str {
+        "invalid-transition"
+    }
+
+    fn description(&self) -> &'static str {
+        "`status_history` records a transition not allowed by `status.transitions`"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let line = frontmatter_line(document, STATUS_HISTORY_FIELD);
+        context
+            .statuses
+            .check_history(document)
+            .into_iter()
+            .map(|problem| Finding::new(problem).at_line(line))
+            .collect()
+    }
+}
+
+/// Required section absent
+struct MissingSection;
+
+impl LintRule for MissingSection {
+    fn name(&self) -> &'static str {
+        "missing-section"
+    }
+
+    fn description(&self) -> &'static str {
+        "A section listed in `lint.required_sections` is missing"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        context
+            .config
+            .required_sections
+            .iter()
+            .filter(|name| find_section(document, name).is_none())
+            .map(|name| Finding::new(format!("Missing required section: {name}")).at_line(Some(1)))
+            .collect()
+    }
+}
+
+/// Required section present without content
+struct EmptySection;
+
+impl LintRule for EmptySection {
+    fn name(&self) -> &'static str {
+        "empty-section"
+    }
+
+    fn description(&self) -> &'static str {
+        "A section listed in `lint.required_sections` has no content"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        context
+            .config
+            .required_sections
+            .iter()
+            .filter_map(|name| find_section(document, name))
+            .filter(|section| section.is_empty())
+            .map(|section| {
+                Finding::new(format!(
+                    "Empty section: {} {}",
+                    "#".repeat(section.level as usize),
+                    section.heading
+                ))
+                .at_line(Some(body_line(document, section.line)))
+            })
+            .collect()
+    }
+}
+
+/// `supersedes` / `relates_to` entries that match no ADR
+struct BrokenReference;
+
+impl LintRule for BrokenReference {
+    fn name(&self) -> &'static str {
+        "broken-reference"
+    }
+
+    fn description(&self) -> &'static str {
+        "A `supersedes` or `relates_to` entry does not match any ADR"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let Some(node) = context.node(document) else {
+            return Vec::new();
+        };
+
+        context
+            .dangling_from(node)
+            .iter()
+            .map(|dangling| {
+                let field = dangling.kind.field();
+                Finding::new(format!(
+                    "{field} reference '{}' matches no ADR",
+                    dangling.reference
+                ))
+                .at_line(frontmatter_line(document, field))
+            })
+            .collect()
+    }
+}
+
+/// Two ADRs sharing an id or number
+struct DuplicateId;
+
+impl LintRule for DuplicateId {
+    fn name(&self) -> &'static str {
+        "duplicate-id"
+    }
+
+    fn description(&self) -> &'static str {
+        "Two or more ADRs share the same id or number"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let graph = context.graph;
+        let Some(node) = context.node(document) else {
+            return Vec::new();
+        };
+        let Some(id) = graph.nodes[node].number() else {
+            return Vec::new();
+        };
+
+        let others: Vec<String> = context
+            .nodes_with_id(graph.nodes[node].namespace.as_deref(), &id)
+            .iter()
+            .filter(|&&other| other != node)
+            .map(|&other| file_name(&graph.nodes[other].path))
+            .collect();
+        if others.is_empty() {
+            return Vec::new();
+        }
+
+        let line =
+            frontmatter_line(document, "id").or_else(|| frontmatter_line(document, "number"));
+        vec![
+            Finding::new(format!("ADR id {id} is also used by {}", others.join(", ")))
+                .at_line(line),
+        ]
+    }
+}
+
+/// ADRs that supersede each other in a loop
+struct SupersessionCycle;
+
+impl LintRule for SupersessionCycle {
+    fn name(&self) -> &'static str {
+        "supersession-cycle"
+    }
+
+    fn description(&self) -> &'static str {
+        "ADRs supersede each other in a loop"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Error
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let graph = context.graph;
+        let Some(node) = context.node(document) else {
+            return Vec::new();
+        };
+
+        // Report each cycle once, on its first member
+        context
+            .cycles_from(node)
+            .iter()
+            .map(|cycle| {
+                let labels: Vec<String> = cycle.iter().map(|&n| graph.nodes[n].label()).collect();
+                Finding::new(format!(
+                    "Supersession cycle: {} -> {}",
+                    labels.join(" -> "),
+                    labels[0]
+                ))
+                .at_line(frontmatter_line(document, "supersedes"))
+            })
+            .collect()
+    }
+}
+
+/// Accepted ADRs that another ADR supersedes
+struct AcceptedButSuperseded;
+
+impl LintRule for AcceptedButSuperseded {
+    fn name(&self) -> &'static str {
+        "accepted-superseded"
+    }
+
+    fn description(&self) -> &'static str {
+        "ADR is still accepted although another ADR supersedes it"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        let graph = context.graph;
+        let Some(superseded_by) = context
+            .node(document)
+            .and_then(|node| context.stale_accepted(node))
+        else {
+            return Vec::new();
+        };
+
+        let superseded_by: Vec<String> = superseded_by
+            .iter()
+            .map(|&n| graph.nodes[n].label())
+            .collect();
+        vec![Finding::new(format!(
+            "ADR is accepted but superseded by {}; set its status to superseded",
+            superseded_by.join(", ")
+        ))
+        .at_line(frontmatter_line(document, "status"))]
+    }
+}
+
+/// Decision dates after today
+struct FutureDate;
+
+impl LintRule for FutureDate {
+    fn name(&self) -> &'static str {
+        "future-date"
+    }
+
+    fn description(&self) -> &'static str {
+        "Decision date lies in the future"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warning
+    }
+
+    fn check(&self, document: &AdrDocument, context: &LintContext) -> Vec<Finding> {
+        match document.metadata.date {
+            Some(date) if date > context.today => {
+                vec![Finding::new(format!("Date {date} is in the future"))
+                    .at_line(frontmatter_line(document, "date"))]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Whether a status was written anywhere, as opposed to the parser default
+fn has_status(document: &AdrDocument) -> bool {
+    AdrParser::frontmatter_keys(&document.frontmatter).contains("status")
+        || document
+            .metadata
+            .inferred_fields
+            .iter()
+            .any(|f| f == "status")
+}
+
+/// Required section by name: a known section kind, or an exact heading
+fn find_section<'a>(
+    document: &'a AdrDocument,
+    name: &str,
+) -> Option<&'a crate::parser::AdrSection> {
+    match SectionKind::from_heading(name) {
+        SectionKind::Other => document
+            .sections
+            .iter()
+            .find(|s| s.heading.eq_ignore_ascii_case(name.trim())),
+        kind => document.sections.find(kind),
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{LintConfig, StatusConfig};
+    use crate::lint::Linter;
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+
+    fn parse(path: &str, content: &str) -> AdrDocument {
+        AdrParser::parse_content(content, PathBuf::from(path)).unwrap()
+    }
+
+    fn rules_hit(documents: &[AdrDocument], path: &str) -> Vec<String> {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        Linter::new(&LintConfig::default(), &StatusConfig::default())
+            .lint_documents(documents, today)
+            .into_iter()
+            .filter(|d| d.path == Path::new(path))
+            .map(|d| d.rule)
+            .collect()
+    }
+
+    const COMPLETE: &str = "## Context\n\nWhy.\n\n## Decision\n\nWhat.\n";
+
+    #[test]
+    fn test_clean_corpus() {
+        let documents = vec![
+            parse(
+                "0001-a.md",
+                &format!("---\nstatus: superseded\ndate: 2024-01-01\n---\n# A\n\n{COMPLETE}"),
+            ),
+            parse(
+                "0002-b.md",
+                &format!("---\nstatus: accepted\nsupersedes: [ADR-0001]\nrelates_to: [0001-a.md]\n---\n# B\n\n{COMPLETE}"),
+            ),
+        ];
+        assert!(rules_hit(&documents, "0001-a.md").is_empty());
+        assert!(rules_hit(&documents, "0002-b.md").is_empty());
+    }
+
+    #[test]
+    fn test_status_and_section_rules() {
+        let documents = vec![
+            parse("0001-a.md", "# A\n\n## Context\n\n## Decision\n\nWhat.\n"),
+            parse(
+                "0002-b.md",
+                &format!("# B\n\n## Status\n\nDraft\n\n{COMPLETE}"),
+            ),
+        ];
+        assert_eq!(
+            rules_hit(&documents, "0001-a.md"),
+            vec!["missing-status", "empty-section"]
+        );
+        assert_eq!(rules_hit(&documents, "0002-b.md"), vec!["unknown-status"]);
+
+        let nygard = vec![parse("0003-c.md", "---\nstatus: proposed\n---\n# C\n")];
+        assert_eq!(
+            rules_hit(&nygard, "0003-c.md"),
+            vec!["missing-section", "missing-section"]
+        );
+    }
+
+    #[test]
+    fn test_reference_id_and_date_rules() {
+        let documents = vec![
+            parse(
+                "0001-a.md",
+                &format!("---\nstatus: accepted\nsupersedes: [0009-missing.md]\ndate: 2030-01-01\n---\n# A\n\n{COMPLETE}"),
+            ),
+            parse(
+                "0001-b.md",
+                &format!("---\nstatus: accepted\n---\n# B\n\n{COMPLETE}"),
+            ),
+        ];
+        assert_eq!(
+            rules_hit(&documents, "0001-a.md"),
+            vec!["broken-reference", "duplicate-id", "future-date"]
+        );
+        assert_eq!(rules_hit(&documents, "0001-b.md"), vec!["duplicate-id"]);
+    }
+
+    #[test]
+    fn test_supersession_rules() {
+        let documents = vec![
+            parse(
+                "0001-a.md",
+                &format!("---\nstatus: accepted\nsupersedes: [\"2\"]\n---\n# A\n\n{COMPLETE}"),
+            ),
+            parse(
+                "0002-b.md",
+                &format!("---\nstatus: superseded\nsupersedes: [\"1\"]\n---\n# B\n\n{COMPLETE}"),
+            ),
+        ];
+        assert_eq!(
+            rules_hit(&documents, "0001-a.md"),
+            vec!["supersession-cycle", "accepted-superseded"]
+        );
+        assert!(rules_hit(&documents, "0002-b.md").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_transition_rule() {
+        let history = "status_history:\n  - from: proposed\n    to: accepted\n    date: 2024-01-02";
+        let documents = vec![
+            parse(
+                "0001-a.md",
+                &format!("---\nstatus: accepted\n{history}\n---\n# A\n\n{COMPLETE}"),
+            ),
+            parse(
+                "0002-b.md",
+                &format!("---\nstatus: rejected\n{history}\n---\n# B\n\n{COMPLETE}"),
+            ),
+        ];
+        assert!(rules_hit(&documents, "0001-a.md").is_empty());
+        assert_eq!(
+            rules_hit(&documents, "0002-b.md"),
+            vec!["invalid-transition"]
+        );
+    }
+}
@@ -7,9 +7,11 @@ use lsp_types::{
     DocumentSymbolResponse, TextEdit, WorkspaceEdit,
 };
 use std::collections::HashMap;
+use std::path::PathBuf;
 use tower_lsp::jsonrpc::Result;
 
 use crate::lsp::protocol::{extract_adr_number, line_range};
+use crate::parser::{AdrParser, AdrSections, SectionKind};
 
 /// Additional handlers for extended LSP functionality
 pub struct LspHandlers;
@@ -121,11 +123,19 @@ impl LspHandlers {
     /// Validate ADR structure and provide suggestions
     pub fn validate_adr_structure(&self, content: &str) -> Vec<String> {
         let mut issues = Vec::new();
+        // Sections of the body, after any frontmatter
+        let sections = AdrParser::parse_content(content, PathBuf::new())
+            .map(|document| document.sections)
+            .unwrap_or_else(|_| AdrSections::parse(content));
 
         // Check for required sections
-        let required_sections = ["status", "context", "decision"];
-        for section in required_sections {
-            if !content.to_lowercase().contains(&format!("## {}", section)) {
+        let required_sections = [
+            ("status", SectionKind::Status),
+            ("context", SectionKind::Context),
+            ("decision", SectionKind::DecisionOutcome),
+        ];
+        for (section, kind) in required_sections {
+            if !sections.has(kind) {
                 issues.push(format!("Missing required section: {}", section));
             }
         }
@@ -136,25 +146,12 @@ impl LspHandlers {
         }
 
         // Check for empty sections
-        let lines: Vec<&str> = content.lines().collect();
-        for (i, line) in lines.iter().enumerate() {
-            if line.starts_with("## ") {
-                // Check if the section has content
-                let mut has_content = false;
-                for next_line in lines.iter().skip(i + 1) {
-                    if next_line.starts_with("## ") {
-                        break;
-                    }
-                    if !next_line.trim().is_empty() {
-                        has_content = true;
-                        break;
-                    }
-                }
-
-                if !has_content {
-                    issues.push(format!("Empty section: {}", line.trim()));
-                }
-            }
+        for section in sections.iter().filter(|s| s.is_empty()) {
+            issues.push(format!(
+                "Empty section: {} {}",
+                "#".repeat(section.level as usize),
+                section.heading
+            ));
         }
 
         issues
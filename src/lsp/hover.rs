@@ -4,12 +4,24 @@
 
 use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
 
+use crate::config::StatusConfig;
+use crate::status::{normalize, StatusModel};
+
 /// Provides contextual hover information for ADR elements
-pub struct HoverProvider;
+pub struct HoverProvider {
+    statuses: StatusModel,
+}
 
 impl HoverProvider {
     pub fn new() -> Self {
-        Self
+        Self::with_status_config(&StatusConfig::default())
+    }
+
+    /// Recognize the states configured under `status` instead of the defaults
+    pub fn with_status_config(config: &StatusConfig) -> Self {
+        Self {
+            statuses: StatusModel::new(config),
+        }
     }
 
     /// Get hover information for the given position in the document
@@ -107,14 +119,12 @@ ADR references help maintain traceability between related architectural decision
     }
 
     fn is_status_value(&self, word: &str) -> bool {
-        matches!(
-            word.to_lowercase().as_str(),
-            "proposed" | "accepted" | "deprecated" | "superseded" | "rejected"
-        )
+        self.statuses.canonical(word).is_some()
     }
 
     fn get_status_hover(&self, status: &str) -> Option<Hover> {
-        let (description, guidance) = match status.to_lowercase().as_str() {
+        let status = self.statuses.canonical(status)?;
+        let (description, guidance) = match normalize(status).as_str() {
             "proposed" => (
                 "This ADR is under consideration and not yet implemented.",
                 "Use this status while the decision is being discussed and refined."
@@ -135,7 +145,14 @@ ADR references help maintain traceability between related architectural decision
                 "This ADR was considered but ultimately not adopted.",
                 "Useful for documenting why certain approaches were not taken."
             ),
-            _ => return None,
+            _ => (
+                "This is a project-specific status from the `status.states` configuration.",
+                "Check the project's conventions for when to use this status."
+            ),
+        };
+        let next = match self.statuses.transitions_from(status).as_slice() {
+            [] => "none".to_string(),
+            targets => targets.join(", "),
         };
 
         Some(Hover {
@@ -147,10 +164,13 @@ ADR references help maintain traceability between related architectural decision
 {}
 
 **Guidance:** {}
+
+**Next statuses:** {}
 "#,
                     status.to_uppercase(),
                     description,
-                    guidance
+                    guidance,
+                    next
                 ),
             }),
             range: None,
@@ -158,11 +178,12 @@ ADR references help maintain traceability between related architectural decision
     }
 
     fn get_section_hover(&self, section: &str) -> Option<Hover> {
+        let states = format!("Should be one of: {}.", self.statuses.states().join(", "));
         let info = match section.to_lowercase().as_str() {
             "status" => (
                 "Status Section",
                 "Indicates the current state of this architectural decision.",
-                "Should be one of: Proposed, Accepted, Deprecated, Superseded, or Rejected."
+                states.as_str(),
             ),
             "context" => (
                 "Context Section", 
@@ -402,4 +423,32 @@ mod tests {
         assert!(provider.is_status_value("DEPRECATED"));
         assert!(!provider.is_status_value("Unknown"));
     }
+
+    #[tokio::test]
+    async fn test_custom_status_hover() {
+        let config = StatusConfig {
+            states: vec![
+                "Draft".to_string(),
+                "In Review".to_string(),
+                "Accepted".to_string(),
+            ],
+            ..StatusConfig::default()
+        };
+        let provider = HoverProvider::with_status_config(&config);
+
+        assert!(provider.is_status_value("draft"));
+        assert!(!provider.is_status_value("Proposed"));
+
+        let content = "## Status\nDraft";
+        let position = Position {
+            line: 1,
+            character: 1,
+        };
+        let hover = provider.get_hover_info(content, position).await.unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown hover");
+        };
+        assert!(markup.value.contains("DRAFT"));
+        assert!(markup.value.contains("project-specific"));
+    }
 }
@@ -16,6 +16,7 @@ use lsp_types::{
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
+use crate::config::Config;
 use crate::lsp::{CompletionProvider, DiagnosticEngine, DocumentStore, HoverProvider, LspConfig};
 
 /// PhotonDrift Language Server implementation
@@ -25,7 +26,7 @@ pub struct PhotonDriftLspServer {
     config: Arc<RwLock<LspConfig>>,
     diagnostic_engine: DiagnosticEngine,
     completion_provider: CompletionProvider,
-    hover_provider: HoverProvider,
+    hover_provider: RwLock<HoverProvider>,
 }
 
 impl PhotonDriftLspServer {
@@ -36,7 +37,7 @@ impl PhotonDriftLspServer {
             config: Arc::new(RwLock::new(LspConfig::default())),
             diagnostic_engine: DiagnosticEngine::new(),
             completion_provider: CompletionProvider::new(),
-            hover_provider: HoverProvider::new(),
+            hover_provider: RwLock::new(HoverProvider::new()),
         }
     }
 }
@@ -53,6 +54,22 @@ impl LanguageServer for PhotonDriftLspServer {
         if let Some(workspace_folders) = params.workspace_folders {
             if let Some(folder) = workspace_folders.first() {
                 if let Ok(path) = folder.uri.to_file_path() {
+                    // Hover on status values follows the project's status model
+                    match Config::from_workspace_root(&path) {
+                        Ok(project) => {
+                            *self.hover_provider.write().await =
+                                HoverProvider::with_status_config(&project.status);
+                        }
+                        Err(e) => {
+                            self.client
+                                .log_message(
+                                    MessageType::WARNING,
+                                    format!("Using default configuration: {e}"),
+                                )
+                                .await;
+                        }
+                    }
+
                     let mut config = self.config.write().await;
                     config.workspace_root = Some(path);
                 }
@@ -193,7 +210,12 @@ impl LanguageServer for PhotonDriftLspServer {
         // Get document content
         let documents = self.documents.read().await;
         if let Some(content) = documents.get(&uri) {
-            Ok(self.hover_provider.get_hover_info(content, position).await)
+            Ok(self
+                .hover_provider
+                .read()
+                .await
+                .get_hover_info(content, position)
+                .await)
         } else {
             Ok(None)
         }
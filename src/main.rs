@@ -5,13 +5,21 @@ mod commands;
 mod config;
 mod drift;
 mod error;
+mod git;
+mod graph;
+mod lint;
 mod parser;
+mod roots;
+mod search;
+mod status;
 
 use crate::commands::{
-    diff::DiffCommand, index::IndexCommand, init::InitCommand, inventory::InventoryCommand,
-    propose::ProposeCommand,
+    config::ConfigCommand, diff::DiffCommand, graph::GraphCommand, history::HistoryCommand,
+    index::IndexCommand, init::InitCommand, inventory::InventoryCommand, lint::LintCommand,
+    new::NewCommand, propose::ProposeCommand, search::SearchCommand, status::StatusCommand,
+    supersede::SupersedeCommand,
 };
-use crate::config::Config;
+use crate::config::{Config, ConfigOverrides};
 
 #[derive(Parser)]
 #[command(name = "adrscan")]
@@ -28,6 +36,9 @@ struct Cli {
     /// Verbose output
     #[arg(short, long, global = true)]
     verbose: bool,
+
+    #[command(flatten)]
+    overrides: ConfigOverrides,
 }
 
 #[derive(Subcommand)]
@@ -38,10 +49,26 @@ enum Commands {
     Inventory(InventoryCommand),
     /// Perform drift detection by diffing current state against baseline
     Diff(DiffCommand),
+    /// Create a new ADR from the configured template
+    New(NewCommand),
     /// Auto-generate draft ADRs for detected drift
     Propose(ProposeCommand),
     /// Generate or update an index of ADRs
     Index(IndexCommand),
+    /// Validate ADRs against configurable lint rules
+    Lint(LintCommand),
+    /// Render the ADR relationship graph as DOT or Mermaid
+    Graph(GraphCommand),
+    /// Move an ADR to a new status
+    Status(StatusCommand),
+    /// Show how an ADR changed over time according to git
+    History(HistoryCommand),
+    /// Search ADRs by metadata and full text
+    Search(SearchCommand),
+    /// Mark an ADR as superseded by another and link the two
+    Supersede(SupersedeCommand),
+    /// Inspect the configuration and where its values come from
+    Config(ConfigCommand),
 }
 
 fn main() {
@@ -58,8 +85,18 @@ fn main() {
         .filter_level(log_level)
         .init();
 
+    // Config commands load the configuration themselves, so that they can
+    // report on loading it
+    if let Commands::Config(ref cmd) = cli.command {
+        if let Err(e) = cmd.execute(cli.config.as_deref(), &cli.overrides) {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+        return;
+    }
+
     // Load configuration
-    let config = match Config::load(cli.config.as_deref()) {
+    let config = match Config::load_with_overrides(cli.config.as_deref(), &cli.overrides) {
         Ok(config) => config,
         Err(e) => {
             eprintln!("Error loading configuration: {e}");
@@ -72,8 +109,16 @@ fn main() {
         Commands::Init(cmd) => cmd.execute(&config),
         Commands::Inventory(cmd) => cmd.execute(&config),
         Commands::Diff(cmd) => cmd.execute(&config),
+        Commands::New(cmd) => cmd.execute(&config),
         Commands::Propose(cmd) => cmd.execute(&config),
         Commands::Index(cmd) => cmd.execute(&config),
+        Commands::Lint(cmd) => cmd.execute(&config),
+        Commands::Graph(cmd) => cmd.execute(&config),
+        Commands::Status(cmd) => cmd.execute(&config),
+        Commands::Search(cmd) => cmd.execute(&config),
+        Commands::History(cmd) => cmd.execute(&config),
+        Commands::Supersede(cmd) => cmd.execute(&config),
+        Commands::Config(_) => unreachable!("handled before loading the configuration"),
     };
 
     if let Err(e) = result {
@@ -217,6 +217,7 @@ impl FeatureExtractor {
             DriftCategory::Database => 5,
             DriftCategory::Infrastructure => 8,
             DriftCategory::Framework => 6,
+            DriftCategory::VersionDrift => 3,
         };
 
         let severity_multiplier = match drift_item.severity {
@@ -255,6 +256,7 @@ impl FeatureExtractor {
             DriftCategory::Database => 30,
             DriftCategory::Infrastructure => 40,
             DriftCategory::Framework => 35,
+            DriftCategory::VersionDrift => 15,
         };
 
         let severity_multiplier = match drift_item.severity {
@@ -329,6 +331,7 @@ impl FeatureExtractor {
             crate::drift::DriftCategory::Database => 0.6,
             crate::drift::DriftCategory::Infrastructure => 0.8,
             crate::drift::DriftCategory::Framework => 0.7,
+            crate::drift::DriftCategory::VersionDrift => 0.5,
         };
 
         // Description complexity (length and technical terms)
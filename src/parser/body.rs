@@ -0,0 +1,305 @@
+//! Body Metadata Extraction
+//!
+//! This module infers ADR metadata from the structure of the markdown body,
+//! covering MADR and Nygard style ADRs that carry little or no frontmatter:
+//!
+//! - title from the first `# ` heading (`# 1. Title` also yields the id)
+//! - status from a `## Status` section or a `* Status:` header bullet
+//! - date and deciders from `* Date:` / `* Deciders:` header bullets, or a
+//!   plain `Date:` line as written by adr-tools
+//! - supersession links from `Supersedes [...](...)` lines in `## Status`
+
+use chrono::NaiveDate;
+use regex::Regex;
+use std::collections::HashSet;
+
+use super::AdrMetadata;
+
+/// Metadata recovered from the markdown body
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BodyMetadata {
+    /// Number prefix of the title heading
+    pub id: Option<String>,
+
+    /// Text of the first level-1 heading
+    pub title: Option<String>,
+
+    /// Normalized status phrase
+    pub status: Option<String>,
+
+    /// Decision date
+    pub date: Option<NaiveDate>,
+
+    /// Decision makers
+    pub deciders: Vec<String>,
+
+    /// ADRs this one supersedes (link targets)
+    pub supersedes: Vec<String>,
+}
+
+impl BodyMetadata {
+    /// Extract metadata from a markdown body
+    pub fn extract(content: &str) -> Self {
+        let numbered_title = Regex::new(r"^(\d+)[.:]?\s+(.+)$").unwrap();
+
+        let mut metadata = Self::default();
+        let mut section: Option<String> = None;
+        let mut in_code_block = false;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block || trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some(heading) = trimmed.strip_prefix("# ") {
+                if metadata.title.is_none() {
+                    let heading = heading.trim();
+                    match numbered_title.captures(heading) {
+                        Some(captures) => {
+                            metadata.id = Some(captures[1].to_string());
+                            metadata.title = Some(captures[2].trim().to_string());
+                        }
+                        None => metadata.title = Some(heading.to_string()),
+                    }
+                }
+                continue;
+            }
+
+            if let Some(heading) = trimmed.strip_prefix("## ") {
+                section = Some(heading.trim().to_lowercase());
+                continue;
+            }
+
+            match section.as_deref() {
+                // Header area between the title and the first section
+                None => metadata.extract_header_line(trimmed),
+                Some("status") => metadata.extract_status_line(trimmed),
+                Some(_) => {}
+            }
+        }
+
+        metadata
+    }
+
+    /// Handle `* Key: value` bullets and plain `Key: value` lines
+    fn extract_header_line(&mut self, line: &str) {
+        let line = line
+            .strip_prefix("* ")
+            .or_else(|| line.strip_prefix("- "))
+            .unwrap_or(line);
+
+        let Some((key, value)) = line.split_once(':') else {
+            return;
+        };
+        let value = value.trim();
+
+        match key.trim().to_lowercase().as_str() {
+            "status" if self.status.is_none() => {
+                self.status = normalize_status(value);
+            }
+            "date" if self.date.is_none() => {
+                self.date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok();
+            }
+            "deciders" if self.deciders.is_empty() => {
+                self.deciders = value
+                    .split(',')
+                    .map(|d| d.trim().to_string())
+                    .filter(|d| !d.is_empty())
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle lines inside a `## Status` section
+    fn extract_status_line(&mut self, line: &str) {
+        // Links to older ADRs are not the status, which may come after them
+        if line.to_lowercase().starts_with("supersedes") {
+            self.supersedes.extend(link_targets(line));
+            return;
+        }
+
+        if self.status.is_none() {
+            self.status = normalize_status(line);
+        }
+    }
+
+    /// Fill in fields that the frontmatter did not provide
+    ///
+    /// `frontmatter_keys` are the top-level keys present in the frontmatter.
+    /// Every field taken from the body is recorded in
+    /// [`AdrMetadata::inferred_fields`].
+    pub fn apply_to(self, metadata: &mut AdrMetadata, frontmatter_keys: &HashSet<String>) {
+        let missing = |keys: &[&str]| !keys.iter().any(|k| frontmatter_keys.contains(*k));
+
+        if let Some(title) = self.title.filter(|_| missing(&["title"])) {
+            metadata.title = title;
+            metadata.inferred_fields.push("title".to_string());
+        }
+        if let Some(status) = self.status.filter(|_| missing(&["status"])) {
+            metadata.status = status;
+            metadata.inferred_fields.push("status".to_string());
+        }
+        if let Some(id) = self.id.filter(|_| missing(&["id", "number"])) {
+            metadata.id = Some(id);
+            metadata.inferred_fields.push("id".to_string());
+        }
+        if let Some(date) = self.date.filter(|_| missing(&["date"])) {
+            metadata.date = Some(date);
+            metadata.inferred_fields.push("date".to_string());
+        }
+        if !self.deciders.is_empty() && missing(&["deciders", "authors"]) {
+            metadata.deciders = self.deciders;
+            metadata.inferred_fields.push("deciders".to_string());
+        }
+        if !self.supersedes.is_empty() && missing(&["supersedes"]) {
+            metadata.supersedes = self.supersedes;
+            metadata.inferred_fields.push("supersedes".to_string());
+        }
+    }
+}
+
+/// Reduce free-form status text to the status phrase, lowercased
+///
+/// The phrase ends at a link, punctuation, a date or a connecting word, so
+/// "Superseded by [3. ...](...)" gives "superseded", "In review" stays
+/// "in review" and "Proposed - Generated from drift detection" gives
+/// "proposed".
+fn normalize_status(text: &str) -> Option<String> {
+    let text = text.trim_start_matches(|c: char| c == '[' || c.is_whitespace());
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let ends_phrase = !word.starts_with(char::is_alphabetic)
+            || (!words.is_empty() && ["by", "on", "since"].contains(&word.to_lowercase().as_str()));
+        if ends_phrase {
+            break;
+        }
+
+        match word.find(|c: char| !c.is_alphanumeric() && c != '-' && c != '_') {
+            Some(end) => {
+                words.push(word[..end].to_lowercase());
+                break;
+            }
+            None => words.push(word.to_lowercase()),
+        }
+    }
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+/// File names of markdown link targets on a line, or the plain reference text
+fn link_targets(line: &str) -> Vec<String> {
+    let link = Regex::new(r"\]\(([^)\s]+)\)").unwrap();
+    let targets: Vec<String> = link
+        .captures_iter(line)
+        .map(|c| {
+            let target = &c[1];
+            target.rsplit('/').next().unwrap_or(target).to_string()
+        })
+        .collect();
+
+    if !targets.is_empty() {
+        return targets;
+    }
+
+    line.split_once(char::is_whitespace)
+        .map(|(_, rest)| rest.trim())
+        .filter(|rest| !rest.is_empty())
+        .map(|rest| vec![rest.to_string()])
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_nygard_adr() {
+        let content = r#"# 2. Use PostgreSQL for persistence
+
+Date: 2019-03-04
+
+## Status
+
+Supersedes [1. Use MySQL](0001-use-mysql.md)
+
+Accepted
+
+## Context
+
+We need a database.
+"#;
+
+        let metadata = BodyMetadata::extract(content);
+        assert_eq!(metadata.id, Some("2".to_string()));
+        assert_eq!(
+            metadata.title,
+            Some("Use PostgreSQL for persistence".to_string())
+        );
+        assert_eq!(metadata.status, Some("accepted".to_string()));
+        assert_eq!(metadata.date, NaiveDate::from_ymd_opt(2019, 3, 4));
+        assert_eq!(metadata.supersedes, vec!["0001-use-mysql.md"]);
+    }
+
+    #[test]
+    fn test_extract_madr_header_bullets() {
+        let content = r#"# Use Markdown Architectural Decision Records
+
+* Status: superseded by [ADR-0005](0005-example.md)
+* Deciders: Oliver, Stefan
+* Date: 2017-12-20
+
+Technical Story: Document decisions
+
+## Context and Problem Statement
+
+* Status: this bullet is body text and must be ignored
+"#;
+
+        let metadata = BodyMetadata::extract(content);
+        assert_eq!(metadata.id, None);
+        assert_eq!(
+            metadata.title,
+            Some("Use Markdown Architectural Decision Records".to_string())
+        );
+        assert_eq!(metadata.status, Some("superseded".to_string()));
+        assert_eq!(metadata.deciders, vec!["Oliver", "Stefan"]);
+        assert_eq!(metadata.date, NaiveDate::from_ymd_opt(2017, 12, 20));
+    }
+
+    #[test]
+    fn test_headings_in_code_blocks_are_ignored() {
+        let content = "```\n# Not a title\n```\n\n# Real Title\n";
+        let metadata = BodyMetadata::extract(content);
+        assert_eq!(metadata.title, Some("Real Title".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_status() {
+        assert_eq!(normalize_status("Accepted"), Some("accepted".to_string()));
+        assert_eq!(
+            normalize_status("Proposed - Generated from drift detection"),
+            Some("proposed".to_string())
+        );
+        assert_eq!(normalize_status("[accepted]"), Some("accepted".to_string()));
+        assert_eq!(normalize_status("In review"), Some("in review".to_string()));
+        assert_eq!(
+            normalize_status("Superseded by [ADR-0005](0005-example.md)"),
+            Some("superseded".to_string())
+        );
+        assert_eq!(
+            normalize_status("Accepted on 2024-01-05."),
+            Some("accepted".to_string())
+        );
+        assert_eq!(
+            normalize_status("Deprecated."),
+            Some("deprecated".to_string())
+        );
+        assert_eq!(normalize_status(""), None);
+    }
+}
@@ -0,0 +1,408 @@
+//! Persistent ADR Cache
+//!
+//! Parsed ADRs are stored in a cache file per ADR directory, keyed by their
+//! path relative to that directory. Cache files live in the user's cache
+//! directory (or `cache.dir`), never in the ADR directory itself, so that
+//! read-only commands leave the repository untouched. An entry is reused as
+//! long as the SHA-256 hash of the file content matches, so repeated runs
+//! only parse the ADRs that were added or edited since the last one. Each
+//! entry also keeps the document's vocabulary, from which an inverted word
+//! index is built to narrow down full-text searches.
+//!
+//! The cache is an optimization only: a missing, corrupt or outdated cache
+//! file is silently rebuilt.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::Write;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use super::{find_adr_files, AdrDocument, AdrParser, Result};
+use crate::config::CacheConfig;
+use crate::error::AdrscanError;
+
+/// Format of the cached entries; bump it whenever the parser output or the
+/// entry layout changes, so that builds of the same release do not reuse
+/// stale entries
+const CACHE_FORMAT: u32 = 1;
+
+/// Cache files written by another adrscan version or cache format are
+/// discarded, since the parser output may have changed
+fn cache_version() -> String {
+    format!("{}+{CACHE_FORMAT}", env!("CARGO_PKG_VERSION"))
+}
+
+/// On-disk layout; entries are stored as raw JSON so that one entry that
+/// cannot be (de)serialized does not invalidate the others
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    entries: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// SHA-256 of the file content
+    hash: String,
+    document: AdrDocument,
+    /// Lowercased words of the title, tags and body
+    words: BTreeSet<String>,
+}
+
+/// Parsed ADRs of one directory, reused across runs
+pub struct AdrCache {
+    adr_dir: PathBuf,
+    /// Cache file, or `None` when caching is disabled
+    file: Option<PathBuf>,
+    entries: BTreeMap<String, CacheEntry>,
+    dirty: bool,
+}
+
+impl AdrCache {
+    /// Load the cache of `adr_dir`; when caching is disabled nothing is
+    /// read or written and every file is parsed
+    pub fn open(adr_dir: &Path, config: &CacheConfig) -> Self {
+        let file = if config.enabled {
+            cache_file(adr_dir, config)
+        } else {
+            None
+        };
+        let entries = file.as_deref().map(load_entries).unwrap_or_default();
+
+        Self {
+            adr_dir: adr_dir.to_path_buf(),
+            file,
+            entries,
+            dirty: false,
+        }
+    }
+
+    /// Parse an ADR file, reusing the cached document if its content is
+    /// unchanged
+    pub fn parse_file(&mut self, path: &Path) -> Result<AdrDocument> {
+        let content = std::fs::read_to_string(path).map_err(AdrscanError::Io)?;
+        let hash = content_hash(&content);
+        let key = self.key(path);
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            if entry.hash == hash {
+                // The directory may be spelled differently than last time
+                entry.document.path = path.to_path_buf();
+                return Ok(entry.document.clone());
+            }
+        }
+
+        let document = AdrParser::parse_content(&content, path.to_path_buf())?;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                hash,
+                words: document_words(&document),
+                document: document.clone(),
+            },
+        );
+        self.dirty = true;
+        Ok(document)
+    }
+
+    /// Parse every ADR in the directory, skipping files that fail to parse
+    pub fn parse_all(&mut self) -> Vec<AdrDocument> {
+        let mut documents = Vec::new();
+        for path in find_adr_files(&self.adr_dir) {
+            match self.parse_file(&path) {
+                Ok(document) => documents.push(document),
+                Err(e) => log::warn!("Failed to parse ADR file {}: {}", path.display(), e),
+            }
+        }
+        documents
+    }
+
+    /// Inverted index from word to the paths of the cached documents
+    /// containing it
+    pub fn word_index(&self) -> WordIndex {
+        let mut postings: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+        for entry in self.entries.values() {
+            for word in &entry.words {
+                postings
+                    .entry(word.clone())
+                    .or_default()
+                    .push(entry.document.path.clone());
+            }
+        }
+        WordIndex { postings }
+    }
+
+    /// Write the cache back if anything changed, dropping entries for files
+    /// that no longer exist
+    pub fn save(&mut self) -> Result<()> {
+        let Some(ref file) = self.file else {
+            return Ok(());
+        };
+
+        let before = self.entries.len();
+        let adr_dir = &self.adr_dir;
+        self.entries.retain(|key, _| adr_dir.join(key).is_file());
+        if !self.dirty && self.entries.len() == before {
+            return Ok(());
+        }
+
+        let mut entries = BTreeMap::new();
+        for (key, entry) in &self.entries {
+            match serde_json::to_value(entry) {
+                Ok(value) => {
+                    entries.insert(key.clone(), value);
+                }
+                Err(e) => log::debug!("Not caching {key}: {e}"),
+            }
+        }
+        let json = serde_json::to_string(&CacheFile {
+            version: cache_version(),
+            entries,
+        })
+        .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write atomically so a concurrent run never reads a partial file;
+        // every writer gets its own temporary file
+        let dir = file.parent().filter(|dir| !dir.as_os_str().is_empty());
+        let mut temp = tempfile::NamedTempFile::new_in(dir.unwrap_or(Path::new(".")))?;
+        temp.write_all(json.as_bytes())?;
+        temp.persist(file).map_err(|e| e.error)?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Save, logging instead of failing; a stale cache is never an error
+    pub fn save_or_warn(&mut self) {
+        if let Err(e) = self.save() {
+            log::warn!("Failed to write ADR cache: {e}");
+        }
+    }
+
+    /// Cache key of `path`: relative to the ADR directory with `/` separators
+    fn key(&self, path: &Path) -> String {
+        match path.strip_prefix(&self.adr_dir) {
+            Ok(relative) => relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Err(_) => path.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+/// Cache file of an ADR directory, named after a hash of its absolute path
+/// so that every directory of every repository gets its own
+fn cache_file(adr_dir: &Path, config: &CacheConfig) -> Option<PathBuf> {
+    let dir = match config.dir {
+        Some(ref dir) => dir.clone(),
+        // Keep unit test runs out of the user's cache
+        None if cfg!(test) => std::env::temp_dir().join("adrscan-test-cache"),
+        None => dirs::cache_dir()?.join("adrscan"),
+    };
+    let absolute = std::fs::canonicalize(adr_dir).unwrap_or_else(|_| adr_dir.to_path_buf());
+    let hash = content_hash(&absolute.to_string_lossy());
+    Some(dir.join(format!("adr-cache-{}.json", &hash[..16])))
+}
+
+/// Word postings built from an [`AdrCache`]
+#[derive(Debug, Default)]
+pub struct WordIndex {
+    postings: BTreeMap<String, Vec<PathBuf>>,
+}
+
+impl WordIndex {
+    /// Add the postings of another index, e.g. of another ADR directory
+    pub fn merge(&mut self, other: WordIndex) {
+        for (word, paths) in other.postings {
+            self.postings.entry(word).or_default().extend(paths);
+        }
+    }
+
+    /// Paths of documents with a word starting with `prefix`
+    pub fn starting_with(&self, prefix: &str) -> HashSet<&Path> {
+        self.postings
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(|(word, _)| word.starts_with(prefix))
+            .flat_map(|(_, paths)| paths.iter().map(PathBuf::as_path))
+            .collect()
+    }
+
+    /// Paths of documents that may contain the full-text `term`, or `None`
+    /// when the term has no words to look up
+    ///
+    /// Full-text terms match at the start of a word, so every word of the
+    /// term but the last has to appear as a whole word and the last one as
+    /// the start of a word.
+    pub fn candidates(&self, term: &str) -> Option<HashSet<&Path>> {
+        let words: Vec<String> = words(term).collect();
+        let (last, whole) = words.split_last()?;
+        whole
+            .iter()
+            .map(|word| {
+                self.postings
+                    .get(word)
+                    .into_iter()
+                    .flatten()
+                    .map(PathBuf::as_path)
+                    .collect()
+            })
+            .chain(std::iter::once(self.starting_with(last)))
+            .reduce(|all: HashSet<&Path>, next| all.intersection(&next).copied().collect())
+    }
+}
+
+/// Lowercased alphanumeric words of `text`
+pub fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn document_words(document: &AdrDocument) -> BTreeSet<String> {
+    words(&document.metadata.title)
+        .chain(document.metadata.tags.iter().flat_map(|tag| words(tag)))
+        .chain(words(&document.content))
+        .collect()
+}
+
+fn content_hash(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn load_entries(file: &Path) -> BTreeMap<String, CacheEntry> {
+    let Ok(json) = std::fs::read_to_string(file) else {
+        return BTreeMap::new();
+    };
+    let cache: CacheFile = match serde_json::from_str(&json) {
+        Ok(cache) => cache,
+        Err(e) => {
+            log::debug!("Ignoring unreadable ADR cache {}: {e}", file.display());
+            return BTreeMap::new();
+        }
+    };
+    if cache.version != cache_version() {
+        log::debug!(
+            "Ignoring ADR cache {} from adrscan {}",
+            file.display(),
+            cache.version
+        );
+        return BTreeMap::new();
+    }
+
+    cache
+        .entries
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, serde_json::from_value(value).ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn cache_config(dir: &Path) -> CacheConfig {
+        CacheConfig {
+            enabled: true,
+            dir: Some(dir.to_path_buf()),
+        }
+    }
+
+    #[test]
+    fn test_cache_reuses_unchanged_documents() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        let cache_dir = TempDir::new().unwrap();
+        let config = cache_config(cache_dir.path());
+        let first = adr_dir.join("0001-db.md");
+        let second = adr_dir.join("0002-queue.md");
+        fs::write(
+            &first,
+            "---\ntitle: Use PostgreSQL\n---\n# Use PostgreSQL\n",
+        )
+        .unwrap();
+        fs::write(&second, "---\ntitle: Use Kafka\n---\nEvent sourcing.\n").unwrap();
+
+        let mut cache = AdrCache::open(adr_dir, &config);
+        assert_eq!(cache.parse_all().len(), 2);
+        cache.save().unwrap();
+        assert!(cache_file(adr_dir, &config).unwrap().is_file());
+        assert_eq!(fs::read_dir(adr_dir).unwrap().count(), 2);
+
+        // Cached documents are served without reparsing; an edited file is
+        // detected by its hash
+        fs::write(&first, "---\ntitle: Use MySQL\n---\n# Use MySQL\n").unwrap();
+        fs::remove_file(&second).unwrap();
+        let mut cache = AdrCache::open(adr_dir, &config);
+        assert_eq!(cache.entries.len(), 2);
+        assert!(!cache.dirty);
+        let documents = cache.parse_all();
+        assert!(cache.dirty);
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].metadata.title, "Use MySQL");
+
+        // Entries for deleted files are pruned on save
+        cache.save().unwrap();
+        assert_eq!(fs::read_dir(cache_dir.path()).unwrap().count(), 1);
+        let cache = AdrCache::open(adr_dir, &config);
+        assert_eq!(cache.entries.keys().collect::<Vec<_>>(), vec!["0001-db.md"]);
+
+        let index = cache.word_index();
+        assert_eq!(
+            index.starting_with("mysq"),
+            HashSet::from([first.as_path()])
+        );
+        assert!(index.starting_with("ysql").is_empty());
+        assert!(index.starting_with("kafka").is_empty());
+        assert_eq!(index.candidates("\"use mysql\"").unwrap().len(), 1);
+        assert_eq!(index.candidates("use my").unwrap().len(), 1);
+        assert!(index.candidates("us mysql").unwrap().is_empty());
+        assert!(index.candidates("use kafka").unwrap().is_empty());
+        assert!(index.candidates("--").is_none());
+    }
+
+    #[test]
+    fn test_cache_ignores_corrupt_or_foreign_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let adr_dir = temp_dir.path();
+        fs::write(adr_dir.join("0001-db.md"), "# Use PostgreSQL\n").unwrap();
+        let cache_dir = TempDir::new().unwrap();
+        let config = cache_config(cache_dir.path());
+        let file = cache_file(adr_dir, &config).unwrap();
+
+        fs::write(&file, "{not json").unwrap();
+        assert!(AdrCache::open(adr_dir, &config).entries.is_empty());
+
+        let mut cache = AdrCache::open(adr_dir, &config);
+        cache.parse_all();
+        cache.save().unwrap();
+        let json = fs::read_to_string(&file).unwrap();
+        fs::write(&file, json.replace(&cache_version(), "0.0.0-old")).unwrap();
+        assert!(AdrCache::open(adr_dir, &config).entries.is_empty());
+        let older_format = format!("{}+{}", env!("CARGO_PKG_VERSION"), CACHE_FORMAT - 1);
+        fs::write(&file, json.replace(&cache_version(), &older_format)).unwrap();
+        assert!(AdrCache::open(adr_dir, &config).entries.is_empty());
+
+        // Disabled caches never touch the disk
+        fs::remove_file(&file).unwrap();
+        let mut cache = AdrCache::open(
+            adr_dir,
+            &CacheConfig {
+                enabled: false,
+                ..config
+            },
+        );
+        assert_eq!(cache.parse_all().len(), 1);
+        cache.save().unwrap();
+        assert!(!file.exists());
+    }
+}
@@ -1,10 +1,19 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use crate::error::AdrscanError;
 type Result<T> = std::result::Result<T, AdrscanError>;
 
+pub mod body;
+pub mod cache;
+pub mod sections;
+pub mod writer;
+
+pub use body::BodyMetadata;
+pub use cache::AdrCache;
+pub use sections::{AdrSection, AdrSections, SectionKind};
+
 /// ADR metadata parsed from frontmatter
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdrMetadata {
@@ -34,6 +43,10 @@ pub struct AdrMetadata {
 
     /// Additional custom fields
     pub custom_fields: HashMap<String, serde_yaml::Value>,
+
+    /// Fields inferred from the markdown body rather than read from frontmatter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inferred_fields: Vec<String>,
 }
 
 /// Parsed ADR document
@@ -52,6 +65,64 @@ pub struct AdrDocument {
     /// Raw frontmatter YAML
     #[allow(dead_code)] // Planned for frontmatter analysis
     pub frontmatter: String,
+
+    /// Delimiter style of the frontmatter block (None when the file has none)
+    #[serde(default)]
+    pub frontmatter_format: Option<FrontmatterFormat>,
+
+    /// Section tree of the markdown body
+    #[serde(default)]
+    pub sections: AdrSections,
+
+    /// Namespace of the ADR root the document was loaded from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// Frontmatter block syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrontmatterFormat {
+    /// YAML frontmatter delimited by `---`
+    Yaml,
+    /// TOML frontmatter delimited by `+++`
+    Toml,
+}
+
+impl FrontmatterFormat {
+    /// Delimiter line used to open and close the frontmatter block
+    pub fn delimiter(&self) -> &'static str {
+        match self {
+            FrontmatterFormat::Yaml => "---",
+            FrontmatterFormat::Toml => "+++",
+        }
+    }
+}
+
+/// Markdown files under an ADR directory, skipping generated indexes
+pub fn find_adr_files(adr_dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walkdir::WalkDir::new(adr_dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| {
+            path.is_file()
+                && matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("markdown")
+                )
+                && !matches!(
+                    path.file_name()
+                        .and_then(|n| n.to_str())
+                        .map(|n| n.to_lowercase())
+                        .as_deref(),
+                    Some("index.md") | Some("readme.md")
+                )
+        })
+        .collect();
+
+    files.sort();
+    files
 }
 
 /// ADR parser for extracting metadata and content
@@ -68,13 +139,26 @@ impl AdrParser {
     /// Parse ADR content string
     pub fn parse_content(content: &str, path: std::path::PathBuf) -> Result<AdrDocument> {
         let (frontmatter, markdown_content) = Self::extract_frontmatter(content)?;
-        let metadata = Self::parse_metadata(&frontmatter)?;
+        let metadata = Self::derive_metadata(&frontmatter, &markdown_content)?;
+
+        let frontmatter_format = if content.starts_with("---\n") {
+            Some(FrontmatterFormat::Yaml)
+        } else if content.starts_with("+++\n") {
+            Some(FrontmatterFormat::Toml)
+        } else {
+            None
+        };
+
+        let sections = AdrSections::parse(&markdown_content);
 
         Ok(AdrDocument {
             path,
             metadata,
+            sections,
             content: markdown_content,
             frontmatter: frontmatter.to_string(),
+            frontmatter_format,
+            namespace: None,
         })
     }
 
@@ -114,6 +198,30 @@ impl AdrParser {
         }
     }
 
+    /// Build metadata from frontmatter, inferring missing fields from the body
+    fn derive_metadata(frontmatter: &str, markdown_content: &str) -> Result<AdrMetadata> {
+        let mut metadata = Self::parse_metadata(frontmatter)?;
+        BodyMetadata::extract(markdown_content)
+            .apply_to(&mut metadata, &Self::frontmatter_keys(frontmatter));
+        Ok(metadata)
+    }
+
+    /// Top-level keys present in the frontmatter
+    pub(crate) fn frontmatter_keys(frontmatter: &str) -> HashSet<String> {
+        if let Ok(serde_yaml::Value::Mapping(mapping)) =
+            serde_yaml::from_str::<serde_yaml::Value>(frontmatter)
+        {
+            return mapping
+                .keys()
+                .filter_map(|k| k.as_str().map(|s| s.to_string()))
+                .collect();
+        }
+
+        toml::from_str::<toml::Table>(frontmatter)
+            .map(|table| table.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// Parse frontmatter into ADR metadata (supports YAML and TOML)
     fn parse_metadata(frontmatter: &str) -> Result<AdrMetadata> {
         // Handle empty frontmatter gracefully
@@ -128,6 +236,7 @@ impl AdrParser {
                 supersedes: Vec::new(),
                 relates_to: Vec::new(),
                 custom_fields: HashMap::new(),
+                inferred_fields: Vec::new(),
             });
         }
 
@@ -215,6 +324,7 @@ impl AdrParser {
             supersedes,
             relates_to,
             custom_fields,
+            inferred_fields: Vec::new(),
         })
     }
 
@@ -281,6 +391,7 @@ impl AdrParser {
             supersedes,
             relates_to,
             custom_fields,
+            inferred_fields: Vec::new(),
         })
     }
 
@@ -433,12 +544,63 @@ This is test content without frontmatter.
 "#;
 
         let result = AdrParser::parse_content(content, PathBuf::from("test.md")).unwrap();
-        assert_eq!(result.metadata.title, "Untitled ADR");
+        assert_eq!(result.metadata.title, "Test ADR");
         assert_eq!(result.metadata.status, "proposed");
         assert!(result.metadata.deciders.is_empty());
+        assert_eq!(result.metadata.inferred_fields, vec!["title"]);
         assert!(result.content.contains("# Test ADR"));
     }
 
+    #[test]
+    fn test_parse_madr_without_frontmatter() {
+        let content = r#"# Use Plain JUnit5 for advanced test assertions
+
+* Status: accepted
+* Deciders: Alice, Bob
+* Date: 2018-06-05
+
+## Context and Problem Statement
+
+How to write readable test assertions?
+"#;
+
+        let result = AdrParser::parse_content(content, PathBuf::from("test.md")).unwrap();
+        assert_eq!(
+            result.metadata.title,
+            "Use Plain JUnit5 for advanced test assertions"
+        );
+        assert_eq!(result.metadata.status, "accepted");
+        assert_eq!(result.metadata.deciders, vec!["Alice", "Bob"]);
+        assert_eq!(
+            result.metadata.date,
+            chrono::NaiveDate::from_ymd_opt(2018, 6, 5)
+        );
+        assert_eq!(
+            result.metadata.inferred_fields,
+            vec!["title", "status", "date", "deciders"]
+        );
+    }
+
+    #[test]
+    fn test_frontmatter_takes_precedence_over_body() {
+        let content = r#"---
+status: deprecated
+---
+
+# 7. Use Redis for sessions
+
+## Status
+
+Accepted
+"#;
+
+        let result = AdrParser::parse_content(content, PathBuf::from("test.md")).unwrap();
+        assert_eq!(result.metadata.status, "deprecated");
+        assert_eq!(result.metadata.title, "Use Redis for sessions");
+        assert_eq!(result.metadata.id, Some("7".to_string()));
+        assert_eq!(result.metadata.inferred_fields, vec!["title", "id"]);
+    }
+
     #[test]
     fn test_parse_malformed_yaml() {
         let content = r#"---
@@ -0,0 +1,543 @@
+//! Structured Section Model
+//!
+//! This module turns the markdown body of an ADR into a tree of typed
+//! sections so that consumers can ask for "the decision outcome" or "the
+//! cons of option 2" instead of matching heading strings themselves.
+//!
+//! Headings are recognized for the common templates (MADR 2/3, Nygard and
+//! the built-in `templates/madr.md`). Every section keeps the byte range it
+//! covers in [`AdrDocument::content`](super::AdrDocument::content) and the
+//! line of its heading, so diagnostics can point back into the source.
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Well-known ADR section types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SectionKind {
+    /// `## Status`
+    Status,
+    /// `## Context`, `## Context and Problem Statement`
+    Context,
+    /// `## Decision Drivers`
+    DecisionDrivers,
+    /// `## Considered Options`
+    ConsideredOptions,
+    /// `## Decision`, `## Decision Outcome`
+    DecisionOutcome,
+    /// `## Pros and Cons of the Options`
+    ProsAndCons,
+    /// `## Consequences`, `### Positive Consequences`, ...
+    Consequences,
+    /// `## Links`, `## References`, `## More Information`
+    Links,
+    /// `## Versions`, `## Version Constraints`
+    Versions,
+    /// Any other heading
+    Other,
+}
+
+impl SectionKind {
+    /// Classify a heading by its text
+    pub fn from_heading(heading: &str) -> Self {
+        let heading = heading
+            .trim()
+            .trim_end_matches(':')
+            .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+            .to_lowercase();
+
+        match heading.as_str() {
+            "status" => SectionKind::Status,
+            "context" | "context and problem statement" | "problem statement" => {
+                SectionKind::Context
+            }
+            "decision drivers" => SectionKind::DecisionDrivers,
+            "considered options" | "options" | "alternatives" | "alternatives considered" => {
+                SectionKind::ConsideredOptions
+            }
+            "decision" | "decision outcome" => SectionKind::DecisionOutcome,
+            "pros and cons of the options" | "pros and cons" => SectionKind::ProsAndCons,
+            "consequences"
+            | "positive consequences"
+            | "negative consequences"
+            | "positive"
+            | "negative" => SectionKind::Consequences,
+            "links" | "references" | "more information" | "related decisions" => SectionKind::Links,
+            "versions" | "version constraints" | "pinned versions" => SectionKind::Versions,
+            _ => SectionKind::Other,
+        }
+    }
+}
+
+/// A heading and everything up to the next heading of the same or higher level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdrSection {
+    /// Section type derived from the heading text
+    pub kind: SectionKind,
+
+    /// Heading text as written
+    pub heading: String,
+
+    /// Heading level (2 for `##`)
+    pub level: u8,
+
+    /// 1-based line of the heading
+    pub line: usize,
+
+    /// Byte range of the whole section, heading included
+    pub range: Range<usize>,
+
+    /// Markdown between the heading and the first subsection, trimmed
+    pub text: String,
+
+    /// Nested subsections
+    pub children: Vec<AdrSection>,
+}
+
+impl AdrSection {
+    /// Whether the section has neither text nor subsections
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.children.is_empty()
+    }
+}
+
+/// One option from "Considered Options" / "Pros and Cons of the Options"
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConsideredOption {
+    /// Option name
+    pub title: String,
+
+    /// "Good, because ..." arguments
+    pub pros: Vec<String>,
+
+    /// "Bad, because ..." arguments
+    pub cons: Vec<String>,
+
+    /// "Neutral, because ..." arguments
+    pub neutral: Vec<String>,
+
+    /// Byte range of the list item or pros/cons subsection the option came from
+    pub range: Range<usize>,
+}
+
+/// Section tree of an ADR body
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdrSections {
+    /// Text of the first level-1 heading
+    pub title: Option<String>,
+
+    /// Top-level sections in document order
+    pub sections: Vec<AdrSection>,
+
+    /// Considered options with their pros and cons, in document order
+    pub options: Vec<ConsideredOption>,
+}
+
+/// A heading found in the body
+struct Heading {
+    level: u8,
+    text: String,
+    range: Range<usize>,
+}
+
+impl AdrSections {
+    /// Build the section tree for a markdown body
+    pub fn parse(content: &str) -> Self {
+        let headings = collect_headings(content);
+        let title = headings
+            .iter()
+            .find(|h| h.level == 1)
+            .map(|h| h.text.clone());
+
+        let mut sections = Vec::new();
+        let mut index = 0;
+        while index < headings.len() {
+            if headings[index].level == 1 {
+                index += 1;
+                continue;
+            }
+            let (section, next) = build_section(content, &headings, index);
+            sections.push(section);
+            index = next;
+        }
+
+        let mut parsed = Self {
+            title,
+            sections,
+            options: Vec::new(),
+        };
+        parsed.options = parsed.collect_options(content);
+        parsed
+    }
+
+    /// First section of the given kind, searching depth-first
+    pub fn find(&self, kind: SectionKind) -> Option<&AdrSection> {
+        self.iter().find(|s| s.kind == kind)
+    }
+
+    /// All sections of the given kind, searching depth-first
+    pub fn find_all(&self, kind: SectionKind) -> Vec<&AdrSection> {
+        self.iter().filter(|s| s.kind == kind).collect()
+    }
+
+    /// Whether a section of the given kind exists
+    pub fn has(&self, kind: SectionKind) -> bool {
+        self.find(kind).is_some()
+    }
+
+    /// Own text of the first section of the given kind
+    pub fn text(&self, kind: SectionKind) -> Option<&str> {
+        self.find(kind).map(|s| s.text.as_str())
+    }
+
+    /// All sections in document order (depth-first)
+    pub fn iter(&self) -> impl Iterator<Item = &AdrSection> {
+        let mut stack: Vec<&AdrSection> = self.sections.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let section = stack.pop()?;
+            stack.extend(section.children.iter().rev());
+            Some(section)
+        })
+    }
+
+    /// Merge the "Considered Options" list with the "Pros and Cons" subsections
+    fn collect_options(&self, content: &str) -> Vec<ConsideredOption> {
+        let mut options: Vec<ConsideredOption> = self
+            .find(SectionKind::ConsideredOptions)
+            .map(|section| {
+                list_items(content, own_range(content, section))
+                    .into_iter()
+                    .map(|(title, range)| ConsideredOption {
+                        title,
+                        range,
+                        ..Default::default()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let Some(pros_and_cons) = self.find(SectionKind::ProsAndCons) else {
+            return options;
+        };
+
+        for child in &pros_and_cons.children {
+            let position = options
+                .iter()
+                .position(|o| same_option(&o.title, &child.heading));
+            let option = match position {
+                Some(position) => &mut options[position],
+                None => {
+                    options.push(ConsideredOption {
+                        title: child.heading.clone(),
+                        range: child.range.clone(),
+                        ..Default::default()
+                    });
+                    options.last_mut().unwrap()
+                }
+            };
+
+            for (item, _) in list_items(content, own_range(content, child)) {
+                let lower = item.to_lowercase();
+                if lower.starts_with("good") {
+                    option.pros.push(argument(&item));
+                } else if lower.starts_with("bad") {
+                    option.cons.push(argument(&item));
+                } else if lower.starts_with("neutral") {
+                    option.neutral.push(argument(&item));
+                }
+            }
+        }
+
+        options
+    }
+}
+
+/// Headings of the body with their text and byte ranges
+fn collect_headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut current: Option<Heading> = None;
+
+    for (event, range) in Parser::new_ext(content, Options::empty()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some(Heading {
+                    level: heading_level(level),
+                    text: String::new(),
+                    range,
+                });
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = current.as_mut() {
+                    heading.text.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(mut heading) = current.take() {
+                    heading.text = heading.text.trim().to_string();
+                    headings.push(heading);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    headings
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Build the section starting at `headings[index]`; returns it and the index after it
+fn build_section(content: &str, headings: &[Heading], index: usize) -> (AdrSection, usize) {
+    let heading = &headings[index];
+
+    let mut next = index + 1;
+    let mut children = Vec::new();
+    while next < headings.len() && headings[next].level > heading.level {
+        let (child, after) = build_section(content, headings, next);
+        children.push(child);
+        next = after;
+    }
+
+    let end = headings
+        .get(next)
+        .map(|h| h.range.start)
+        .unwrap_or(content.len());
+    let own_end = children.first().map(|c| c.range.start).unwrap_or(end);
+    let body_start = heading.range.end.min(own_end);
+
+    let section = AdrSection {
+        kind: SectionKind::from_heading(&heading.text),
+        heading: heading.text.clone(),
+        level: heading.level,
+        line: content[..heading.range.start].matches('\n').count() + 1,
+        range: heading.range.start..end,
+        text: content[body_start..own_end].trim().to_string(),
+        children,
+    };
+
+    (section, next)
+}
+
+/// Byte range of a section's own body (after the heading, before subsections)
+fn own_range(content: &str, section: &AdrSection) -> Range<usize> {
+    let end = section
+        .children
+        .first()
+        .map(|c| c.range.start)
+        .unwrap_or(section.range.end);
+    let start = content[section.range.start..end]
+        .find('\n')
+        .map(|offset| section.range.start + offset + 1)
+        .unwrap_or(end);
+    start..end
+}
+
+/// Top-level list items in a range as (plain text, absolute byte range)
+fn list_items(content: &str, range: Range<usize>) -> Vec<(String, Range<usize>)> {
+    let base = range.start;
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut current: Option<(String, Range<usize>)> = None;
+
+    for (event, span) in Parser::new_ext(&content[range], Options::empty()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::List(_)) => depth += 1,
+            Event::End(TagEnd::List(_)) => depth = depth.saturating_sub(1),
+            Event::Start(Tag::Item) if depth == 1 => {
+                current = Some((String::new(), base + span.start..base + span.end));
+            }
+            Event::End(TagEnd::Item) if depth == 1 => {
+                if let Some((text, span)) = current.take() {
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if !text.is_empty() {
+                        items.push((text, span));
+                    }
+                }
+            }
+            Event::Text(text) | Event::Code(text) if depth == 1 => {
+                if let Some((item, _)) = current.as_mut() {
+                    item.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak if depth == 1 => {
+                if let Some((item, _)) = current.as_mut() {
+                    item.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    items
+}
+
+/// Argument of a "Good, because ..." bullet
+fn argument(item: &str) -> String {
+    let rest = item.split_once(',').map(|(_, rest)| rest).unwrap_or(item);
+    let rest = rest.trim();
+    rest.strip_prefix("because")
+        .map(str::trim)
+        .unwrap_or(rest)
+        .to_string()
+}
+
+/// Whether a pros/cons heading refers to a listed option
+fn same_option(listed: &str, heading: &str) -> bool {
+    let normalize = |s: &str| {
+        s.trim_matches(|c: char| c == '[' || c == ']' || c.is_whitespace())
+            .to_lowercase()
+    };
+    let (listed, heading) = (normalize(listed), normalize(heading));
+    !heading.is_empty() && (listed == heading || listed.starts_with(&heading))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MADR: &str = r#"# Use PostgreSQL
+
+## Context and Problem Statement
+
+We need a relational database.
+
+## Decision Drivers
+
+* Operational maturity
+* Team experience
+
+## Considered Options
+
+* PostgreSQL
+* MySQL - widely deployed
+* SQLite
+
+## Decision Outcome
+
+Chosen option: "PostgreSQL", because it meets every driver.
+
+### Positive Consequences
+
+* Mature tooling
+
+## Pros and Cons of the Options
+
+### PostgreSQL
+
+* Good, because it supports JSONB
+* Bad, because it needs tuning
+
+### MySQL
+
+* Good, because the team knows it
+* Bad, because replication is awkward
+* Bad, because of licensing concerns
+* Neutral, because hosting cost is similar
+
+## Links
+
+* [ADR-0001](0001-record-decisions.md)
+"#;
+
+    #[test]
+    fn test_section_kinds_and_tree() {
+        let sections = AdrSections::parse(MADR);
+
+        assert_eq!(sections.title, Some("Use PostgreSQL".to_string()));
+        let kinds: Vec<SectionKind> = sections.sections.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SectionKind::Context,
+                SectionKind::DecisionDrivers,
+                SectionKind::ConsideredOptions,
+                SectionKind::DecisionOutcome,
+                SectionKind::ProsAndCons,
+                SectionKind::Links,
+            ]
+        );
+
+        let outcome = sections.find(SectionKind::DecisionOutcome).unwrap();
+        assert_eq!(
+            outcome.text,
+            "Chosen option: \"PostgreSQL\", because it meets every driver."
+        );
+        assert_eq!(outcome.level, 2);
+        assert_eq!(outcome.children.len(), 1);
+        assert_eq!(outcome.children[0].kind, SectionKind::Consequences);
+        assert!(MADR[outcome.range.clone()].starts_with("## Decision Outcome"));
+        assert!(MADR[outcome.range.clone()].ends_with("* Mature tooling\n\n"));
+    }
+
+    #[test]
+    fn test_considered_options_with_pros_and_cons() {
+        let sections = AdrSections::parse(MADR);
+        let titles: Vec<&str> = sections.options.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["PostgreSQL", "MySQL - widely deployed", "SQLite"]
+        );
+
+        let mysql = &sections.options[1];
+        assert_eq!(mysql.pros, vec!["the team knows it"]);
+        assert_eq!(
+            mysql.cons,
+            vec!["replication is awkward", "of licensing concerns"]
+        );
+        assert_eq!(mysql.neutral, vec!["hosting cost is similar"]);
+        assert!(MADR[mysql.range.clone()].starts_with("* MySQL"));
+        assert!(sections.options[2].pros.is_empty());
+    }
+
+    #[test]
+    fn test_nygard_sections_and_lines() {
+        let content = "# 1. Record decisions\n\n## Status\n\nAccepted\n\n## Context\n\n```\n## not a heading\n```\n\n## Decision\n\n## Consequences\n\nMore docs.\n";
+        let sections = AdrSections::parse(content);
+
+        assert_eq!(sections.text(SectionKind::Status), Some("Accepted"));
+        assert!(sections
+            .text(SectionKind::Context)
+            .unwrap()
+            .contains("## not a heading"));
+        assert!(sections
+            .find(SectionKind::DecisionOutcome)
+            .unwrap()
+            .is_empty());
+        assert!(!sections.has(SectionKind::ConsideredOptions));
+        assert!(sections.options.is_empty());
+
+        let consequences = sections.find(SectionKind::Consequences).unwrap();
+        assert_eq!(consequences.line, 15);
+    }
+
+    #[test]
+    fn test_section_kind_from_heading() {
+        assert_eq!(
+            SectionKind::from_heading("Decision"),
+            SectionKind::DecisionOutcome
+        );
+        assert_eq!(
+            SectionKind::from_heading("2. Context:"),
+            SectionKind::Context
+        );
+        assert_eq!(
+            SectionKind::from_heading("More Information"),
+            SectionKind::Links
+        );
+        assert_eq!(
+            SectionKind::from_heading("Version Constraints"),
+            SectionKind::Versions
+        );
+        assert_eq!(SectionKind::from_heading("Notes"), SectionKind::Other);
+    }
+}
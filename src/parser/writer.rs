@@ -0,0 +1,691 @@
+//! Lossless ADR Writer
+//!
+//! This module edits the frontmatter of a parsed [`AdrDocument`] in place.
+//! Only the lines of the field being changed are rewritten, so comments,
+//! key order, unknown fields and the markdown body survive a parse/write
+//! round trip byte-for-byte.
+
+use std::path::Path;
+
+use super::{AdrDocument, AdrParser, FrontmatterFormat, Result};
+use crate::error::AdrscanError;
+
+/// Line range occupied by a top-level frontmatter field
+struct FieldSpan {
+    /// Line holding the key
+    start: usize,
+    /// One past the last line belonging to the value
+    end: usize,
+}
+
+/// Quoting style used for YAML string scalars
+#[derive(Clone, Copy)]
+enum Quote {
+    Plain,
+    Single,
+    Double,
+}
+
+impl AdrDocument {
+    /// Render the document back to markdown, including its frontmatter block
+    pub fn to_markdown(&self) -> String {
+        match self.frontmatter_format {
+            Some(format) => format!(
+                "{delimiter}\n{}\n{delimiter}\n{}",
+                self.frontmatter,
+                self.content,
+                delimiter = format.delimiter()
+            ),
+            None => self.content.clone(),
+        }
+    }
+
+    /// Write the document back to the file it was parsed from
+    pub fn write(&self) -> Result<()> {
+        self.write_to(&self.path)
+    }
+
+    /// Write the document to `path`, replacing any existing file atomically
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let file_name = path.file_name().ok_or_else(|| {
+            AdrscanError::InvalidArgument(format!("Not a file path: {}", path.display()))
+        })?;
+        let temp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+
+        // Write next to the target and rename over it so readers never see a
+        // partially written ADR
+        std::fs::write(&temp_path, self.to_markdown())?;
+        if let Err(e) = std::fs::rename(&temp_path, path) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(AdrscanError::Io(e));
+        }
+
+        Ok(())
+    }
+
+    /// Set a string field in the frontmatter
+    pub fn set_string_field(&mut self, key: &str, value: &str) -> Result<()> {
+        self.set_field(key, &serde_yaml::Value::String(value.to_string()))
+    }
+
+    /// Set a list-of-strings field in the frontmatter
+    pub fn set_list_field(&mut self, key: &str, values: &[String]) -> Result<()> {
+        let sequence = values
+            .iter()
+            .map(|v| serde_yaml::Value::String(v.clone()))
+            .collect();
+        self.set_field(key, &serde_yaml::Value::Sequence(sequence))
+    }
+
+    /// Set a top-level frontmatter field, leaving every other line untouched
+    ///
+    /// Existing fields are rewritten in their original style (quoting, flow or
+    /// block lists, trailing comments). New fields are appended to the end of
+    /// the frontmatter. Documents without frontmatter get a YAML block.
+    pub fn set_field(&mut self, key: &str, value: &serde_yaml::Value) -> Result<()> {
+        let format = self.frontmatter_format.unwrap_or(FrontmatterFormat::Yaml);
+        let mut lines = self.frontmatter_lines();
+
+        match find_field(&lines, key, format) {
+            Some(span) => {
+                let old = &lines[span.start..span.end];
+                let replacement = match format {
+                    FrontmatterFormat::Yaml => render_yaml_field(key, value, old),
+                    FrontmatterFormat::Toml => render_toml_field(key, value, old)?,
+                };
+                lines.splice(span.start..span.end, replacement);
+            }
+            None => {
+                let (insert_at, replacement) = match format {
+                    FrontmatterFormat::Yaml => (
+                        content_end(&lines, lines.len()),
+                        render_yaml_field(key, value, &[]),
+                    ),
+                    FrontmatterFormat::Toml => {
+                        let first_table = lines
+                            .iter()
+                            .position(|l| is_toml_table_header(l))
+                            .unwrap_or(lines.len());
+                        (
+                            content_end(&lines, first_table),
+                            render_toml_field(key, value, &[])?,
+                        )
+                    }
+                };
+                lines.splice(insert_at..insert_at, replacement);
+            }
+        }
+
+        self.update_frontmatter(&lines, format)
+    }
+
+    fn frontmatter_lines(&self) -> Vec<String> {
+        if self.frontmatter.is_empty() {
+            Vec::new()
+        } else {
+            self.frontmatter.split('\n').map(str::to_string).collect()
+        }
+    }
+
+    /// Replace the frontmatter text and re-derive metadata from it
+    fn update_frontmatter(&mut self, lines: &[String], format: FrontmatterFormat) -> Result<()> {
+        let frontmatter = lines.join("\n");
+        let metadata = AdrParser::derive_metadata(&frontmatter, &self.content)?;
+
+        self.frontmatter = frontmatter;
+        self.metadata = metadata;
+        self.frontmatter_format = Some(format);
+        Ok(())
+    }
+}
+
+/// Write several documents back to their files as a single change
+///
+/// Every document is rendered to a temporary sibling before any target is
+/// touched. If a later rename fails, files already replaced are restored to
+/// their previous content so the set is never left half-updated.
+pub fn write_documents(documents: &[&AdrDocument]) -> Result<()> {
+    let mut staged = Vec::with_capacity(documents.len());
+    let cleanup = |staged: &[(std::path::PathBuf, &Path)]| {
+        for (temp_path, _) in staged {
+            let _ = std::fs::remove_file(temp_path);
+        }
+    };
+
+    for document in documents {
+        let path = document.path.as_path();
+        let file_name = path.file_name().ok_or_else(|| {
+            AdrscanError::InvalidArgument(format!("Not a file path: {}", path.display()))
+        })?;
+        let temp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+        if let Err(e) = std::fs::write(&temp_path, document.to_markdown()) {
+            cleanup(&staged);
+            return Err(AdrscanError::Io(e));
+        }
+        staged.push((temp_path, path));
+    }
+
+    // Keep the original bytes so a failed rename can be rolled back
+    let mut originals = Vec::with_capacity(staged.len());
+    for (_, path) in &staged {
+        match std::fs::read(path) {
+            Ok(bytes) => originals.push(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => originals.push(None),
+            Err(e) => {
+                cleanup(&staged);
+                return Err(AdrscanError::Io(e));
+            }
+        }
+    }
+
+    for (index, (temp_path, path)) in staged.iter().enumerate() {
+        if let Err(e) = std::fs::rename(temp_path, path) {
+            for ((_, replaced), original) in staged[..index].iter().zip(&originals) {
+                let _ = match original {
+                    Some(bytes) => std::fs::write(replaced, bytes),
+                    None => std::fs::remove_file(replaced),
+                };
+            }
+            cleanup(&staged[index..]);
+            return Err(AdrscanError::Io(e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Index just past the last non-blank line before `limit`
+fn content_end(lines: &[String], limit: usize) -> usize {
+    let mut end = limit;
+    while end > 0 && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+    end
+}
+
+/// Locate the lines of a top-level field
+fn find_field(lines: &[String], key: &str, format: FrontmatterFormat) -> Option<FieldSpan> {
+    match format {
+        FrontmatterFormat::Yaml => {
+            let start = lines.iter().position(|l| yaml_key(l) == Some(key))?;
+
+            // Indented lines and block list items belong to the value; blank
+            // lines and comments only do when more of the value follows them
+            let mut end = start + 1;
+            for (offset, line) in lines[start + 1..].iter().enumerate() {
+                if is_yaml_continuation(line) {
+                    end = start + offset + 2;
+                } else if !(line.trim().is_empty() || line.starts_with('#')) {
+                    break;
+                }
+            }
+
+            Some(FieldSpan { start, end })
+        }
+        FrontmatterFormat::Toml => {
+            let mut start = 0;
+            while start < lines.len() && !is_toml_table_header(&lines[start]) {
+                // Multi-line arrays and inline tables run until brackets balance
+                let mut depth = bracket_depth(&lines[start]);
+                let mut end = start + 1;
+                while depth > 0 && end < lines.len() {
+                    depth += bracket_depth(&lines[end]);
+                    end += 1;
+                }
+
+                if toml_key(&lines[start]) == Some(key) {
+                    return Some(FieldSpan { start, end });
+                }
+                start = end;
+            }
+            None
+        }
+    }
+}
+
+/// Key of a top-level YAML mapping entry
+fn yaml_key(line: &str) -> Option<&str> {
+    if line.starts_with(|c: char| c.is_whitespace() || c == '#' || c == '-') {
+        return None;
+    }
+
+    let (key, rest) = match line.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let close = line[1..].find(quote)? + 1;
+            (&line[1..close], line[close + 1..].trim_start())
+        }
+        _ => {
+            let colon = line.match_indices(':').map(|(i, _)| i).find(|&i| {
+                line[i + 1..].is_empty() || line[i + 1..].starts_with(char::is_whitespace)
+            })?;
+            (line[..colon].trim_end(), &line[colon..])
+        }
+    };
+
+    let after_colon = rest.strip_prefix(':')?;
+    if after_colon.is_empty() || after_colon.starts_with(char::is_whitespace) {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+fn is_yaml_continuation(line: &str) -> bool {
+    (line.starts_with([' ', '\t']) && !line.trim().is_empty())
+        || line == "-"
+        || line.starts_with("- ")
+}
+
+/// Key of a top-level TOML assignment
+fn toml_key(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') || trimmed.starts_with('[') {
+        return None;
+    }
+
+    let eq = trimmed.find('=')?;
+    let key = trimmed[..eq].trim();
+    let key = key
+        .strip_prefix('"')
+        .and_then(|k| k.strip_suffix('"'))
+        .or_else(|| key.strip_prefix('\'').and_then(|k| k.strip_suffix('\'')))
+        .unwrap_or(key);
+
+    (!key.is_empty()).then_some(key)
+}
+
+fn is_toml_table_header(line: &str) -> bool {
+    line.trim_start().starts_with('[')
+}
+
+/// Net count of opened brackets on a line, ignoring strings and comments
+fn bracket_depth(line: &str) -> i32 {
+    let mut depth = 0;
+    let mut in_string: Option<char> = None;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        match in_string {
+            Some(quote) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && quote == '"' {
+                    escaped = true;
+                } else if c == quote {
+                    in_string = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => in_string = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                '#' => break,
+                _ => {}
+            },
+        }
+    }
+
+    depth
+}
+
+/// Split the text after a key into its value and a trailing comment
+///
+/// The comment keeps its leading whitespace so it can be re-attached as-is.
+fn split_trailing_comment(rest: &str) -> (&str, &str) {
+    let mut in_string: Option<char> = None;
+    let mut previous = ' ';
+
+    for (i, c) in rest.char_indices() {
+        match in_string {
+            Some(quote) if c == quote => in_string = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_string = Some(c),
+            None if c == '#' && previous.is_whitespace() => {
+                let value = rest[..i].trim_end();
+                return (value, &rest[value.len()..]);
+            }
+            None => {}
+        }
+        previous = c;
+    }
+
+    (rest.trim_end(), "")
+}
+
+/// Split a key line into the key text (through the separator) and the rest
+fn split_key_line(line: &str, separator: char) -> (&str, &str) {
+    let key_end = match line.chars().next() {
+        Some(quote @ ('"' | '\'')) => line[1..].find(quote).map_or(0, |i| i + 2),
+        _ => 0,
+    };
+
+    match line[key_end..].find(separator) {
+        Some(i) => line.split_at(key_end + i + 1),
+        None => (line, ""),
+    }
+}
+
+fn render_yaml_field(key: &str, value: &serde_yaml::Value, old: &[String]) -> Vec<String> {
+    let (key_text, old_value, comment) = match old.first() {
+        Some(first) => {
+            let (key_text, rest) = split_key_line(first, ':');
+            let (old_value, comment) = split_trailing_comment(rest);
+            (key_text.to_string(), old_value.trim(), comment)
+        }
+        None => (format!("{key}:"), "", ""),
+    };
+
+    let quote = if old.is_empty() || old_value.contains('"') {
+        Quote::Double
+    } else if old_value.contains('\'') {
+        Quote::Single
+    } else {
+        Quote::Plain
+    };
+
+    match value {
+        serde_yaml::Value::Sequence(items) if items.iter().all(is_yaml_scalar) => {
+            let block_indent = old
+                .get(1)
+                .filter(|_| old_value.is_empty())
+                .filter(|l| l.trim_start().starts_with('-'))
+                .map(|l| l[..l.len() - l.trim_start().len()].to_string());
+
+            match block_indent {
+                Some(indent) if !items.is_empty() => {
+                    let mut lines = vec![format!("{key_text}{comment}")];
+                    lines.extend(
+                        items
+                            .iter()
+                            .map(|item| format!("{indent}- {}", yaml_scalar(item, quote))),
+                    );
+                    lines
+                }
+                _ => {
+                    let rendered: Vec<String> =
+                        items.iter().map(|item| yaml_scalar(item, quote)).collect();
+                    vec![format!("{key_text} [{}]{comment}", rendered.join(", "))]
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(_) | serde_yaml::Value::Mapping(_) => {
+            let nested = serde_yaml::to_string(value).unwrap_or_default();
+            let mut lines = vec![format!("{key_text}{comment}")];
+            lines.extend(nested.lines().map(|l| format!("  {l}")));
+            lines
+        }
+        _ => vec![format!("{key_text} {}{comment}", yaml_scalar(value, quote))],
+    }
+}
+
+fn is_yaml_scalar(value: &serde_yaml::Value) -> bool {
+    !matches!(
+        value,
+        serde_yaml::Value::Sequence(_) | serde_yaml::Value::Mapping(_)
+    )
+}
+
+fn yaml_scalar(value: &serde_yaml::Value, quote: Quote) -> String {
+    match value {
+        serde_yaml::Value::String(s) => match quote {
+            Quote::Single if !s.contains('\n') => format!("'{}'", s.replace('\'', "''")),
+            Quote::Plain if !s.contains('\n') => serde_yaml::to_string(s)
+                .map(|out| out.trim_end().to_string())
+                .unwrap_or_else(|_| double_quoted(s)),
+            _ => double_quoted(s),
+        },
+        serde_yaml::Value::Null => "null".to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Tagged(tagged) => yaml_scalar(&tagged.value, quote),
+        other => serde_yaml::to_string(other)
+            .map(|out| out.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// JSON string literals are valid YAML double-quoted scalars
+fn double_quoted(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("\"{s}\""))
+}
+
+fn render_toml_field(key: &str, value: &serde_yaml::Value, old: &[String]) -> Result<Vec<String>> {
+    let toml_value = yaml_to_toml(value)?;
+
+    let Some(first) = old.first() else {
+        return Ok(vec![format!("{key} = {toml_value}")]);
+    };
+
+    let (key_text, rest) = split_key_line(first.as_str(), '=');
+    let key_text = key_text.to_string();
+
+    // Keep multi-line arrays multi-line, using the original item indentation
+    if let (toml::Value::Array(items), Some(second)) = (&toml_value, old.get(1)) {
+        if !items.is_empty() {
+            let indent = &second[..second.len() - second.trim_start().len()];
+            let mut lines = vec![format!("{key_text} [")];
+            lines.extend(items.iter().map(|item| format!("{indent}{item},")));
+            lines.push("]".to_string());
+            return Ok(lines);
+        }
+    }
+
+    let comment = if old.len() == 1 {
+        split_trailing_comment(rest).1
+    } else {
+        ""
+    };
+
+    Ok(vec![format!("{key_text} {toml_value}{comment}")])
+}
+
+fn yaml_to_toml(value: &serde_yaml::Value) -> Result<toml::Value> {
+    Ok(match value {
+        serde_yaml::Value::String(s) => toml::Value::String(s.clone()),
+        serde_yaml::Value::Bool(b) => toml::Value::Boolean(*b),
+        serde_yaml::Value::Number(n) => match n.as_i64() {
+            Some(i) => toml::Value::Integer(i),
+            None => toml::Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        serde_yaml::Value::Sequence(items) => {
+            toml::Value::Array(items.iter().map(yaml_to_toml).collect::<Result<_>>()?)
+        }
+        serde_yaml::Value::Mapping(mapping) => {
+            let mut table = toml::map::Map::new();
+            for (k, v) in mapping {
+                let key = k.as_str().ok_or_else(|| {
+                    AdrscanError::InvalidArgument(
+                        "TOML frontmatter only supports string keys".to_string(),
+                    )
+                })?;
+                table.insert(key.to_string(), yaml_to_toml(v)?);
+            }
+            toml::Value::Table(table)
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_to_toml(&tagged.value)?,
+        serde_yaml::Value::Null => {
+            return Err(AdrscanError::InvalidArgument(
+                "TOML frontmatter cannot represent null values".to_string(),
+            ))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    const YAML_ADR: &str = r#"---
+# Owned by the platform team
+title: "Use PostgreSQL"
+status: proposed  # pending review
+date: 2024-01-10
+deciders: ["Alice", "Bob"]
+tags:
+  - database
+  - storage
+review_board: architecture
+---
+
+# Use PostgreSQL
+
+## Context
+
+Body text with trailing spaces
+and no final newline"#;
+
+    const TOML_ADR: &str = r#"+++
+title = "Use Kafka"
+status = "proposed" # pending
+tags = [
+    "messaging",
+]
+owner = "platform"
+
+[extra]
+cost = 3
++++
+
+# Use Kafka
+"#;
+
+    fn parse(content: &str) -> AdrDocument {
+        AdrParser::parse_content(content, PathBuf::from("0001-test.md")).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_is_lossless() {
+        for content in [YAML_ADR, TOML_ADR, "# No frontmatter\n\nJust text.\n"] {
+            assert_eq!(parse(content).to_markdown(), content);
+        }
+    }
+
+    #[test]
+    fn test_set_yaml_scalar_preserves_everything_else() {
+        let mut doc = parse(YAML_ADR);
+        doc.set_string_field("status", "accepted").unwrap();
+
+        let expected = YAML_ADR.replace(
+            "status: proposed  # pending review",
+            "status: accepted  # pending review",
+        );
+        assert_eq!(doc.to_markdown(), expected);
+        assert_eq!(doc.metadata.status, "accepted");
+        assert!(doc.metadata.custom_fields.contains_key("review_board"));
+    }
+
+    #[test]
+    fn test_set_yaml_lists_keep_their_style() {
+        let mut doc = parse(YAML_ADR);
+        doc.set_list_field("deciders", &["Carol".to_string()])
+            .unwrap();
+        doc.set_list_field("tags", &["database".to_string(), "sql".to_string()])
+            .unwrap();
+
+        let output = doc.to_markdown();
+        assert!(output.contains("deciders: [\"Carol\"]\n"));
+        assert!(output.contains("tags:\n  - database\n  - sql\nreview_board: architecture\n"));
+        assert_eq!(doc.metadata.deciders, vec!["Carol"]);
+        assert_eq!(doc.metadata.tags, vec!["database", "sql"]);
+    }
+
+    #[test]
+    fn test_set_quoted_yaml_string_keeps_quotes() {
+        let mut doc = parse(YAML_ADR);
+        doc.set_string_field("title", "Use PostgreSQL 16").unwrap();
+        assert!(doc.to_markdown().contains("title: \"Use PostgreSQL 16\"\n"));
+    }
+
+    #[test]
+    fn test_add_new_yaml_field() {
+        let mut doc = parse(YAML_ADR);
+        doc.set_list_field("supersedes", &["0003".to_string()])
+            .unwrap();
+
+        let output = doc.to_markdown();
+        assert!(output.contains("review_board: architecture\nsupersedes: [\"0003\"]\n---\n"));
+        assert_eq!(doc.metadata.supersedes, vec!["0003"]);
+    }
+
+    #[test]
+    fn test_set_toml_fields() {
+        let mut doc = parse(TOML_ADR);
+        doc.set_string_field("status", "accepted").unwrap();
+        doc.set_list_field("tags", &["messaging".to_string(), "events".to_string()])
+            .unwrap();
+        doc.set_string_field("date", "2024-02-01").unwrap();
+
+        let output = doc.to_markdown();
+        assert!(output.contains("status = \"accepted\" # pending\n"));
+        assert!(output.contains("tags = [\n    \"messaging\",\n    \"events\",\n]\n"));
+        assert!(
+            output.contains("owner = \"platform\"\ndate = \"2024-02-01\"\n\n[extra]\ncost = 3\n")
+        );
+        assert!(output.ends_with("+++\n\n# Use Kafka\n"));
+        assert_eq!(doc.metadata.status, "accepted");
+        assert_eq!(doc.metadata.tags, vec!["messaging", "events"]);
+    }
+
+    #[test]
+    fn test_set_field_without_frontmatter_creates_yaml_block() {
+        let content = "# Legacy ADR\n\nBody.\n";
+        let mut doc = parse(content);
+        doc.set_string_field("status", "accepted").unwrap();
+
+        assert_eq!(
+            doc.to_markdown(),
+            "---\nstatus: \"accepted\"\n---\n# Legacy ADR\n\nBody.\n"
+        );
+        assert_eq!(doc.frontmatter_format, Some(FrontmatterFormat::Yaml));
+    }
+
+    #[test]
+    fn test_toml_rejects_null() {
+        let mut doc = parse(TOML_ADR);
+        assert!(doc.set_field("status", &serde_yaml::Value::Null).is_err());
+        // A failed edit leaves the document untouched
+        assert_eq!(doc.to_markdown(), TOML_ADR);
+    }
+
+    #[test]
+    fn test_write_replaces_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("0001-test.md");
+        std::fs::write(&path, YAML_ADR).unwrap();
+
+        let mut doc = AdrParser::parse_file(&path).unwrap();
+        doc.set_string_field("status", "accepted").unwrap();
+        doc.write().unwrap();
+
+        let reparsed = AdrParser::parse_file(&path).unwrap();
+        assert_eq!(reparsed.metadata.status, "accepted");
+        assert_eq!(reparsed.content, doc.content);
+    }
+
+    #[test]
+    fn test_write_documents_rolls_back() {
+        let temp_dir = TempDir::new().unwrap();
+        let first_path = temp_dir.path().join("0001-test.md");
+        std::fs::write(&first_path, YAML_ADR).unwrap();
+
+        let mut first = AdrParser::parse_file(&first_path).unwrap();
+        first.set_string_field("status", "superseded").unwrap();
+
+        // The second target's directory does not exist, so staging fails
+        let mut second = parse(TOML_ADR);
+        second.path = temp_dir.path().join("missing").join("0002-test.md");
+        assert!(write_documents(&[&first, &second]).is_err());
+        assert_eq!(std::fs::read_to_string(&first_path).unwrap(), YAML_ADR);
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+
+        second.path = temp_dir.path().join("0002-test.md");
+        write_documents(&[&first, &second]).unwrap();
+        assert_eq!(
+            AdrParser::parse_file(&first_path).unwrap().metadata.status,
+            "superseded"
+        );
+        assert_eq!(std::fs::read_to_string(&second.path).unwrap(), TOML_ADR);
+    }
+}
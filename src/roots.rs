@@ -0,0 +1,334 @@
+//! ADR Roots
+//!
+//! Resolves the `adr_dir` setting into concrete directories. A monorepo can
+//! keep ADRs in several places, e.g. a global `docs/adr` plus one directory
+//! per team matched by `services/*/docs/adr`. ADRs under a namespaced root
+//! are identified as `<namespace>/<id>` (`payments/0007`), and each root may
+//! replace the top-level template settings and opt out of drift detection.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use crate::{
+    config::{AdrRootConfig, CacheConfig, Config, DriftConfig, TemplateConfig},
+    error::AdrscanError,
+    parser::{AdrCache, AdrDocument},
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// A resolved ADR directory with its effective settings
+#[derive(Debug, Clone)]
+pub struct AdrRoot {
+    pub path: PathBuf,
+    pub namespace: Option<String>,
+    pub template: TemplateConfig,
+    pub drift: DriftConfig,
+}
+
+impl AdrRoot {
+    /// Root for an explicit directory, using the top-level settings
+    pub fn single(path: &Path, config: &Config) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            namespace: None,
+            template: config.template.clone(),
+            drift: config.drift.clone(),
+        }
+    }
+
+    fn configured(
+        path: PathBuf,
+        namespace: Option<String>,
+        root: &AdrRootConfig,
+        config: &Config,
+    ) -> Self {
+        let mut template = config.template.clone();
+        if let Some(ref overrides) = root.template {
+            if let Some(ref format) = overrides.format {
+                template.format = format.clone();
+            }
+            if let Some(ref custom_path) = overrides.custom_path {
+                template.custom_path = Some(custom_path.clone());
+            }
+        }
+
+        let mut drift = config.drift.clone();
+        if let Some(ref overrides) = root.drift {
+            if let Some(enabled) = overrides.enabled {
+                drift.enabled = enabled;
+            }
+        }
+
+        Self {
+            path,
+            namespace,
+            template,
+            drift,
+        }
+    }
+
+    /// Parse every ADR under this root, tagged with the root's namespace
+    pub fn parse_all(&self, cache: &CacheConfig) -> Vec<AdrDocument> {
+        let mut cache = AdrCache::open(&self.path, cache);
+        let mut documents = cache.parse_all();
+        cache.save_or_warn();
+
+        for document in &mut documents {
+            document.namespace = self.namespace.clone();
+        }
+        documents
+    }
+}
+
+/// Directories to read: the `--adr-dir` override alone, or every configured
+/// root with globs expanded
+pub fn resolve(config: &Config, adr_dir: Option<&PathBuf>) -> Result<Vec<AdrRoot>> {
+    if let Some(adr_dir) = adr_dir {
+        ensure_exists(adr_dir)?;
+        return Ok(vec![AdrRoot::single(adr_dir, config)]);
+    }
+
+    let mut roots = Vec::new();
+    for root in config.adr_dir.roots() {
+        let pattern = root.path.to_string_lossy();
+        if !is_glob(&pattern) {
+            ensure_exists(&root.path)?;
+            roots.push(AdrRoot::configured(
+                root.path.clone(),
+                root.namespace.clone(),
+                root,
+                config,
+            ));
+            continue;
+        }
+
+        let matches = expand(&root.path)?;
+        if matches.is_empty() {
+            log::warn!("ADR root pattern {pattern} matches no directories");
+        }
+        if root.namespace.is_some() && matches.len() > 1 {
+            return Err(AdrscanError::ConfigError(format!(
+                "ADR root pattern {pattern} matches {} directories but sets a single namespace",
+                matches.len()
+            )));
+        }
+        for path in matches {
+            let namespace = root
+                .namespace
+                .clone()
+                .or_else(|| wildcard_segment(&root.path, &path));
+            roots.push(AdrRoot::configured(path, namespace, root, config));
+        }
+    }
+
+    let mut seen: HashMap<&str, &Path> = HashMap::new();
+    for root in &roots {
+        if let Some(ref namespace) = root.namespace {
+            if let Some(other) = seen.insert(namespace, &root.path) {
+                return Err(AdrscanError::ConfigError(format!(
+                    "Namespace '{namespace}' is used by both {} and {}",
+                    other.display(),
+                    root.path.display()
+                )));
+            }
+        }
+    }
+
+    Ok(roots)
+}
+
+/// Parse the ADRs of every root, in root order
+pub fn load_documents(roots: &[AdrRoot], config: &Config) -> Vec<AdrDocument> {
+    roots
+        .iter()
+        .flat_map(|root| root.parse_all(&config.cache))
+        .collect()
+}
+
+/// ADR directories of the roots that enable drift detection
+pub fn drift_adr_dirs(roots: &[AdrRoot]) -> Vec<PathBuf> {
+    roots
+        .iter()
+        .filter(|root| root.drift.enabled)
+        .map(|root| root.path.clone())
+        .collect()
+}
+
+/// The root new ADRs go to by default: the first root without a namespace,
+/// else the first root
+pub fn default_root(roots: &[AdrRoot]) -> Result<&AdrRoot> {
+    roots
+        .iter()
+        .find(|root| root.namespace.is_none())
+        .or_else(|| roots.first())
+        .ok_or_else(|| {
+            AdrscanError::DirectoryNotFound(
+                "No ADR directory matches the configured adr_dir".to_string(),
+            )
+        })
+}
+
+/// The root an ADR file belongs to
+pub fn root_of<'a>(roots: &'a [AdrRoot], path: &Path) -> Option<&'a AdrRoot> {
+    // Prefer the most specific root when roots are nested
+    roots
+        .iter()
+        .filter(|root| path.starts_with(&root.path))
+        .max_by_key(|root| root.path.components().count())
+}
+
+fn ensure_exists(path: &Path) -> Result<()> {
+    if path.exists() {
+        Ok(())
+    } else {
+        Err(AdrscanError::DirectoryNotFound(format!(
+            "ADR directory not found: {}",
+            path.display()
+        )))
+    }
+}
+
+/// Whether an `adr_dir` entry is a glob rather than a single directory
+pub fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '['])
+}
+
+/// Directories matching a glob, sorted
+fn expand(pattern: &Path) -> Result<Vec<PathBuf>> {
+    let pattern = pattern.to_string_lossy();
+    let entries = glob::glob(&pattern).map_err(|e| {
+        AdrscanError::ConfigError(format!("Invalid ADR root pattern '{pattern}': {e}"))
+    })?;
+
+    let mut directories: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|path| path.is_dir())
+        .collect();
+    directories.sort();
+    Ok(directories)
+}
+
+/// Name of the directory matched by the first wildcard component of `pattern`
+fn wildcard_segment(pattern: &Path, path: &Path) -> Option<String> {
+    let position = pattern
+        .components()
+        .position(|c| matches!(c, Component::Normal(name) if is_glob(&name.to_string_lossy())))?;
+    match path.components().nth(position)? {
+        Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AdrDirs, DriftOverride, TemplateOverride};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn root(path: PathBuf) -> AdrRootConfig {
+        path.into()
+    }
+
+    #[test]
+    fn test_resolve_globs_and_overrides() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        for dir in [
+            "docs/adr",
+            "services/payments/docs/adr",
+            "services/search/docs/adr",
+        ] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        fs::create_dir_all(base.join("services/empty")).unwrap();
+        fs::write(
+            base.join("services/payments/docs/adr/0007-ledger.md"),
+            "---\ntitle: Ledger\nstatus: accepted\n---\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            adr_dir: AdrDirs::from(vec![
+                root(base.join("docs/adr")),
+                AdrRootConfig {
+                    template: Some(TemplateOverride {
+                        format: Some("custom".to_string()),
+                        custom_path: Some(PathBuf::from("team.md")),
+                    }),
+                    drift: Some(DriftOverride {
+                        enabled: Some(false),
+                    }),
+                    ..root(base.join("services/*/docs/adr"))
+                },
+            ]),
+            ..Config::default()
+        };
+
+        let roots = resolve(&config, None).unwrap();
+        let namespaces: Vec<_> = roots.iter().map(|r| r.namespace.as_deref()).collect();
+        assert_eq!(namespaces, vec![None, Some("payments"), Some("search")]);
+        assert_eq!(roots[0].template.format, "madr");
+        assert!(roots[0].drift.enabled);
+        assert_eq!(roots[1].template.format, "custom");
+        assert!(!roots[1].drift.enabled);
+        assert_eq!(drift_adr_dirs(&roots), vec![base.join("docs/adr")]);
+
+        let documents = load_documents(&roots, &config);
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].namespace.as_deref(), Some("payments"));
+        assert_eq!(
+            root_of(&roots, &documents[0].path).unwrap().namespace,
+            roots[1].namespace
+        );
+
+        // An explicit directory replaces the configured roots
+        let only = resolve(&config, Some(&base.join("docs/adr"))).unwrap();
+        assert_eq!(only.len(), 1);
+        assert!(only[0].namespace.is_none());
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+        fs::create_dir_all(base.join("a/adr")).unwrap();
+        fs::create_dir_all(base.join("b/adr")).unwrap();
+
+        let missing = Config {
+            adr_dir: base.join("missing").into(),
+            ..Config::default()
+        };
+        assert!(matches!(
+            resolve(&missing, None),
+            Err(AdrscanError::DirectoryNotFound(_))
+        ));
+
+        let shared_namespace = Config {
+            adr_dir: AdrDirs::from(vec![AdrRootConfig {
+                namespace: Some("team".to_string()),
+                ..root(base.join("*/adr"))
+            }]),
+            ..Config::default()
+        };
+        assert!(matches!(
+            resolve(&shared_namespace, None),
+            Err(AdrscanError::ConfigError(_))
+        ));
+
+        let duplicate = Config {
+            adr_dir: AdrDirs::from(vec![
+                AdrRootConfig {
+                    namespace: Some("a".to_string()),
+                    ..root(base.join("b/adr"))
+                },
+                root(base.join("*/adr")),
+            ]),
+            ..Config::default()
+        };
+        assert!(matches!(
+            resolve(&duplicate, None),
+            Err(AdrscanError::ConfigError(_))
+        ));
+    }
+}
@@ -0,0 +1,542 @@
+//! ADR Search
+//!
+//! A small query language over ADR metadata and body text:
+//!
+//! ```text
+//! status:accepted tag:database "event sourcing" date:>2024-01-01 decider:alice
+//! ```
+//!
+//! Bare words and quoted phrases are full-text terms, matching at the start
+//! of a word (`postgres` finds "PostgreSQL"); `field:value` pairs are
+//! filters. Every term and filter must match, and any of them can be negated
+//! with a leading `-`. ADRs from namespaced roots can be selected with
+//! `namespace:payments` or `id:payments/0007`. Matches are ranked by a
+//! TF-IDF style score that weighs hits in the title and tags above hits in
+//! the body.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    error::AdrscanError,
+    graph::{normalize_reference, AdrNode},
+    parser::{cache::WordIndex, AdrDocument},
+    status,
+};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Relative weight of a term occurrence per location
+const TITLE_WEIGHT: f64 = 5.0;
+const TAG_WEIGHT: f64 = 3.0;
+const BODY_WEIGHT: f64 = 1.0;
+
+/// Characters of context on each side of a snippet match
+const SNIPPET_CONTEXT: usize = 60;
+
+/// A parsed search query
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Full-text words and phrases, lowercased
+    pub terms: Vec<String>,
+    /// Terms that must not appear
+    pub excluded_terms: Vec<String>,
+    pub filters: Vec<Filter>,
+}
+
+/// A field constraint
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    pub field: Field,
+    pub negated: bool,
+}
+
+/// Searchable fields
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Status(String),
+    Tag(String),
+    /// Case-insensitive substring of any decider
+    Decider(String),
+    /// Case-insensitive substring of the title
+    Title(String),
+    /// Number or id, optionally qualified as `namespace/id`
+    Id(String),
+    Namespace(String),
+    Date(DateComparison, NaiveDate),
+    /// Any other frontmatter field, compared as text
+    Custom(String, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateComparison {
+    Before,
+    OnOrBefore,
+    On,
+    OnOrAfter,
+    After,
+}
+
+/// A matching ADR
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub path: PathBuf,
+    pub id: Option<String>,
+    pub title: String,
+    pub status: String,
+    pub date: Option<NaiveDate>,
+    pub tags: Vec<String>,
+    /// Namespace of the ADR's root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Relevance; zero when the query has no full-text terms
+    pub score: f64,
+    /// Body text around the first full-text match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl Query {
+    /// Parse a query string
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut query = Query::default();
+
+        for token in tokenize(input)? {
+            let (negated, text) = match token.text.strip_prefix('-') {
+                Some(rest) if !token.quoted && !rest.is_empty() => (true, rest.to_string()),
+                _ => (false, token.text),
+            };
+
+            let field = if token.quoted {
+                None
+            } else {
+                text.split_once(':')
+                    .filter(|(name, value)| !name.is_empty() && !value.is_empty())
+                    .map(|(name, value)| parse_field(name, value))
+                    .transpose()?
+            };
+
+            match field {
+                Some(field) => query.filters.push(Filter { field, negated }),
+                None if negated => query.excluded_terms.push(text.to_lowercase()),
+                None => query.terms.push(text.to_lowercase()),
+            }
+        }
+
+        Ok(query)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty() && self.excluded_terms.is_empty() && self.filters.is_empty()
+    }
+
+    /// Search documents, returning matches ranked by relevance; only the
+    /// text of documents that the word index lists for every term is read
+    pub fn search_indexed(&self, documents: &[AdrDocument], index: &WordIndex) -> Vec<SearchHit> {
+        self.search_with(documents, Some(index))
+    }
+
+    /// Search, reading only the documents the word index lists, if given
+    fn search_with(&self, documents: &[AdrDocument], index: Option<&WordIndex>) -> Vec<SearchHit> {
+        let candidates: Vec<Option<HashSet<&Path>>> = self
+            .terms
+            .iter()
+            .map(|term| index.and_then(|index| index.candidates(term)))
+            .collect();
+        let texts: Vec<Option<SearchText>> = documents
+            .iter()
+            .map(|document| {
+                candidates
+                    .iter()
+                    .flatten()
+                    .all(|paths| paths.contains(document.path.as_path()))
+                    .then(|| SearchText::from(document))
+            })
+            .collect();
+
+        // Inverse document frequency over the whole corpus, not just matches
+        let idf: Vec<f64> = self
+            .terms
+            .iter()
+            .zip(&candidates)
+            .map(|(term, candidates)| {
+                let containing = match candidates {
+                    Some(paths) => paths.len(),
+                    None => texts.iter().flatten().filter(|t| t.contains(term)).count(),
+                };
+                (1.0 + documents.len() as f64 / (1.0 + containing as f64)).ln()
+            })
+            .collect();
+
+        let mut hits: Vec<SearchHit> = documents
+            .iter()
+            .zip(&texts)
+            .filter_map(|(document, text)| Some((document, text.as_ref()?)))
+            .filter(|(document, text)| {
+                self.filters
+                    .iter()
+                    .all(|f| f.field.matches(document) != f.negated)
+                    && self.terms.iter().all(|term| text.contains(term))
+                    && !self.excluded_terms.iter().any(|term| text.contains(term))
+            })
+            .map(|(document, text)| {
+                let score = self.terms.iter().zip(&idf).fold(0.0, |score, (term, idf)| {
+                    score + text.term_score(term) * idf
+                });
+
+                SearchHit {
+                    path: document.path.clone(),
+                    id: document.metadata.id.clone(),
+                    title: document.metadata.title.clone(),
+                    status: document.metadata.status.clone(),
+                    date: document.metadata.date,
+                    tags: document.metadata.tags.clone(),
+                    namespace: document.namespace.clone(),
+                    score,
+                    snippet: self
+                        .terms
+                        .first()
+                        .and_then(|term| snippet(&document.content, term)),
+                }
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        hits
+    }
+}
+
+impl Field {
+    fn matches(&self, document: &AdrDocument) -> bool {
+        let metadata = &document.metadata;
+        match self {
+            Field::Status(value) => status::normalize(&metadata.status) == status::normalize(value),
+            Field::Tag(value) => metadata.tags.iter().any(|t| t.eq_ignore_ascii_case(value)),
+            Field::Decider(value) => metadata
+                .deciders
+                .iter()
+                .any(|d| d.to_lowercase().contains(&value.to_lowercase())),
+            Field::Title(value) => metadata
+                .title
+                .to_lowercase()
+                .contains(&value.to_lowercase()),
+            Field::Id(value) => {
+                let (namespace, id) = match value.split_once('/') {
+                    Some((namespace, id)) => (Some(namespace), id),
+                    None => (None, value.as_str()),
+                };
+                namespace.is_none_or(|namespace| {
+                    Field::Namespace(namespace.to_string()).matches(document)
+                }) && AdrNode::from(document).number() == Some(normalize_reference(id))
+            }
+            Field::Namespace(value) => document
+                .namespace
+                .as_deref()
+                .is_some_and(|namespace| namespace.eq_ignore_ascii_case(value)),
+            Field::Date(comparison, date) => metadata.date.is_some_and(|d| match comparison {
+                DateComparison::Before => d < *date,
+                DateComparison::OnOrBefore => d <= *date,
+                DateComparison::On => d == *date,
+                DateComparison::OnOrAfter => d >= *date,
+                DateComparison::After => d > *date,
+            }),
+            Field::Custom(name, value) => {
+                metadata
+                    .custom_fields
+                    .get(name)
+                    .is_some_and(|field| match field {
+                        serde_yaml::Value::Sequence(items) => items
+                            .iter()
+                            .any(|item| yaml_text(item).eq_ignore_ascii_case(value)),
+                        other => yaml_text(other).eq_ignore_ascii_case(value),
+                    })
+            }
+        }
+    }
+}
+
+fn yaml_text(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn parse_field(name: &str, value: &str) -> Result<Field> {
+    let value = value.to_string();
+    Ok(match name.to_lowercase().as_str() {
+        "status" => Field::Status(value),
+        "tag" | "tags" => Field::Tag(value),
+        "decider" | "deciders" => Field::Decider(value),
+        "title" => Field::Title(value),
+        "id" => Field::Id(value),
+        "namespace" | "ns" => Field::Namespace(value),
+        "date" => {
+            let (comparison, date) = [
+                (">=", DateComparison::OnOrAfter),
+                ("<=", DateComparison::OnOrBefore),
+                (">", DateComparison::After),
+                ("<", DateComparison::Before),
+                ("=", DateComparison::On),
+            ]
+            .iter()
+            .find_map(|(op, comparison)| value.strip_prefix(op).map(|rest| (*comparison, rest)))
+            .unwrap_or((DateComparison::On, value.as_str()));
+
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                AdrscanError::InvalidArgument(format!(
+                    "Invalid date in query: '{date}' (expected YYYY-MM-DD)"
+                ))
+            })?;
+            Field::Date(comparison, date)
+        }
+        _ => Field::Custom(name.to_string(), value),
+    })
+}
+
+struct Token {
+    text: String,
+    /// The whole token was a quoted phrase
+    quoted: bool,
+}
+
+/// Split on whitespace, keeping quoted phrases (and quoted field values)
+/// together
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut text = String::new();
+        let quoted = c == '"';
+        let mut in_quotes = false;
+        while let Some(&c) = chars.peek() {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            } else if c.is_whitespace() && !in_quotes {
+                break;
+            } else {
+                text.push(c);
+            }
+            chars.next();
+        }
+
+        if in_quotes {
+            return Err(AdrscanError::InvalidArgument(format!(
+                "Unterminated quote in query: {input}"
+            )));
+        }
+        if !text.is_empty() {
+            tokens.push(Token { text, quoted });
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Lowercased text of a document by location
+struct SearchText {
+    title: String,
+    tags: String,
+    body: String,
+}
+
+impl From<&AdrDocument> for SearchText {
+    fn from(document: &AdrDocument) -> Self {
+        Self {
+            title: document.metadata.title.to_lowercase(),
+            tags: document.metadata.tags.join(" ").to_lowercase(),
+            body: document.content.to_lowercase(),
+        }
+    }
+}
+
+impl SearchText {
+    fn contains(&self, term: &str) -> bool {
+        [&self.title, &self.tags, &self.body]
+            .iter()
+            .any(|text| word_matches(text, term).next().is_some())
+    }
+
+    /// Weighted, dampened term frequency
+    fn term_score(&self, term: &str) -> f64 {
+        let damp = |count: usize| {
+            if count == 0 {
+                0.0
+            } else {
+                1.0 + (count as f64).ln()
+            }
+        };
+
+        TITLE_WEIGHT * damp(word_matches(&self.title, term).count())
+            + TAG_WEIGHT * damp(word_matches(&self.tags, term).count())
+            + BODY_WEIGHT * damp(word_matches(&self.body, term).count())
+    }
+}
+
+/// Byte offsets of the matches of `term` in `text` that start a word, as
+/// looked up in the word index
+fn word_matches<'a>(text: &'a str, term: &'a str) -> impl Iterator<Item = usize> + 'a {
+    let starts_word = term.starts_with(char::is_alphanumeric);
+    text.match_indices(term)
+        .map(|(start, _)| start)
+        .filter(move |&start| !starts_word || !text[..start].ends_with(char::is_alphanumeric))
+}
+
+/// A single-line excerpt of `content` around the first match of `term`
+fn snippet(content: &str, term: &str) -> Option<String> {
+    let lower = content.to_lowercase();
+    let start = word_matches(&lower, term).next()?;
+
+    // Lowercasing can change byte offsets for some scripts; only trust the
+    // offset when it is valid in the original text
+    if !content.is_char_boundary(start) || !content.is_char_boundary(start + term.len()) {
+        return None;
+    }
+
+    let mut from = start.saturating_sub(SNIPPET_CONTEXT);
+    while !content.is_char_boundary(from) {
+        from -= 1;
+    }
+    let mut to = (start + term.len() + SNIPPET_CONTEXT).min(content.len());
+    while !content.is_char_boundary(to) {
+        to += 1;
+    }
+
+    let excerpt = content[from..to]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(format!(
+        "{}{excerpt}{}",
+        if from > 0 { "…" } else { "" },
+        if to < content.len() { "…" } else { "" }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::AdrParser;
+
+    fn parse(path: &str, content: &str) -> AdrDocument {
+        AdrParser::parse_content(content, PathBuf::from(path)).unwrap()
+    }
+
+    fn corpus() -> Vec<AdrDocument> {
+        vec![
+            parse(
+                "0001-event-sourcing.md",
+                "---\ntitle: Adopt event sourcing\nstatus: accepted\ndate: 2024-02-01\ndeciders: [Alice Smith, Bob]\ntags: [architecture]\nteam: payments\n---\n# Adopt event sourcing\n\nWe store events instead of state.\n",
+            ),
+            parse(
+                "0002-postgres.md",
+                "---\ntitle: Use PostgreSQL\nstatus: accepted\ndate: 2023-06-01\ndeciders: [Bob]\ntags: [database]\n---\n# Use PostgreSQL\n\nThe event sourcing store is backed by PostgreSQL.\n",
+            ),
+            parse(
+                "0003-mongo.md",
+                "---\ntitle: Use MongoDB\nstatus: rejected\ndate: 2024-03-01\ndeciders: [Alice Smith]\ntags: [database]\n---\n# Use MongoDB\n\nRejected in favour of PostgreSQL.\n",
+            ),
+        ]
+    }
+
+    fn paths(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|h| h.path.display().to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query =
+            Query::parse(r#"status:accepted tag:database "event sourcing" date:>2024-01-01 decider:alice -draft title:"use postgres""#)
+                .unwrap();
+
+        assert_eq!(query.terms, vec!["event sourcing"]);
+        assert_eq!(query.excluded_terms, vec!["draft"]);
+        assert_eq!(
+            query.filters.iter().map(|f| &f.field).collect::<Vec<_>>(),
+            vec![
+                &Field::Status("accepted".to_string()),
+                &Field::Tag("database".to_string()),
+                &Field::Date(
+                    DateComparison::After,
+                    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+                ),
+                &Field::Decider("alice".to_string()),
+                &Field::Title("use postgres".to_string()),
+            ]
+        );
+
+        assert!(Query::parse("date:yesterday").is_err());
+        assert!(Query::parse("\"unterminated").is_err());
+        assert!(Query::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_filters() {
+        let documents = corpus();
+        let search = |q: &str| paths(&Query::parse(q).unwrap().search_with(&documents, None));
+
+        assert_eq!(
+            search("tag:database -status:rejected"),
+            vec!["0002-postgres.md"]
+        );
+        assert_eq!(
+            search("decider:alice date:>=2024-02-01"),
+            vec!["0001-event-sourcing.md", "0003-mongo.md"]
+        );
+        assert_eq!(search("id:ADR-3"), vec!["0003-mongo.md"]);
+        assert_eq!(search("team:payments"), vec!["0001-event-sourcing.md"]);
+        assert!(search("date:<2020-01-01").is_empty());
+
+        let mut documents = documents;
+        documents[2].namespace = Some("payments".to_string());
+        let search = |q: &str| paths(&Query::parse(q).unwrap().search_with(&documents, None));
+        assert_eq!(search("ns:payments"), vec!["0003-mongo.md"]);
+        assert_eq!(search("id:payments/3"), vec!["0003-mongo.md"]);
+        assert!(search("id:search/3").is_empty());
+    }
+
+    #[test]
+    fn test_ranking_and_snippets() {
+        let documents = corpus();
+
+        let hits = Query::parse("\"event sourcing\"")
+            .unwrap()
+            .search_with(&documents, None);
+        // The title match outranks a mention in the body
+        assert_eq!(
+            paths(&hits),
+            vec!["0001-event-sourcing.md", "0002-postgres.md"]
+        );
+        assert!(hits[0].score > hits[1].score);
+        assert_eq!(
+            hits[1].snippet.as_deref(),
+            Some("# Use PostgreSQL The event sourcing store is backed by PostgreSQL.")
+        );
+
+        // Terms match at the start of words
+        let hits = Query::parse("sourc").unwrap().search_with(&documents, None);
+        assert_eq!(hits.len(), 2);
+        assert!(Query::parse("ourcing")
+            .unwrap()
+            .search_with(&documents, None)
+            .is_empty());
+
+        let hits = Query::parse("postgresql -mongodb")
+            .unwrap()
+            .search_with(&documents, None);
+        assert_eq!(paths(&hits), vec!["0002-postgres.md"]);
+    }
+}
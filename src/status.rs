@@ -0,0 +1,258 @@
+//! ADR Status Lifecycle
+//!
+//! Statuses are configured in [`StatusConfig`] as a list of states and the
+//! transitions allowed between them. Names are compared case-insensitively
+//! with `-`, `_` and spaces treated alike, so "In Review", "in-review" and
+//! "in_review" are the same state. Every transition made with
+//! `adrscan status` is recorded in the ADR's `status_history` frontmatter
+//! field so lint can check that the recorded lifecycle is valid.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{config::StatusConfig, error::AdrscanError, parser::AdrDocument};
+type Result<T> = std::result::Result<T, AdrscanError>;
+
+/// Frontmatter key holding the recorded transitions
+pub const STATUS_HISTORY_FIELD: &str = "status_history";
+
+/// Configured states and the transitions allowed between them
+#[derive(Debug, Clone)]
+pub struct StatusModel {
+    /// State names as configured, in display order
+    states: Vec<String>,
+
+    /// Allowed targets keyed by normalized source state
+    transitions: HashMap<String, Vec<String>>,
+}
+
+/// A single recorded status change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub from: String,
+    pub to: String,
+    pub date: NaiveDate,
+}
+
+impl Default for StatusModel {
+    fn default() -> Self {
+        Self::new(&StatusConfig::default())
+    }
+}
+
+impl StatusModel {
+    pub fn new(config: &StatusConfig) -> Self {
+        let transitions = config
+            .transitions
+            .iter()
+            .map(|(from, to)| (normalize(from), to.iter().map(|s| normalize(s)).collect()))
+            .collect();
+
+        Self {
+            states: config.states.clone(),
+            transitions,
+        }
+    }
+
+    /// Configured state names, in display order
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    /// Configured spelling of a status, if it is a known state
+    pub fn canonical(&self, status: &str) -> Option<&str> {
+        self.position(status).map(|i| self.states[i].as_str())
+    }
+
+    /// Position of a status in the configured order
+    pub fn position(&self, status: &str) -> Option<usize> {
+        let status = normalize(status);
+        self.states.iter().position(|s| normalize(s) == status)
+    }
+
+    /// States reachable from `status` in a single transition
+    pub fn transitions_from(&self, status: &str) -> Vec<&str> {
+        self.states
+            .iter()
+            .filter(|to| self.can_transition(status, to))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether moving from one known state to another is allowed
+    pub fn can_transition(&self, from: &str, to: &str) -> bool {
+        if self.position(from).is_none() || self.position(to).is_none() {
+            return false;
+        }
+        if normalize(from) == normalize(to) {
+            return false;
+        }
+        if self.transitions.is_empty() {
+            return true;
+        }
+
+        self.transitions
+            .get(&normalize(from))
+            .map(|targets| targets.contains(&normalize(to)))
+            .unwrap_or(false)
+    }
+
+    /// Problems with a document's recorded transitions
+    ///
+    /// Each transition must be allowed, continue from the previous one, and
+    /// the last one must end in the document's current status.
+    pub fn check_history(&self, document: &AdrDocument) -> Vec<String> {
+        let history = match status_history(document) {
+            Ok(history) => history,
+            Err(e) => return vec![e.to_string()],
+        };
+
+        let mut problems = Vec::new();
+        for (index, transition) in history.iter().enumerate() {
+            if let Some(previous) = index.checked_sub(1).map(|i| &history[i]) {
+                if normalize(&previous.to) != normalize(&transition.from) {
+                    problems.push(format!(
+                        "Transition on {} starts from '{}' but the previous one ended in '{}'",
+                        transition.date, transition.from, previous.to
+                    ));
+                }
+            }
+            if !self.can_transition(&transition.from, &transition.to) {
+                problems.push(format!(
+                    "Transition from '{}' to '{}' on {} is not allowed",
+                    transition.from, transition.to, transition.date
+                ));
+            }
+        }
+
+        if let Some(last) = history.last() {
+            if normalize(&last.to) != normalize(&document.metadata.status) {
+                problems.push(format!(
+                    "Status is '{}' but the last recorded transition ended in '{}'",
+                    document.metadata.status, last.to
+                ));
+            }
+        }
+
+        problems
+    }
+}
+
+/// Normalize a status name for comparison
+pub fn normalize(status: &str) -> String {
+    status
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Transitions recorded in a document's frontmatter, oldest first
+pub fn status_history(document: &AdrDocument) -> Result<Vec<StatusTransition>> {
+    match document.metadata.custom_fields.get(STATUS_HISTORY_FIELD) {
+        Some(value) => serde_yaml::from_value(value.clone())
+            .map_err(|e| AdrscanError::ParseError(format!("Invalid {STATUS_HISTORY_FIELD}: {e}"))),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Set a document's status and append the change to its history
+pub fn record_transition(document: &mut AdrDocument, to: &str, date: NaiveDate) -> Result<()> {
+    let mut history = status_history(document)?;
+    history.push(StatusTransition {
+        from: document.metadata.status.clone(),
+        to: to.to_string(),
+        date,
+    });
+
+    let value = serde_yaml::to_value(&history)
+        .map_err(|e| AdrscanError::SerializationError(e.to_string()))?;
+    document.set_string_field("status", to)?;
+    document.set_field(STATUS_HISTORY_FIELD, &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::AdrParser;
+    use std::path::PathBuf;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_default_transitions() {
+        let model = StatusModel::default();
+
+        assert!(model.can_transition("proposed", "Accepted"));
+        assert!(model.can_transition("accepted", "superseded"));
+        assert!(!model.can_transition("accepted", "proposed"));
+        assert!(!model.can_transition("rejected", "accepted"));
+        assert!(!model.can_transition("proposed", "proposed"));
+        assert!(!model.can_transition("proposed", "draft"));
+        assert_eq!(
+            model.transitions_from("accepted"),
+            vec!["deprecated", "superseded"]
+        );
+    }
+
+    #[test]
+    fn test_custom_states() {
+        let config = StatusConfig {
+            states: vec![
+                "draft".to_string(),
+                "In Review".to_string(),
+                "accepted".to_string(),
+            ],
+            transitions: HashMap::new(),
+        };
+        let model = StatusModel::new(&config);
+
+        assert_eq!(model.canonical("in-review"), Some("In Review"));
+        assert_eq!(model.position("ACCEPTED"), Some(2));
+        assert!(model.can_transition("draft", "accepted"));
+        assert!(!model.can_transition("draft", "proposed"));
+    }
+
+    #[test]
+    fn test_record_and_check_history() {
+        let mut document = AdrParser::parse_content(
+            "---\ntitle: Test\nstatus: proposed\n---\n# Test\n",
+            PathBuf::from("0001-test.md"),
+        )
+        .unwrap();
+        let model = StatusModel::default();
+
+        record_transition(&mut document, "accepted", date(1)).unwrap();
+        record_transition(&mut document, "deprecated", date(9)).unwrap();
+
+        let reparsed =
+            AdrParser::parse_content(&document.to_markdown(), document.path.clone()).unwrap();
+        assert_eq!(reparsed.metadata.status, "deprecated");
+        assert_eq!(
+            status_history(&reparsed).unwrap(),
+            vec![
+                StatusTransition {
+                    from: "proposed".to_string(),
+                    to: "accepted".to_string(),
+                    date: date(1),
+                },
+                StatusTransition {
+                    from: "accepted".to_string(),
+                    to: "deprecated".to_string(),
+                    date: date(9),
+                },
+            ]
+        );
+        assert!(model.check_history(&reparsed).is_empty());
+
+        // A skipped step, a disallowed move and a stale status are all reported
+        document.set_string_field("status", "accepted").unwrap();
+        record_transition(&mut document, "proposed", date(10)).unwrap();
+        document.set_string_field("status", "rejected").unwrap();
+        assert_eq!(model.check_history(&document).len(), 3);
+    }
+}
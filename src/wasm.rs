@@ -237,7 +237,7 @@ fn convert_wasm_config(wasm_config: &WasmConfig) -> Result<Config, JsValue> {
         .collect();
 
     Ok(Config {
-        adr_dir: wasm_config.adr_dir.clone().into(),
+        adr_dir: std::path::PathBuf::from(&wasm_config.adr_dir).into(),
         include_patterns,
         exclude_patterns,
         snapshot_file: wasm_config.snapshot_file.clone().into(),
@@ -248,7 +248,9 @@ fn convert_wasm_config(wasm_config: &WasmConfig) -> Result<Config, JsValue> {
         drift: DriftConfig {
             enabled: wasm_config.drift_enabled,
             detection_patterns: vec![], // Will be populated with defaults
+            ..Default::default()
         },
+        ..Default::default()
     })
 }
 
@@ -417,7 +417,13 @@ impl AdrscanWasm {
     #[wasm_bindgen]
     pub fn get_config(&self) -> Result<JsValue, JsValue> {
         let config_map = HashMap::from([
-            ("adr_dir", self.config.adr_dir.to_string_lossy().to_string()),
+            (
+                "adr_dir",
+                self.config.adr_dir.roots()[0]
+                    .path
+                    .to_string_lossy()
+                    .to_string(),
+            ),
             ("template_format", self.config.template.format.clone()),
             ("drift_enabled", self.config.drift.enabled.to_string()),
         ]);
@@ -431,7 +437,7 @@ impl AdrscanWasm {
 #[cfg(feature = "wasm")]
 fn convert_wasm_config(wasm_config: &WasmConfig) -> Result<Config, JsValue> {
     Ok(Config {
-        adr_dir: wasm_config.adr_dir.clone().into(),
+        adr_dir: std::path::PathBuf::from(&wasm_config.adr_dir).into(),
         include_patterns: vec!["**/*.md".to_string()],
         exclude_patterns: vec!["**/node_modules/**".to_string()],
         snapshot_file: "./.adrscan_snapshot.json".into(),
@@ -442,6 +448,7 @@ fn convert_wasm_config(wasm_config: &WasmConfig) -> Result<Config, JsValue> {
         drift: DriftConfig {
             enabled: wasm_config.drift_enabled,
             detection_patterns: vec![], // Simplified for WASM
+            ..Default::default()
         },
     })
 }
@@ -139,3 +139,39 @@ fn test_init_help_command() {
         .success()
         .stdout(predicate::str::contains("Initialize ADR directory"));
 }
+
+#[test]
+fn test_last_adr_dir_flag_wins() {
+    let temp_dir = TempDir::new().unwrap();
+    let adr = "---\nstatus: accepted\n---\n# Title\n\n## Context\n\nWhy.\n\n## Decision\n\nWhat.\n";
+    for (dir, count) in [("one", 1), ("two", 2)] {
+        std::fs::create_dir_all(temp_dir.path().join(dir)).unwrap();
+        for number in 1..=count {
+            std::fs::write(
+                temp_dir.path().join(format!("{dir}/000{number}-adr.md")),
+                adr,
+            )
+            .unwrap();
+        }
+    }
+
+    // The global flag and the command's own flag are the same option, so
+    // the configuration and the command always see the same directory
+    for (args, checked) in [
+        (
+            ["--adr-dir", "two", "lint", "--adr-dir", "one"],
+            "1 files checked",
+        ),
+        (
+            ["--adr-dir", "one", "lint", "--adr-dir", "two"],
+            "2 files checked",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin("adrscan").unwrap();
+        cmd.args(args).current_dir(&temp_dir);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(checked));
+    }
+}